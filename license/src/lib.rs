@@ -0,0 +1,470 @@
+//! Portable proofs that a buyer owns a game, verifiable without trusting an RPC node.
+//!
+//! A [`LicenseProof`] holds the storage proof of the `OwnedGames` entry of a buyer at a block,
+//! along with the evidence that the block is finalized: either its GRANDPA justification, checked
+//! against an authority set the verifier trusts, or the chain of headers from the block up to a
+//! finalized block the verifier knows of, e.g. from a light client.
+//!
+//! A license proof shows that the game was owned at the proven block, so a launcher which wants to
+//! notice refunds should ask for recent proofs and check the block number of the [`License`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use liganite_primitives::types::GameId;
+use scale_codec::{Decode, Encode};
+use sp_consensus_grandpa::{
+    check_message_signature, AuthorityList, AuthorityWeight, GrandpaJustification, SetId,
+};
+use sp_core::{
+    hashing::{blake2_128, twox_128, twox_64},
+    RuntimeDebug,
+};
+use sp_runtime::traits::Header as HeaderT;
+use sp_trie::{read_trie_value, LayoutV1, StorageProof};
+
+/// The name of the games pallet in the liganite runtime, which prefixes its storage keys.
+pub const GAMES_PALLET: &[u8] = b"Games";
+/// The name of the game ownership storage of the games pallet.
+pub const OWNED_GAMES_STORAGE: &[u8] = b"OwnedGames";
+
+/// The evidence that the block of a license proof is finalized.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+pub enum Finality<Header: HeaderT> {
+    /// The GRANDPA justification of the block
+    Justification(GrandpaJustification<Header>),
+    /// The headers from the child of the block up to a block known to be finalized
+    HeaderChain(Vec<Header>),
+}
+
+/// A portable proof that a buyer owns a game.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+pub struct LicenseProof<Header: HeaderT, AccountId> {
+    /// The buyer who owns the game
+    pub buyer: AccountId,
+    /// The publisher the game was bought from, which may be a previous account of the publisher
+    pub publisher: AccountId,
+    /// The identifier of the game within the catalogue of its publisher
+    pub game_id: GameId,
+    /// The header of the block at which the ownership is proven
+    pub header: Header,
+    /// The proof of the ownership entry against the state root of the block
+    pub storage_proof: StorageProof,
+    /// The evidence that the block is finalized
+    pub finality: Finality<Header>,
+}
+
+/// What the verifier of a license proof trusts.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum Trust<Hash> {
+    /// The GRANDPA authority set which finalized the block
+    Authorities {
+        /// The identifier of the authority set
+        set_id: SetId,
+        /// The authorities of the set along with their weights
+        authorities: AuthorityList,
+    },
+    /// The hash of a finalized block
+    Finalized(Hash),
+}
+
+/// A verified license.
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct License<AccountId, BlockNumber> {
+    /// The buyer who owns the game
+    pub buyer: AccountId,
+    /// The publisher the game was bought from, which may be a previous account of the publisher
+    pub publisher: AccountId,
+    /// The identifier of the game within the catalogue of its publisher
+    pub game_id: GameId,
+    /// The block at which the buyer owned the game
+    pub block_number: BlockNumber,
+}
+
+/// The reasons a license proof can be rejected.
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Error {
+    /// The finality evidence is not of the kind the verifier trusts
+    FinalityMismatch,
+    /// The justification does not finalize the block of the proof
+    JustificationTargetMismatch,
+    /// A precommit of the justification is not validly signed by an authority of the set, or
+    /// does not vote for the block of the proof
+    InvalidPrecommit,
+    /// The precommits of the justification do not reach the supermajority of the authorities
+    NotEnoughVotes,
+    /// The header chain does not link the block of the proof to the finalized block
+    BrokenHeaderChain,
+    /// The storage proof does not match the state root of the block
+    InvalidStorageProof,
+    /// The buyer did not own the game at the block
+    NotOwned,
+}
+
+/// Returns the storage key of the ownership entry of a game, as stored by the games pallet.
+pub fn owned_game_key<AccountId: Encode>(
+    buyer: &AccountId,
+    publisher: &AccountId,
+    game_id: GameId,
+) -> Vec<u8> {
+    let buyer = buyer.encode();
+    let game = (publisher, game_id).encode();
+
+    let mut key = Vec::with_capacity(64 + buyer.len() + game.len());
+    key.extend(twox_128(GAMES_PALLET));
+    key.extend(twox_128(OWNED_GAMES_STORAGE));
+    key.extend(twox_64(&buyer));
+    key.extend(buyer);
+    key.extend(blake2_128(&game));
+    key.extend(game);
+    key
+}
+
+/// Verifies a license proof against what the verifier trusts.
+///
+/// The caller still has to check that the buyer of the returned license is the player, e.g. by
+/// asking them to sign a challenge, and that the game is the one being launched.
+pub fn verify<Header: HeaderT, AccountId: Encode>(
+    proof: LicenseProof<Header, AccountId>,
+    trust: &Trust<Header::Hash>,
+) -> Result<License<AccountId, Header::Number>, Error> {
+    let LicenseProof { buyer, publisher, game_id, header, storage_proof, finality } = proof;
+    match (finality, trust) {
+        (Finality::Justification(justification), Trust::Authorities { set_id, authorities }) => {
+            verify_justification(&header, &justification, *set_id, authorities)?
+        },
+        (Finality::HeaderChain(headers), Trust::Finalized(finalized)) => {
+            verify_header_chain(&header, &headers, finalized)?
+        },
+        _ => return Err(Error::FinalityMismatch),
+    }
+
+    let key = owned_game_key(&buyer, &publisher, game_id);
+    let db = storage_proof.into_memory_db::<Header::Hashing>();
+    match read_trie_value::<LayoutV1<Header::Hashing>, _>(
+        &db,
+        header.state_root(),
+        &key,
+        None,
+        None,
+    ) {
+        Ok(Some(_)) => Ok(License { buyer, publisher, game_id, block_number: *header.number() }),
+        Ok(None) => Err(Error::NotOwned),
+        Err(_) => Err(Error::InvalidStorageProof),
+    }
+}
+
+/// Checks that the justification finalizes the header, with the precommits of a supermajority of
+/// the authorities.
+fn verify_justification<Header: HeaderT>(
+    header: &Header,
+    justification: &GrandpaJustification<Header>,
+    set_id: SetId,
+    authorities: &AuthorityList,
+) -> Result<(), Error> {
+    let commit = &justification.commit;
+    if commit.target_hash != header.hash() || commit.target_number != *header.number() {
+        return Err(Error::JustificationTargetMismatch);
+    }
+
+    let ancestries = justification
+        .votes_ancestries
+        .iter()
+        .map(|ancestry| (ancestry.hash(), ancestry))
+        .collect::<BTreeMap<_, _>>();
+    let mut signers = BTreeSet::new();
+    let mut votes: AuthorityWeight = 0;
+    for signed in &commit.precommits {
+        let weight = authorities
+            .iter()
+            .find(|(id, _)| *id == signed.id)
+            .map(|(_, weight)| *weight)
+            .ok_or(Error::InvalidPrecommit)?;
+        let message = finality_grandpa::Message::Precommit(signed.precommit.clone());
+        let signature = check_message_signature(
+            &message,
+            &signed.id,
+            &signed.signature,
+            justification.round,
+            set_id,
+        );
+        if !signature.is_valid() ||
+            !is_descendant(&ancestries, commit.target_hash, signed.precommit.target_hash)
+        {
+            return Err(Error::InvalidPrecommit);
+        }
+        // An authority which signed several precommits is only counted once
+        if signers.insert(signed.id.clone()) {
+            votes = votes.saturating_add(weight);
+        }
+    }
+
+    let total = authorities
+        .iter()
+        .fold(0, |total: AuthorityWeight, (_, weight)| total.saturating_add(*weight));
+    let faulty = total.saturating_sub(1) / 3;
+    if total == 0 || votes < total - faulty {
+        return Err(Error::NotEnoughVotes);
+    }
+    Ok(())
+}
+
+/// Returns whether the block `hash` is the block `ancestor` or one of its descendants, following
+/// the given headers.
+fn is_descendant<Header: HeaderT>(
+    ancestries: &BTreeMap<Header::Hash, &Header>,
+    ancestor: Header::Hash,
+    mut hash: Header::Hash,
+) -> bool {
+    while hash != ancestor {
+        match ancestries.get(&hash) {
+            Some(header) => hash = *header.parent_hash(),
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Checks that the headers link the header to the finalized block.
+fn verify_header_chain<Header: HeaderT>(
+    header: &Header,
+    headers: &[Header],
+    finalized: &Header::Hash,
+) -> Result<(), Error> {
+    let last = headers.iter().try_fold(header.hash(), |hash, child| {
+        (*child.parent_hash() == hash)
+            .then(|| child.hash())
+            .ok_or(Error::BrokenHeaderChain)
+    })?;
+    if last != *finalized {
+        return Err(Error::BrokenHeaderChain);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_consensus_grandpa::localized_payload;
+    use sp_core::{Pair, H256};
+    use sp_keyring::Ed25519Keyring;
+    use sp_runtime::{generic, traits::BlakeTwo256};
+    use sp_trie::{MemoryDB, TrieDBMutBuilder, TrieMut};
+
+    type Header = generic::Header<u32, BlakeTwo256>;
+
+    const BUYER: u64 = 11;
+    const PUBLISHER: u64 = 1;
+    const GAME_ID: GameId = 1;
+    const SET_ID: SetId = 3;
+
+    const AUTHORITIES: [Ed25519Keyring; 4] =
+        [Ed25519Keyring::Alice, Ed25519Keyring::Bob, Ed25519Keyring::Charlie, Ed25519Keyring::Dave];
+
+    /// Produces the state of a chain with the given owned games, returning its root and a proof
+    /// of all of its entries.
+    fn state(owned_games: &[(u64, u64, GameId)]) -> (H256, StorageProof) {
+        let mut db = MemoryDB::<BlakeTwo256>::default();
+        let mut root = H256::default();
+        {
+            let mut trie =
+                TrieDBMutBuilder::<LayoutV1<BlakeTwo256>>::new(&mut db, &mut root).build();
+            trie.insert(b":code", b"runtime").unwrap();
+            for (buyer, publisher, game_id) in owned_games {
+                trie.insert(&owned_game_key(buyer, publisher, *game_id), &[]).unwrap();
+            }
+        }
+        let proof = StorageProof::new(db.drain().into_values().map(|(node, _)| node));
+        (root, proof)
+    }
+
+    fn header(number: u32, state_root: H256, parent_hash: H256) -> Header {
+        Header::new(number, Default::default(), state_root, parent_hash, Default::default())
+    }
+
+    fn authorities() -> Trust<H256> {
+        Trust::Authorities {
+            set_id: SET_ID,
+            authorities: AUTHORITIES.iter().map(|key| (key.public().into(), 1)).collect(),
+        }
+    }
+
+    fn justification(
+        header: &Header,
+        target: &Header,
+        set_id: SetId,
+        signers: &[Ed25519Keyring],
+    ) -> GrandpaJustification<Header> {
+        let round = 7;
+        let precommit = finality_grandpa::Precommit {
+            target_hash: target.hash(),
+            target_number: target.number,
+        };
+        let message = finality_grandpa::Message::Precommit(precommit.clone());
+        let payload = localized_payload(round, set_id, &message);
+        let precommits = signers
+            .iter()
+            .map(|signer| finality_grandpa::SignedPrecommit {
+                precommit: precommit.clone(),
+                signature: signer.pair().sign(&payload).into(),
+                id: signer.public().into(),
+            })
+            .collect();
+        let votes_ancestries = if target == header { Vec::new() } else { vec![target.clone()] };
+        GrandpaJustification {
+            round,
+            commit: finality_grandpa::Commit {
+                target_hash: header.hash(),
+                target_number: header.number,
+                precommits,
+            },
+            votes_ancestries,
+        }
+    }
+
+    fn proof(
+        header: Header,
+        storage_proof: StorageProof,
+        finality: Finality<Header>,
+    ) -> LicenseProof<Header, u64> {
+        LicenseProof {
+            buyer: BUYER,
+            publisher: PUBLISHER,
+            game_id: GAME_ID,
+            header,
+            storage_proof,
+            finality,
+        }
+    }
+
+    fn justified_proof(signers: &[Ed25519Keyring], set_id: SetId) -> LicenseProof<Header, u64> {
+        let (state_root, storage_proof) = state(&[(BUYER, PUBLISHER, GAME_ID)]);
+        let header = header(10, state_root, H256::repeat_byte(1));
+        let justification = justification(&header, &header, set_id, signers);
+        proof(header, storage_proof, Finality::Justification(justification))
+    }
+
+    #[test]
+    fn test_verify_justification() {
+        let license = verify(justified_proof(&AUTHORITIES[..3], SET_ID), &authorities()).unwrap();
+        assert_eq!(
+            license,
+            License { buyer: BUYER, publisher: PUBLISHER, game_id: GAME_ID, block_number: 10 }
+        );
+    }
+
+    #[test]
+    fn test_verify_justification_descendant_votes() {
+        let (state_root, storage_proof) = state(&[(BUYER, PUBLISHER, GAME_ID)]);
+        let header = header(10, state_root, H256::repeat_byte(1));
+        let child = self::header(11, state_root, header.hash());
+        let justification = justification(&header, &child, SET_ID, &AUTHORITIES);
+        let proof = proof(header, storage_proof, Finality::Justification(justification));
+        assert!(verify(proof, &authorities()).is_ok());
+    }
+
+    #[test]
+    fn test_verify_justification_not_enough_votes() {
+        assert_eq!(
+            verify(justified_proof(&AUTHORITIES[..2], SET_ID), &authorities()),
+            Err(Error::NotEnoughVotes)
+        );
+        // Votes from the same authority are only counted once
+        let signers = [Ed25519Keyring::Alice, Ed25519Keyring::Bob, Ed25519Keyring::Bob];
+        assert_eq!(
+            verify(justified_proof(&signers, SET_ID), &authorities()),
+            Err(Error::NotEnoughVotes)
+        );
+    }
+
+    #[test]
+    fn test_verify_justification_invalid_precommit() {
+        let signers = [Ed25519Keyring::Alice, Ed25519Keyring::Bob, Ed25519Keyring::Eve];
+        assert_eq!(
+            verify(justified_proof(&signers, SET_ID), &authorities()),
+            Err(Error::InvalidPrecommit)
+        );
+        assert_eq!(
+            verify(justified_proof(&AUTHORITIES, SET_ID + 1), &authorities()),
+            Err(Error::InvalidPrecommit)
+        );
+    }
+
+    #[test]
+    fn test_verify_justification_target_mismatch() {
+        let mut proof = justified_proof(&AUTHORITIES, SET_ID);
+        proof.header.number += 1;
+        assert_eq!(verify(proof, &authorities()), Err(Error::JustificationTargetMismatch));
+    }
+
+    #[test]
+    fn test_verify_header_chain() {
+        let (state_root, storage_proof) = state(&[(BUYER, PUBLISHER, GAME_ID)]);
+        let header = header(10, state_root, H256::repeat_byte(1));
+        let child = self::header(11, H256::repeat_byte(2), header.hash());
+        let grandchild = self::header(12, H256::repeat_byte(3), child.hash());
+        let finalized = grandchild.hash();
+
+        let chain = vec![child.clone(), grandchild.clone()];
+        let license_proof =
+            proof(header.clone(), storage_proof.clone(), Finality::HeaderChain(chain));
+        let license = verify(license_proof, &Trust::Finalized(finalized)).unwrap();
+        assert_eq!(license.block_number, 10);
+
+        // The block itself can be the finalized one
+        let license_proof =
+            proof(header.clone(), storage_proof.clone(), Finality::HeaderChain(vec![]));
+        assert!(verify(license_proof, &Trust::Finalized(header.hash())).is_ok());
+
+        let license_proof =
+            proof(header.clone(), storage_proof.clone(), Finality::HeaderChain(vec![grandchild]));
+        assert_eq!(
+            verify(license_proof, &Trust::Finalized(finalized)),
+            Err(Error::BrokenHeaderChain)
+        );
+
+        let license_proof = proof(header, storage_proof, Finality::HeaderChain(vec![child]));
+        assert_eq!(
+            verify(license_proof, &Trust::Finalized(finalized)),
+            Err(Error::BrokenHeaderChain)
+        );
+    }
+
+    #[test]
+    fn test_verify_finality_mismatch() {
+        let proof = justified_proof(&AUTHORITIES, SET_ID);
+        let finalized = proof.header.hash();
+        assert_eq!(verify(proof, &Trust::Finalized(finalized)), Err(Error::FinalityMismatch));
+    }
+
+    #[test]
+    fn test_verify_not_owned() {
+        let (state_root, storage_proof) = state(&[(BUYER, PUBLISHER, GAME_ID + 1)]);
+        let header = header(10, state_root, H256::repeat_byte(1));
+        let justification = justification(&header, &header, SET_ID, &AUTHORITIES);
+        let proof = proof(header, storage_proof, Finality::Justification(justification));
+        assert_eq!(verify(proof, &authorities()), Err(Error::NotOwned));
+    }
+
+    #[test]
+    fn test_verify_invalid_storage_proof() {
+        let (state_root, _) = state(&[(BUYER, PUBLISHER, GAME_ID)]);
+        let (_, storage_proof) = state(&[(BUYER, PUBLISHER, GAME_ID + 1)]);
+        let header = header(10, state_root, H256::repeat_byte(1));
+        let justification = justification(&header, &header, SET_ID, &AUTHORITIES);
+        let proof = proof(header, storage_proof, Finality::Justification(justification));
+        assert_eq!(verify(proof, &authorities()), Err(Error::InvalidStorageProof));
+    }
+
+    #[test]
+    fn test_license_proof_encoding() {
+        let proof = justified_proof(&AUTHORITIES, SET_ID);
+        let decoded = LicenseProof::<Header, u64>::decode(&mut &proof.encode()[..]).unwrap();
+        assert_eq!(decoded, proof);
+    }
+}
@@ -1,4 +1,4 @@
-use liganite_runtime::WASM_BINARY;
+use liganite_runtime::{genesis_config_presets::LIGANITE_DEMO_PRESET, WASM_BINARY};
 use sc_service::ChainType;
 
 /// Specialized `ChainSpec`. This is a specialization of the general Substrate ChainSpec type.
@@ -27,3 +27,15 @@ pub fn local_chain_spec() -> Result<ChainSpec, String> {
     .with_genesis_config_preset_name(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET)
     .build())
 }
+
+pub fn demo_chain_spec() -> Result<ChainSpec, String> {
+    Ok(ChainSpec::builder(
+        WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?,
+        None,
+    )
+    .with_name("Liganite Demo")
+    .with_id("liganite_demo")
+    .with_chain_type(ChainType::Local)
+    .with_genesis_config_preset_name(LIGANITE_DEMO_PRESET)
+    .build())
+}
@@ -8,12 +8,17 @@
 use std::sync::Arc;
 
 use jsonrpsee::RpcModule;
-use liganite_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use liganite_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Nonce};
+use sc_client_api::{BlockBackend, ProofProvider};
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 
+mod liganite;
+
+pub use liganite::{Liganite, LiganiteApiServer};
+
 /// Full client dependencies.
 pub struct FullDeps<C, P> {
     /// The client instance to use.
@@ -29,9 +34,12 @@ pub fn create_full<C, P>(
 where
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+    C: BlockBackend<Block> + ProofProvider<Block>,
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: liganite_games_runtime_api::GamesApi<Block, AccountId, Balance, BlockNumber>,
+    C::Api: liganite_publish_runtime_api::PublishApi<Block, AccountId, Balance, BlockNumber>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + 'static,
 {
@@ -42,12 +50,8 @@ where
     let FullDeps { client, pool } = deps;
 
     module.merge(System::new(client.clone(), pool).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
-
-    // Extend this RPC with a custom API by using the following syntax.
-    // `YourRpcStruct` should have a reference to a client, which is needed
-    // to call into the runtime.
-    // `module.merge(YourRpcTrait::into_rpc(YourRpcStruct::new(ReferenceToClient, ...)))?;`
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(Liganite::new(client).into_rpc())?;
 
     // You probably want to enable the `rpc v2 chainSpec` API as well
     //
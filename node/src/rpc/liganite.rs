@@ -0,0 +1,610 @@
+//! The `liganite` RPC namespace, serving the game catalogue and the publishers in JSON.
+//!
+//! The methods are built on the `GamesApi` and `PublishApi` runtime APIs. The names, URLs and
+//! CIDs stored on-chain as bytes are decoded as UTF-8 strings, and the balances are returned as
+//! decimal strings so that they do not lose precision in JavaScript clients.
+//!
+//! `liganite_licenseProof` returns a SCALE encoded `liganite_license::LicenseProof`, which game
+//! launchers verify themselves instead of trusting this node.
+
+use std::{iter, marker::PhantomData, sync::Arc};
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned, INVALID_PARAMS_CODE},
+};
+use liganite_games_runtime_api::{
+    Distribution, GameId, GameInfo, GameStatus, GamesApi as GamesRuntimeApi, OrderInfo,
+    TagCategory, TagGroup as TagGroupInfo, TagId, TagInfo, MAX_PAGE_SIZE,
+};
+use liganite_license::{owned_game_key, Finality, LicenseProof};
+use liganite_publish_runtime_api::{
+    PublishApi as PublishRuntimeApi, PublisherInfo, PublisherStatus, VerificationEvidence,
+};
+use liganite_runtime::{AccountId, Balance, BlockNumber};
+use sc_client_api::{BlockBackend, ProofProvider};
+use scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_consensus_grandpa::{GrandpaJustification, GRANDPA_ENGINE_ID};
+use sp_core::Bytes;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+
+/// Error code for the failures of the runtime API calls.
+const RUNTIME_ERROR: i32 = 1;
+/// Error code for the failures to read the chain.
+const CLIENT_ERROR: i32 = 2;
+/// Error code for the license proofs at blocks which are not finalized.
+const NOT_FINALIZED_ERROR: i32 = 3;
+
+/// The maximum number of headers linking the block of a license proof to the finalized block.
+const MAX_HEADER_CHAIN: usize = 512;
+
+/// The `liganite` RPC methods.
+#[rpc(server)]
+pub trait LiganiteApi<BlockHash> {
+    /// Returns a game, following the ownership transfers of its publisher.
+    #[method(name = "liganite_getGame")]
+    fn get_game(
+        &self,
+        publisher: AccountId,
+        game_id: GameId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Game>>;
+
+    /// Returns a page of the games of a publisher, or of the active games with a tag. Exactly one
+    /// of `publisher` and `tag` must be given. The last game of a page is the cursor of the next
+    /// one.
+    #[method(name = "liganite_listGames")]
+    fn list_games(
+        &self,
+        publisher: Option<AccountId>,
+        tag: Option<TagId>,
+        cursor: Option<GameKey>,
+        limit: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Game>>;
+
+    /// Returns a page of the games owned by a buyer. The last game of a page is the cursor of the
+    /// next one.
+    #[method(name = "liganite_ownedGames")]
+    fn owned_games(
+        &self,
+        buyer: AccountId,
+        cursor: Option<GameKey>,
+        limit: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<GameKey>>;
+
+    /// Returns a page of the pending orders of a game. The buyer of the last order of a page is
+    /// the cursor of the next one.
+    #[method(name = "liganite_pendingOrders")]
+    fn pending_orders(
+        &self,
+        publisher: AccountId,
+        game_id: GameId,
+        cursor: Option<AccountId>,
+        limit: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Order>>;
+
+    /// Returns every tag, grouped by category, to build the filters of a store front.
+    #[method(name = "liganite_tagsByCategory")]
+    fn tags_by_category(&self, at: Option<BlockHash>) -> RpcResult<Vec<TagGroup>>;
+
+    /// Returns a publisher, following their ownership transfers.
+    #[method(name = "liganite_publisher")]
+    fn publisher(
+        &self,
+        publisher: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<Publisher>>;
+
+    /// Returns a SCALE encoded proof that a buyer owns a game, at a finalized block which defaults
+    /// to the latest one. The game is identified as it was bought, as returned by
+    /// `liganite_ownedGames`.
+    ///
+    /// The proof carries the GRANDPA justification of the block when the node has it, and the
+    /// headers up to the latest finalized block otherwise.
+    #[method(name = "liganite_licenseProof")]
+    fn license_proof(
+        &self,
+        buyer: AccountId,
+        publisher: AccountId,
+        game_id: GameId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Bytes>;
+}
+
+/// The identifier of a game.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameKey {
+    /// The publisher of the game
+    pub publisher: AccountId,
+    /// The identifier of the game within the catalogue of its publisher
+    pub game_id: GameId,
+}
+
+/// A game of the catalogue.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Game {
+    /// The current account of the publisher of the game
+    pub publisher: AccountId,
+    /// The identifier of the game within the catalogue of its publisher
+    pub game_id: GameId,
+    /// The name of the game
+    pub name: String,
+    /// The tags of the game
+    pub tags: Vec<Tag>,
+    /// How the game is distributed and its price
+    pub distribution: GameDistribution,
+    /// The lifecycle status of the game: `active`, `hidden`, `delisted` or `retired`
+    pub status: &'static str,
+    /// The refund policy of the game, if it can be refunded
+    pub refund_policy: Option<RefundPolicy>,
+    /// The latest release of the game which is not yanked
+    pub latest_release: Option<Release>,
+}
+
+/// A game tag.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tag {
+    /// The identifier of the tag
+    pub id: TagId,
+    /// The name of the tag
+    pub name: String,
+    /// Whether the tag is deprecated, and so cannot be given to new games
+    pub deprecated: bool,
+    /// The category of the tag
+    pub category: &'static str,
+    /// The broader tag this tag refines, if any
+    pub parent: Option<TagId>,
+}
+
+/// The tags of a category.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagGroup {
+    /// The category of the tags
+    pub category: &'static str,
+    /// The tags of the category, by id
+    pub tags: Vec<Tag>,
+}
+
+/// How a game is distributed.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum GameDistribution {
+    /// The game is distributed free of charge
+    Free {
+        /// The CID of the game
+        cid: String,
+    },
+    /// The game is purchased instantly
+    Instant {
+        /// The price of the game
+        price: String,
+        /// The CID of the game
+        cid: String,
+    },
+    /// The game is delivered by the publisher after the purchase
+    Individual {
+        /// The price of the game
+        price: String,
+    },
+}
+
+/// The refund policy of a game.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundPolicy {
+    /// The number of blocks after a purchase during which a refund can be asked
+    pub window: BlockNumber,
+    /// The maximum number of downloads for a refund to be granted
+    pub max_downloads: u32,
+}
+
+/// A release of a game.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Release {
+    /// The version of the release, e.g. `1.2.3`
+    pub version: String,
+    /// The CID of the game build
+    pub cid: String,
+    /// The CID of the changelog
+    pub changelog: String,
+    /// The block at which the release was published
+    pub released_at: BlockNumber,
+}
+
+/// A pending order of a game.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    /// The buyer who placed the order
+    pub buyer: AccountId,
+    /// The deposit held from the buyer
+    pub deposit: String,
+    /// The block at which the order expires
+    pub expires_at: BlockNumber,
+}
+
+/// A publisher.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Publisher {
+    /// The current account of the publisher
+    pub publisher: AccountId,
+    /// The name of the publisher
+    pub name: String,
+    /// The URL of the publisher
+    pub url: String,
+    /// The CID of the logo of the publisher
+    pub logo: Option<String>,
+    /// The CID of the description of the publisher
+    pub description: Option<String>,
+    /// The contact email of the publisher
+    pub email: Option<String>,
+    /// Whether the publisher is `active` or `suspended`
+    pub status: &'static str,
+    /// The deposit held from the publisher
+    pub deposit: String,
+    /// The verification of the publisher, if they are verified
+    pub verification: Option<Verification>,
+    /// The previous accounts of the publisher, from the oldest to the most recent
+    pub previous: Vec<AccountId>,
+}
+
+/// The verification of a publisher.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum Verification {
+    /// The publisher proved the ownership of the domain of their URL
+    Domain {
+        /// The URL the domain has been proved for
+        url: String,
+        /// The CID of the proof
+        proof: String,
+        /// The block at which the publisher has been verified
+        verified_at: BlockNumber,
+    },
+    /// The publisher has been verified with a document
+    Document {
+        /// The CID of the document
+        cid: String,
+        /// The block at which the publisher has been verified
+        verified_at: BlockNumber,
+    },
+}
+
+fn string(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+impl From<GameInfo<AccountId, Balance, BlockNumber>> for Game {
+    fn from(game: GameInfo<AccountId, Balance, BlockNumber>) -> Self {
+        let distribution = match game.distribution {
+            Distribution::Free { cid } => GameDistribution::Free { cid: string(&cid) },
+            Distribution::Instant { price, cid } => {
+                GameDistribution::Instant { price: price.to_string(), cid: string(&cid) }
+            },
+            Distribution::Individual { price } => {
+                GameDistribution::Individual { price: price.to_string() }
+            },
+        };
+        Game {
+            publisher: game.publisher,
+            game_id: game.game_id,
+            name: string(&game.name),
+            tags: game.tags.into_iter().map(Into::into).collect(),
+            distribution,
+            status: match game.status {
+                GameStatus::Active => "active",
+                GameStatus::Hidden => "hidden",
+                GameStatus::Delisted => "delisted",
+                GameStatus::Retired => "retired",
+            },
+            refund_policy: game.refund_policy.map(|policy| RefundPolicy {
+                window: policy.window,
+                max_downloads: policy.max_downloads,
+            }),
+            latest_release: game.latest_release.map(|release| Release {
+                version: format!(
+                    "{}.{}.{}",
+                    release.version.major, release.version.minor, release.version.patch
+                ),
+                cid: string(&release.cid),
+                changelog: string(&release.changelog),
+                released_at: release.released_at,
+            }),
+        }
+    }
+}
+
+fn category(category: TagCategory) -> &'static str {
+    match category {
+        TagCategory::TopLevelGenre => "topLevelGenre",
+        TagCategory::Genre => "genre",
+        TagCategory::SubGenre => "subGenre",
+        TagCategory::Visuals => "visuals",
+        TagCategory::Theme => "theme",
+        TagCategory::Feature => "feature",
+        TagCategory::Players => "players",
+        TagCategory::Other => "other",
+        TagCategory::Assessment => "assessment",
+        TagCategory::Rating => "rating",
+        TagCategory::Hardware => "hardware",
+    }
+}
+
+impl From<TagInfo> for Tag {
+    fn from(tag: TagInfo) -> Self {
+        Tag {
+            id: tag.id,
+            name: string(&tag.name),
+            deprecated: tag.deprecated,
+            category: category(tag.category),
+            parent: tag.parent,
+        }
+    }
+}
+
+impl From<TagGroupInfo> for TagGroup {
+    fn from(group: TagGroupInfo) -> Self {
+        TagGroup {
+            category: category(group.category),
+            tags: group.tags.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<OrderInfo<AccountId, Balance, BlockNumber>> for Order {
+    fn from(order: OrderInfo<AccountId, Balance, BlockNumber>) -> Self {
+        Order {
+            buyer: order.buyer,
+            deposit: order.deposit.to_string(),
+            expires_at: order.expires_at,
+        }
+    }
+}
+
+impl From<PublisherInfo<AccountId, Balance, BlockNumber>> for Publisher {
+    fn from(publisher: PublisherInfo<AccountId, Balance, BlockNumber>) -> Self {
+        let details = publisher.details;
+        let verification = publisher.verification.map(|verification| {
+            let verified_at = verification.verified_at;
+            match verification.evidence {
+                VerificationEvidence::Domain { url, proof } => {
+                    Verification::Domain { url: string(&url), proof: string(&proof), verified_at }
+                },
+                VerificationEvidence::Document(cid) => {
+                    Verification::Document { cid: string(&cid), verified_at }
+                },
+            }
+        });
+        Publisher {
+            publisher: publisher.publisher,
+            name: string(&details.name),
+            url: string(&details.url),
+            logo: details.logo.as_deref().map(string),
+            description: details.description.as_deref().map(string),
+            email: details.email.as_deref().map(string),
+            status: match publisher.status {
+                PublisherStatus::Active => "active",
+                PublisherStatus::Suspended => "suspended",
+            },
+            deposit: publisher.deposit.to_string(),
+            verification,
+            previous: publisher.previous,
+        }
+    }
+}
+
+fn runtime_error(error: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, "Unable to query the runtime.", Some(format!("{error:?}")))
+}
+
+fn client_error(error: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(CLIENT_ERROR, "Unable to read the chain.", Some(format!("{error:?}")))
+}
+
+fn not_finalized_error() -> ErrorObjectOwned {
+    ErrorObject::owned(
+        NOT_FINALIZED_ERROR,
+        "The block is not finalized, or too far from the latest finalized block.",
+        None::<()>,
+    )
+}
+
+fn page_size(limit: Option<u32>) -> u32 {
+    limit.unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE)
+}
+
+/// Serves the `liganite` RPC methods.
+pub struct Liganite<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> Liganite<C, Block> {
+    /// Creates a new instance of the `liganite` RPC handler.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+impl<C, Block> Liganite<C, Block>
+where
+    Block: BlockT,
+    C: HeaderBackend<Block>,
+{
+    fn header(&self, hash: Block::Hash) -> RpcResult<Block::Header> {
+        self.client.header(hash).map_err(client_error)?.ok_or_else(not_finalized_error)
+    }
+
+    /// Returns the headers from the child of the given block up to the finalized block.
+    fn header_chain(
+        &self,
+        header: &Block::Header,
+        finalized: Block::Hash,
+    ) -> RpcResult<Vec<Block::Header>> {
+        let mut headers = Vec::new();
+        let mut hash = finalized;
+        while hash != header.hash() {
+            let child = self.header(hash)?;
+            if child.number() <= header.number() || headers.len() == MAX_HEADER_CHAIN {
+                return Err(not_finalized_error());
+            }
+            hash = *child.parent_hash();
+            headers.push(child);
+        }
+        headers.reverse();
+        Ok(headers)
+    }
+}
+
+impl<C, Block> LiganiteApiServer<<Block as BlockT>::Hash> for Liganite<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C: BlockBackend<Block> + ProofProvider<Block>,
+    C::Api: GamesRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    C::Api: PublishRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+{
+    fn get_game(
+        &self,
+        publisher: AccountId,
+        game_id: GameId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<Game>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let game = self.client.runtime_api().game(at, publisher, game_id).map_err(runtime_error)?;
+        Ok(game.map(Into::into))
+    }
+
+    fn list_games(
+        &self,
+        publisher: Option<AccountId>,
+        tag: Option<TagId>,
+        cursor: Option<GameKey>,
+        limit: Option<u32>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<Game>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let api = self.client.runtime_api();
+        let games = match (publisher, tag) {
+            (Some(publisher), None) => {
+                api.games_of(at, publisher, cursor.map(|cursor| cursor.game_id), page_size(limit))
+            },
+            (None, Some(tag)) => api.games_by_tag(
+                at,
+                tag,
+                cursor.map(|cursor| (cursor.publisher, cursor.game_id)),
+                page_size(limit),
+            ),
+            _ => {
+                return Err(ErrorObject::owned(
+                    INVALID_PARAMS_CODE,
+                    "Exactly one of `publisher` and `tag` must be given.",
+                    None::<()>,
+                ))
+            },
+        }
+        .map_err(runtime_error)?;
+        Ok(games.into_iter().map(Into::into).collect())
+    }
+
+    fn owned_games(
+        &self,
+        buyer: AccountId,
+        cursor: Option<GameKey>,
+        limit: Option<u32>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<GameKey>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let cursor = cursor.map(|cursor| (cursor.publisher, cursor.game_id));
+        let games = self
+            .client
+            .runtime_api()
+            .owned_games(at, buyer, cursor, page_size(limit))
+            .map_err(runtime_error)?;
+        Ok(games
+            .into_iter()
+            .map(|(publisher, game_id)| GameKey { publisher, game_id })
+            .collect())
+    }
+
+    fn pending_orders(
+        &self,
+        publisher: AccountId,
+        game_id: GameId,
+        cursor: Option<AccountId>,
+        limit: Option<u32>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<Order>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let orders = self
+            .client
+            .runtime_api()
+            .pending_orders(at, publisher, game_id, cursor, page_size(limit))
+            .map_err(runtime_error)?;
+        Ok(orders.into_iter().map(Into::into).collect())
+    }
+
+    fn tags_by_category(&self, at: Option<Block::Hash>) -> RpcResult<Vec<TagGroup>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let groups = self.client.runtime_api().tags_by_category(at).map_err(runtime_error)?;
+        Ok(groups.into_iter().map(Into::into).collect())
+    }
+
+    fn publisher(
+        &self,
+        publisher: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<Publisher>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        let publisher =
+            self.client.runtime_api().publisher(at, publisher).map_err(runtime_error)?;
+        Ok(publisher.map(Into::into))
+    }
+
+    fn license_proof(
+        &self,
+        buyer: AccountId,
+        publisher: AccountId,
+        game_id: GameId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Bytes> {
+        let finalized = self.client.info().finalized_hash;
+        let header = self.header(at.unwrap_or(finalized))?;
+        let hash = header.hash();
+
+        let key = owned_game_key(&buyer, &publisher, game_id);
+        let storage_proof = self
+            .client
+            .read_proof(hash, &mut iter::once(key.as_slice()))
+            .map_err(client_error)?;
+
+        let justification = self
+            .client
+            .justifications(hash)
+            .map_err(client_error)?
+            .and_then(|justifications| justifications.into_justification(GRANDPA_ENGINE_ID))
+            .map(|encoded| GrandpaJustification::<Block::Header>::decode(&mut &encoded[..]))
+            .transpose()
+            .map_err(client_error)?;
+        let finality = match justification {
+            Some(justification) => Finality::Justification(justification),
+            None => Finality::HeaderChain(self.header_chain(&header, finalized)?),
+        };
+
+        let proof = LicenseProof { buyer, publisher, game_id, header, storage_proof, finality };
+        Ok(proof.encode().into())
+    }
+}
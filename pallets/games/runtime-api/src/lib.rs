@@ -0,0 +1,149 @@
+//! Runtime API to query the game catalogue of the liganite network.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use liganite_primitives::types::{
+    Cid, Distribution, GameDetails, GameId, GameStatus, Name, RefundPolicy, SemVer, Tag,
+    TagCategory, TagDetails, TagId,
+};
+use scale_codec::{Codec, Decode, Encode};
+use scale_info::{prelude::vec::Vec, TypeInfo};
+
+/// The maximum number of games returned by a single page.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// A game of the catalogue, as shown in a store front.
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct GameInfo<AccountId, Balance, BlockNumber> {
+    /// The current account of the publisher of the game
+    pub publisher: AccountId,
+    /// The identifier of the game within the catalogue of its publisher
+    pub game_id: GameId,
+    /// The name of the game
+    pub name: Name,
+    /// The tags of the game
+    pub tags: Vec<TagInfo>,
+    /// How the game is distributed and its price
+    pub distribution: Distribution<Balance>,
+    /// The lifecycle status of the game
+    pub status: GameStatus,
+    /// The refund policy of the game, if it can be refunded
+    pub refund_policy: Option<RefundPolicy<BlockNumber>>,
+    /// The latest release of the game which is not yanked
+    pub latest_release: Option<ReleaseInfo<BlockNumber>>,
+}
+
+/// A release of a game.
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct ReleaseInfo<BlockNumber> {
+    /// The version of the release
+    pub version: SemVer,
+    /// The CID of the game build
+    pub cid: Cid,
+    /// The CID of the changelog
+    pub changelog: Cid,
+    /// The block at which the release was published
+    pub released_at: BlockNumber,
+}
+
+/// A pending order of a game.
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct OrderInfo<AccountId, Balance, BlockNumber> {
+    /// The buyer who placed the order
+    pub buyer: AccountId,
+    /// The deposit held from the buyer
+    pub deposit: Balance,
+    /// The block at which the order expires
+    pub expires_at: BlockNumber,
+}
+
+/// A game tag.
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct TagInfo {
+    /// The identifier of the tag
+    pub id: TagId,
+    /// The name of the tag
+    pub name: Tag,
+    /// Whether the tag is deprecated, and so cannot be given to new games
+    pub deprecated: bool,
+    /// The category of the tag
+    pub category: TagCategory,
+    /// The broader tag this tag refines, if any
+    pub parent: Option<TagId>,
+}
+
+impl TagInfo {
+    pub fn new(id: TagId, details: TagDetails) -> Self {
+        Self {
+            id,
+            deprecated: !details.is_active(),
+            name: details.name,
+            category: details.category,
+            parent: details.parent,
+        }
+    }
+}
+
+/// The tags of a category, as shown in the filters of a store front.
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct TagGroup {
+    /// The category of the tags
+    pub category: TagCategory,
+    /// The tags of the category, by id
+    pub tags: Vec<TagInfo>,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait GamesApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Returns a game, following the ownership transfers of its publisher.
+        fn game(
+            publisher: AccountId,
+            game_id: GameId,
+        ) -> Option<GameInfo<AccountId, Balance, BlockNumber>>;
+
+        /// Returns up to `limit` games of a publisher, starting after the `cursor` game. The last
+        /// game of a page is the cursor of the next one.
+        fn games_of(
+            publisher: AccountId,
+            cursor: Option<GameId>,
+            limit: u32,
+        ) -> Vec<GameInfo<AccountId, Balance, BlockNumber>>;
+
+        /// Returns up to `limit` active games with the given tag, starting after the `cursor` game.
+        /// The last game of a page is the cursor of the next one.
+        fn games_by_tag(
+            tag_id: TagId,
+            cursor: Option<(AccountId, GameId)>,
+            limit: u32,
+        ) -> Vec<GameInfo<AccountId, Balance, BlockNumber>>;
+
+        /// Returns up to `limit` games owned by a buyer, starting after the `cursor` game. The
+        /// games are identified as they were bought. The last game of a page is the cursor of the
+        /// next one.
+        fn owned_games(
+            buyer: AccountId,
+            cursor: Option<(AccountId, GameId)>,
+            limit: u32,
+        ) -> Vec<(AccountId, GameId)>;
+
+        /// Returns up to `limit` pending orders of a game, starting after the order of the
+        /// `cursor` buyer. The buyer of the last order of a page is the cursor of the next one.
+        fn pending_orders(
+            publisher: AccountId,
+            game_id: GameId,
+            cursor: Option<AccountId>,
+            limit: u32,
+        ) -> Vec<OrderInfo<AccountId, Balance, BlockNumber>>;
+
+        /// Returns a tag.
+        fn tag(tag_id: TagId) -> Option<TagInfo>;
+
+        /// Returns every tag, grouped by category.
+        fn tags_by_category() -> Vec<TagGroup>;
+    }
+}
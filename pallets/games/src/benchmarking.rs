@@ -8,7 +8,9 @@ use frame_benchmarking::v2::*;
 use frame_support::sp_runtime::traits::{Bounded, CheckedDiv};
 use frame_system::RawOrigin;
 use liganite_primitives::{
-    testing::bounded_vec, types::PublisherDetails, MAX_NAME_SIZE, MAX_TAGS_PER_GAME,
+    testing::bounded_vec,
+    types::{GameTags, Name, PublisherDetails},
+    MAX_NAME_SIZE, MAX_REASON_SIZE, MAX_TAGS_PER_GAME, MAX_TAG_SIZE,
 };
 use scale_info::prelude::{vec, vec::Vec};
 
@@ -25,24 +27,35 @@ fn prefund_account<T: Config>(account: &T::AccountId) {
     T::Currency::set_balance(account, CurrencyOf::<T>::from(initial_balance));
 }
 
+fn insert_publisher<T: Config>(publisher: &T::AccountId) {
+    T::PublisherManager::insert_publisher(
+        publisher,
+        &PublisherDetails {
+            name: bounded_vec(b"publisher"),
+            url: bounded_vec(b"https://publisher.com"),
+            ..Default::default()
+        },
+    );
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
 
     #[benchmark]
-    fn game_add(a: Linear<1, MAX_NAME_SIZE>, b: Linear<0, MAX_TAGS_PER_GAME>) {
+    fn game_add(a: Linear<1, MAX_NAME_SIZE>, b: Linear<1, MAX_TAGS_PER_GAME>) {
         let name = bounded_vec(&vec![b'a'; a as usize]);
         let tags = bounded_vec(&(0..b as TagId).collect::<Vec<_>>());
         let url = bounded_vec(b"https://publisher.com");
 
         for tag in tags.iter() {
-            Tags::<T>::insert(*tag, Tag::default());
+            Tags::<T>::insert(*tag, TagDetails::new(Tag::default(), TagCategory::Genre, None));
         }
 
         let publisher = whitelisted_caller();
         T::PublisherManager::insert_publisher(
             &publisher,
-            &PublisherDetails { name: name.clone(), url },
+            &PublisherDetails { name: name.clone(), url, ..Default::default() },
         );
 
         let game_id = 1;
@@ -64,6 +77,7 @@ mod benchmarks {
     #[benchmark]
     fn buy_free() {
         let publisher = get_account::<T>(0);
+        insert_publisher::<T>(&publisher);
         let game_id = 10;
         let game_details = GameDetails {
             name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
@@ -85,6 +99,7 @@ mod benchmarks {
     #[benchmark]
     fn buy_instant() {
         let publisher = get_account::<T>(0);
+        insert_publisher::<T>(&publisher);
         let game_id = 10;
         let price = CurrencyOf::<T>::from(2_000_000_000u32);
         let game_details = GameDetails {
@@ -96,6 +111,10 @@ mod benchmarks {
             },
         };
         PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        // the payment of a game with a refund policy is held, which is the worst case
+        prefund_account::<T>(&publisher);
+        let policy = RefundPolicy { window: T::OrderTimeout::get(), max_downloads: 1 };
+        RefundPolicies::<T>::insert(&publisher, game_id, policy);
         let buyer = whitelisted_caller();
         prefund_account::<T>(&buyer);
 
@@ -103,11 +122,13 @@ mod benchmarks {
         game_buy(RawOrigin::Signed(buyer.clone()), publisher.clone(), game_id);
 
         assert_eq!(OwnedGames::<T>::get(&buyer, (&publisher, game_id)), Some(()));
+        assert!(Purchases::<T>::contains_key(&buyer, (&publisher, game_id)));
     }
 
     #[benchmark]
     fn order_place() {
         let publisher = get_account::<T>(0);
+        insert_publisher::<T>(&publisher);
         let game_id = 10;
         let price = CurrencyOf::<T>::from(2_000_000_000u32);
         let game_details = GameDetails {
@@ -122,9 +143,11 @@ mod benchmarks {
         #[extrinsic_call]
         game_buy(RawOrigin::Signed(buyer.clone()), publisher.clone(), game_id);
 
-        let expected = OrderDetails { deposit: price };
+        let expires_at =
+            frame_system::Pallet::<T>::block_number().saturating_add(T::OrderTimeout::get());
+        let expected = OrderDetails { deposit: price, expires_at };
         assert_eq!(BuyerOrders::<T>::get(&buyer, (&publisher, game_id)), Some(expected));
-        assert_eq!(PublisherOrders::<T>::get(&publisher, game_id), Some(buyer));
+        assert_eq!(PublisherOrders::<T>::get((&publisher, game_id, &buyer)), Some(()));
     }
 
     #[benchmark]
@@ -141,14 +164,21 @@ mod benchmarks {
 
         let buyer = whitelisted_caller();
         prefund_account::<T>(&buyer);
-        BuyerOrders::<T>::insert(&buyer, (&publisher, game_id), &OrderDetails { deposit: price });
-        PublisherOrders::<T>::insert(&publisher, game_id, &buyer);
+        Games::<T>::insert_order(
+            &buyer,
+            &publisher,
+            game_id,
+            &OrderDetails { deposit: price, expires_at: T::OrderTimeout::get() },
+        );
 
         #[extrinsic_call]
         _(RawOrigin::Signed(buyer.clone()), publisher.clone(), game_id);
 
         assert_eq!(BuyerOrders::<T>::get(&buyer, (&publisher, game_id)), None);
-        assert_eq!(PublisherOrders::<T>::get(&publisher, game_id), None);
+        assert_eq!(PublisherOrders::<T>::get((&publisher, game_id, &buyer)), None);
+        let expires_at = T::OrderTimeout::get();
+        assert_eq!(OrderExpiries::<T>::get((expires_at, &buyer, (&publisher, game_id))), None);
+        assert_eq!(PendingOrdersCount::<T>::get(&publisher, game_id), 0);
     }
 
     #[benchmark]
@@ -164,15 +194,519 @@ mod benchmarks {
             distribution: Distribution::Individual { price },
         };
         PublishedGames::<T>::insert(&publisher, game_id, game_details);
-        BuyerOrders::<T>::insert(&buyer, (&publisher, game_id), &OrderDetails { deposit: price });
-        PublisherOrders::<T>::insert(&publisher, game_id, &buyer);
+        Games::<T>::insert_order(
+            &buyer,
+            &publisher,
+            game_id,
+            &OrderDetails { deposit: price, expires_at: T::OrderTimeout::get() },
+        );
+
+        let delivery = Delivery::Plain(bounded_vec(b"bafkrgqe3nmefcemf5kx23jw4mpkux4kswm4umtvwmc2j6cf3ftvkv7flwpfrwe7nvlhyzvvn7wtfhec5nkkwxv3hvzjivkw6yv3f3og5edhcldy"));
 
         #[extrinsic_call]
-        _(RawOrigin::Signed(publisher.clone()), game_id, buyer.clone());
+        _(RawOrigin::Signed(publisher.clone()), game_id, buyer.clone(), delivery.clone());
 
         assert_eq!(BuyerOrders::<T>::get(&buyer, (&publisher, game_id)), None);
-        assert_eq!(PublisherOrders::<T>::get(&publisher, game_id), None);
+        assert_eq!(PublisherOrders::<T>::get((&publisher, game_id, &buyer)), None);
+        let expires_at = T::OrderTimeout::get();
+        assert_eq!(OrderExpiries::<T>::get((expires_at, &buyer, (&publisher, game_id))), None);
+        assert_eq!(PendingOrdersCount::<T>::get(&publisher, game_id), 0);
         assert_eq!(OwnedGames::<T>::get(&buyer, (&publisher, game_id)), Some(()));
+        assert_eq!(Deliveries::<T>::get(&buyer, (&publisher, game_id)), Some(delivery));
+    }
+
+    #[benchmark]
+    fn game_update() {
+        let publisher = whitelisted_caller();
+        T::PublisherManager::insert_publisher(
+            &publisher,
+            &PublisherDetails {
+                name: bounded_vec(b"publisher"),
+                url: bounded_vec(b"https://publisher.com"),
+                ..Default::default()
+            },
+        );
+
+        let tags: GameTags = bounded_vec(&(0..MAX_TAGS_PER_GAME as TagId).collect::<Vec<_>>());
+        for tag in tags.iter() {
+            Tags::<T>::insert(*tag, TagDetails::new(Tag::default(), TagCategory::Genre, None));
+        }
+
+        let game_id = 10;
+        let game_details = GameDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: CurrencyOf::<T>::from(1_000u32) },
+        };
+        PublishedGames::<T>::insert(&publisher, game_id, game_details);
+
+        let name: Name = bounded_vec(&vec![b'b'; MAX_NAME_SIZE as usize]);
+        let distribution = Distribution::Instant {
+            price: CurrencyOf::<T>::from(2_000u32),
+            cid: bounded_vec(b"bafkrgqe3nmefcemf5kx23jw4mpkux4kswm4umtvwmc2j6cf3ftvkv7flwpfrwe7nvlhyzvvn7wtfhec5nkkwxv3hvzjivkw6yv3f3og5edhcldy"),
+        };
+        let update = GameDetailsUpdate {
+            name: Some(name.clone()),
+            tags: Some(tags.clone()),
+            distribution: Some(distribution.clone()),
+        };
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(publisher.clone()), game_id, update);
+
+        assert_eq!(
+            PublishedGames::<T>::get(&publisher, game_id),
+            Some(GameDetails { name, tags, distribution })
+        );
+    }
+
+    #[benchmark]
+    fn game_status_set() {
+        let publisher = whitelisted_caller();
+        insert_publisher::<T>(&publisher);
+        let game_id = 10;
+        let game_details = GameDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
+            distribution: Distribution::Individual { price: CurrencyOf::<T>::from(1_000u32) },
+        };
+        PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        // listing a game again checks the publisher and indexes its tags, which is the worst case
+        GameStatuses::<T>::insert(&publisher, game_id, GameStatus::Delisted);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(publisher.clone()), game_id, GameStatus::Active);
+
+        assert_eq!(GameStatuses::<T>::get(&publisher, game_id), GameStatus::Active);
+    }
+
+    #[benchmark]
+    fn game_retire(n: Linear<0, 500>) {
+        let publisher = whitelisted_caller();
+        let game_id = 10;
+        let price = CurrencyOf::<T>::from(2_000_000_000u32);
+        let game_details = GameDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<T>::insert(&publisher, game_id, game_details);
+
+        for i in 0..n {
+            let buyer = get_account::<T>(i);
+            prefund_account::<T>(&buyer);
+            T::Currency::hold(&HoldReason::GamePayment.into(), &buyer, price)
+                .expect("the buyer is funded; qed");
+            Games::<T>::insert_order(
+                &buyer,
+                &publisher,
+                game_id,
+                &OrderDetails { deposit: price, expires_at: T::OrderTimeout::get() },
+            );
+        }
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(publisher.clone()), game_id, n);
+
+        assert_eq!(GameStatuses::<T>::get(&publisher, game_id), GameStatus::Retired);
+        assert_eq!(PendingOrdersCount::<T>::get(&publisher, game_id), 0);
+    }
+
+    #[benchmark]
+    fn release_publish() {
+        let publisher = whitelisted_caller();
+        T::PublisherManager::insert_publisher(
+            &publisher,
+            &PublisherDetails {
+                name: bounded_vec(b"publisher"),
+                url: bounded_vec(b"https://publisher.com"),
+                ..Default::default()
+            },
+        );
+        let game_id = 10;
+        let game_details = GameDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
+            distribution: Distribution::Individual { price: CurrencyOf::<T>::from(1_000u32) },
+        };
+        PublishedGames::<T>::insert(&publisher, game_id, game_details);
+
+        let cid: Cid = bounded_vec(b"bafkrgqe3nmefcemf5kx23jw4mpkux4kswm4umtvwmc2j6cf3ftvkv7flwpfrwe7nvlhyzvvn7wtfhec5nkkwxv3hvzjivkw6yv3f3og5edhcldy");
+        let previous = SemVer::new(1, 0, 0);
+        Releases::<T>::insert(
+            (&publisher, game_id, previous),
+            ReleaseDetails {
+                cid: cid.clone(),
+                changelog: cid.clone(),
+                released_at: frame_system::Pallet::<T>::block_number(),
+                yanked: false,
+                previous: None,
+                next: None,
+            },
+        );
+        LatestRelease::<T>::insert(&publisher, game_id, previous);
+        HighestRelease::<T>::insert(&publisher, game_id, previous);
+        let version = SemVer::new(1, 0, 1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(publisher.clone()), game_id, version, cid.clone(), cid);
+
+        assert_eq!(LatestRelease::<T>::get(&publisher, game_id), Some(version));
+        assert_eq!(
+            Releases::<T>::get((&publisher, game_id, previous)).and_then(|r| r.next),
+            Some(version)
+        );
+    }
+
+    #[benchmark]
+    fn release_yank() {
+        let publisher = whitelisted_caller();
+        let game_id = 10;
+        let cid: Cid = bounded_vec(b"bafkrgqe3nmefcemf5kx23jw4mpkux4kswm4umtvwmc2j6cf3ftvkv7flwpfrwe7nvlhyzvvn7wtfhec5nkkwxv3hvzjivkw6yv3f3og5edhcldy");
+        let versions = [SemVer::new(1, 0, 0), SemVer::new(1, 0, 1), SemVer::new(1, 0, 2)];
+        for (i, version) in versions.iter().enumerate() {
+            Releases::<T>::insert(
+                (&publisher, game_id, *version),
+                ReleaseDetails {
+                    cid: cid.clone(),
+                    changelog: cid.clone(),
+                    released_at: frame_system::Pallet::<T>::block_number(),
+                    yanked: false,
+                    previous: i.checked_sub(1).map(|p| versions[p]),
+                    next: versions.get(i + 1).copied(),
+                },
+            );
+        }
+        LatestRelease::<T>::insert(&publisher, game_id, versions[2]);
+
+        // yanking a release in the middle updates both of its neighbours
+        #[extrinsic_call]
+        _(RawOrigin::Signed(publisher.clone()), game_id, versions[1]);
+
+        assert_eq!(
+            Releases::<T>::get((&publisher, game_id, versions[0])).and_then(|r| r.next),
+            Some(versions[2])
+        );
+        assert_eq!(
+            Releases::<T>::get((&publisher, game_id, versions[2])).and_then(|r| r.previous),
+            Some(versions[0])
+        );
+    }
+
+    #[benchmark]
+    fn order_expire() {
+        let caller = whitelisted_caller();
+        let publisher = get_account::<T>(0);
+        let buyer = get_account::<T>(1);
+        prefund_account::<T>(&buyer);
+        let game_id = 10;
+        let price = CurrencyOf::<T>::from(2_000_000_000u32);
+        T::Currency::hold(&HoldReason::GamePayment.into(), &buyer, price)
+            .expect("the buyer is funded; qed");
+        let expires_at = frame_system::Pallet::<T>::block_number();
+        Games::<T>::insert_order(
+            &buyer,
+            &publisher,
+            game_id,
+            &OrderDetails { deposit: price, expires_at },
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), buyer.clone(), publisher.clone(), game_id);
+
+        assert_eq!(BuyerOrders::<T>::get(&buyer, (&publisher, game_id)), None);
+        assert_eq!(OrderExpiries::<T>::get((expires_at, &buyer, (&publisher, game_id))), None);
+    }
+
+    #[benchmark]
+    fn order_reject() {
+        let publisher = whitelisted_caller();
+        let buyer = get_account::<T>(0);
+        prefund_account::<T>(&buyer);
+        let game_id = 10;
+        let price = CurrencyOf::<T>::from(2_000_000_000u32);
+        T::Currency::hold(&HoldReason::GamePayment.into(), &buyer, price)
+            .expect("the buyer is funded; qed");
+        Games::<T>::insert_order(
+            &buyer,
+            &publisher,
+            game_id,
+            &OrderDetails { deposit: price, expires_at: T::OrderTimeout::get() },
+        );
+        let reason = RejectionReason::Other(bounded_vec(&vec![b'a'; MAX_REASON_SIZE as usize]));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(publisher.clone()), game_id, buyer.clone(), reason);
+
+        assert_eq!(BuyerOrders::<T>::get(&buyer, (&publisher, game_id)), None);
+        assert_eq!(PublisherOrders::<T>::get((&publisher, game_id, &buyer)), None);
+    }
+
+    #[benchmark]
+    fn refund_policy_set() {
+        let publisher = whitelisted_caller();
+        let game_id = 10;
+        let price = CurrencyOf::<T>::from(2_000_000_000u32);
+        let game_details = GameDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
+            distribution: Distribution::Instant {
+                price,
+                cid: bounded_vec(b"bafkrgqe3nmefcemf5kx23jw4mpkux4kswm4umtvwmc2j6cf3ftvkv7flwpfrwe7nvlhyzvvn7wtfhec5nkkwxv3hvzjivkw6yv3f3og5edhcldy")
+            },
+        };
+        PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        let policy = RefundPolicy { window: T::OrderTimeout::get(), max_downloads: 1 };
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(publisher.clone()), game_id, Some(policy.clone()));
+
+        assert_eq!(RefundPolicies::<T>::get(&publisher, game_id), Some(policy));
+    }
+
+    #[benchmark]
+    fn purchase_refund() {
+        let publisher = get_account::<T>(0);
+        prefund_account::<T>(&publisher);
+        let buyer = whitelisted_caller();
+        prefund_account::<T>(&buyer);
+        let game_id = 10;
+        let price = CurrencyOf::<T>::from(2_000_000_000u32);
+        T::Currency::transfer_and_hold(
+            &HoldReason::RefundablePayment.into(),
+            &buyer,
+            &publisher,
+            price,
+            Exact,
+            Preserve,
+            Polite,
+        )
+        .expect("the buyer is funded; qed");
+        let refundable_until =
+            frame_system::Pallet::<T>::block_number().saturating_add(T::OrderTimeout::get());
+        let purchase = PurchaseDetails { payment: price, refundable_until, max_downloads: 1 };
+        Purchases::<T>::insert(&buyer, (&publisher, game_id), purchase);
+        OwnedGames::<T>::insert(&buyer, (&publisher, game_id), ());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(buyer.clone()), publisher.clone(), game_id, 1);
+
+        assert_eq!(Purchases::<T>::get(&buyer, (&publisher, game_id)), None);
+        assert_eq!(OwnedGames::<T>::get(&buyer, (&publisher, game_id)), None);
+    }
+
+    #[benchmark]
+    fn purchase_settle() {
+        let caller = whitelisted_caller();
+        let publisher = get_account::<T>(0);
+        prefund_account::<T>(&publisher);
+        let buyer = get_account::<T>(1);
+        prefund_account::<T>(&buyer);
+        let game_id = 10;
+        let price = CurrencyOf::<T>::from(2_000_000_000u32);
+        T::Currency::transfer_and_hold(
+            &HoldReason::RefundablePayment.into(),
+            &buyer,
+            &publisher,
+            price,
+            Exact,
+            Preserve,
+            Polite,
+        )
+        .expect("the buyer is funded; qed");
+        let refundable_until = frame_system::Pallet::<T>::block_number();
+        let purchase = PurchaseDetails { payment: price, refundable_until, max_downloads: 1 };
+        Purchases::<T>::insert(&buyer, (&publisher, game_id), purchase);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), buyer.clone(), publisher.clone(), game_id);
+
+        assert_eq!(Purchases::<T>::get(&buyer, (&publisher, game_id)), None);
+    }
+
+    #[benchmark]
+    fn catalog_transfer_step() {
+        let publisher = get_account::<T>(0);
+        let new_publisher = get_account::<T>(1);
+        let game_id = 10;
+        let game_details = GameDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            tags: bounded_vec(&vec![TagId::default(); MAX_TAGS_PER_GAME as usize]),
+            distribution: Distribution::Individual { price: CurrencyOf::<T>::from(1_000u32) },
+        };
+        PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        GameStatuses::<T>::insert(&publisher, game_id, GameStatus::Delisted);
+        RefundPolicies::<T>::insert(
+            &publisher,
+            game_id,
+            RefundPolicy { window: T::OrderTimeout::get(), max_downloads: 1 },
+        );
+        LatestRelease::<T>::insert(&publisher, game_id, SemVer::new(1, 0, 0));
+        CatalogTransfers::<T>::insert(&publisher, &new_publisher);
+
+        #[block]
+        {
+            Games::<T>::transfer_catalog_step(publisher.clone(), new_publisher.clone());
+        }
+
+        assert!(PublishedGames::<T>::contains_key(&new_publisher, game_id));
+        assert_eq!(GameStatuses::<T>::get(&new_publisher, game_id), GameStatus::Delisted);
+    }
+
+    #[benchmark]
+    fn tag_add() -> Result<(), BenchmarkError> {
+        let origin =
+            T::TagOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let tag_id = TagId::MAX;
+        let name: Tag = bounded_vec(&vec![b'a'; MAX_TAG_SIZE as usize]);
+        let parent = TagId::MAX - 1;
+        Tags::<T>::insert(
+            parent,
+            TagDetails::new(
+                bounded_vec(&vec![b'b'; MAX_TAG_SIZE as usize]),
+                TagCategory::Genre,
+                None,
+            ),
+        );
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, tag_id, name.clone(), TagCategory::SubGenre, Some(parent));
+
+        assert_eq!(
+            Tags::<T>::get(tag_id),
+            Some(TagDetails::new(name, TagCategory::SubGenre, Some(parent)))
+        );
+        assert_eq!(TagChildren::<T>::get(parent), 1);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn tag_rename() -> Result<(), BenchmarkError> {
+        let origin =
+            T::TagOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let tag_id = TagId::MAX;
+        let previous: Tag = bounded_vec(&vec![b'a'; MAX_TAG_SIZE as usize]);
+        Tags::<T>::insert(tag_id, TagDetails::new(previous.clone(), TagCategory::Genre, None));
+        TagNames::<T>::insert(&previous, tag_id);
+        let name: Tag = bounded_vec(&vec![b'b'; MAX_TAG_SIZE as usize]);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, tag_id, name.clone());
+
+        assert_eq!(Tags::<T>::get(tag_id).map(|tag| tag.name), Some(name));
+        assert!(!TagNames::<T>::contains_key(&previous));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn tag_deprecate() -> Result<(), BenchmarkError> {
+        let origin =
+            T::TagOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let tag_id = TagId::MAX;
+        Tags::<T>::insert(
+            tag_id,
+            TagDetails::new(
+                bounded_vec(&vec![b'a'; MAX_TAG_SIZE as usize]),
+                TagCategory::Genre,
+                None,
+            ),
+        );
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, tag_id);
+
+        assert_eq!(Tags::<T>::get(tag_id).map(|tag| tag.status), Some(TagStatus::Deprecated));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn tag_merge() -> Result<(), BenchmarkError> {
+        let origin =
+            T::TagOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let from = TagId::MAX - 1;
+        let into = TagId::MAX;
+        Tags::<T>::insert(
+            from,
+            TagDetails::new(
+                bounded_vec(&vec![b'a'; MAX_TAG_SIZE as usize]),
+                TagCategory::Genre,
+                None,
+            ),
+        );
+        Tags::<T>::insert(
+            into,
+            TagDetails::new(
+                bounded_vec(&vec![b'b'; MAX_TAG_SIZE as usize]),
+                TagCategory::Genre,
+                None,
+            ),
+        );
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, from, into);
+
+        assert_eq!(TagMerge::<T>::get(), Some((from, into)));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn tag_merge_step() {
+        let publisher = get_account::<T>(0);
+        let game_id = 10;
+        let from = 0;
+        let into = MAX_TAGS_PER_GAME as TagId;
+        let game_details = GameDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            tags: bounded_vec(&(0..MAX_TAGS_PER_GAME as TagId).collect::<Vec<_>>()),
+            distribution: Distribution::Individual { price: CurrencyOf::<T>::from(1_000u32) },
+        };
+        PublishedGames::<T>::insert(&publisher, game_id, game_details);
+        TagMerge::<T>::put((from, into));
+
+        #[block]
+        {
+            Games::<T>::tag_merge_step(from, into);
+        }
+
+        let details = PublishedGames::<T>::get(&publisher, game_id).expect("game exists; qed");
+        assert_eq!(details.tags.first(), Some(&into));
+        assert!(GamesByTag::<T>::contains_key(into, (&publisher, game_id)));
+        assert_eq!(TagMergeCursor::<T>::get(), Some((publisher, game_id)));
+    }
+
+    #[benchmark]
+    fn tag_classify() -> Result<(), BenchmarkError> {
+        let origin =
+            T::TagOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let tag_id = TagId::MAX;
+        let previous = TagId::MAX - 1;
+        let parent = TagId::MAX - 2;
+        Tags::<T>::insert(
+            tag_id,
+            TagDetails::new(
+                bounded_vec(&vec![b'a'; MAX_TAG_SIZE as usize]),
+                TagCategory::Genre,
+                Some(previous),
+            ),
+        );
+        TagChildren::<T>::insert(previous, 1);
+        Tags::<T>::insert(
+            parent,
+            TagDetails::new(
+                bounded_vec(&vec![b'b'; MAX_TAG_SIZE as usize]),
+                TagCategory::Genre,
+                None,
+            ),
+        );
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, tag_id, TagCategory::SubGenre, Some(parent));
+
+        let details = Tags::<T>::get(tag_id).expect("tag exists; qed");
+        assert_eq!((details.category, details.parent), (TagCategory::SubGenre, Some(parent)));
+        assert_eq!(TagChildren::<T>::get(previous), 0);
+        assert_eq!(TagChildren::<T>::get(parent), 1);
+        Ok(())
     }
 
     impl_benchmark_test_suite!(Games, mock::new_test_ext(), mock::Test);
@@ -3,22 +3,33 @@
 
 use frame_support::{
     pallet_prelude::*,
+    sp_runtime::traits::{Saturating, Zero},
     traits::{
-        fungible::{hold::Mutate as FunHoldMutate, Inspect as FunInspect, Mutate as FunMutate},
+        fungible::{
+            hold::{Inspect as FunHoldInspect, Mutate as FunHoldMutate},
+            Inspect as FunInspect, Mutate as FunMutate,
+        },
         tokens::{
-            Fortitude::Polite, Precision::BestEffort, Preservation::Preserve, Restriction::Free,
+            Fortitude::{Force, Polite},
+            Precision::{BestEffort, Exact},
+            Preservation::Preserve,
+            Restriction::{Free, OnHold},
         },
     },
+    weights::WeightMeter,
 };
 use frame_system::pallet_prelude::*;
 use liganite_primitives::{
-    publisher::PublisherManager,
-    tags::TAGS,
+    publisher::{PublisherCatalog, PublisherManager},
+    tags::{RETIRED_TAGS, TAGS},
     types::{
-        AccountIdOf, BuyerId, Cid, Distribution, GameDetails, GameId, GlobalGameId, OrderDetails,
-        PublisherId, Tag, TagId,
+        AccountIdOf, BuyerId, Cid, Delivery, Distribution, GameDetails, GameDetailsUpdate, GameId,
+        GameStatus, GlobalGameId, OrderDetails, PublisherId, PublisherRole, PurchaseDetails,
+        RefundPolicy, RejectionReason, ReleaseDetails, SemVer, Tag, TagCategory, TagDetails, TagId,
+        TagStatus,
     },
 };
+use scale_info::prelude::vec::Vec;
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
@@ -31,36 +42,91 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
 type CurrencyOf<T> = <<T as Config>::Currency as FunInspect<AccountIdOf<T>>>::Balance;
 type GameDetailsOf<T> = GameDetails<CurrencyOf<T>>;
-type OrderDetailsOf<T> = OrderDetails<CurrencyOf<T>>;
+type GameDetailsUpdateOf<T> = GameDetailsUpdate<CurrencyOf<T>>;
+type OrderDetailsOf<T> = OrderDetails<CurrencyOf<T>, BlockNumberFor<T>>;
+type ReleaseDetailsOf<T> = ReleaseDetails<BlockNumberFor<T>>;
+type RefundPolicyOf<T> = RefundPolicy<BlockNumberFor<T>>;
+type PurchaseDetailsOf<T> = PurchaseDetails<CurrencyOf<T>, BlockNumberFor<T>>;
+
+/// The maximum number of entries returned by a single page of a view function.
+pub const MAX_PAGE_SIZE: u32 = 100;
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
-        #[serde(skip)]
-        _marker: PhantomData<T>,
+        /// The tags added to the predefined ones, as (tag id, name, category, parent). A parent
+        /// must be listed before the tags refining it.
+        pub tags: Vec<(TagId, Tag, TagCategory, Option<TagId>)>,
+        /// The initial games, which are active. Their publishers must be registered in genesis.
+        pub games: Vec<(PublisherId<T>, GameId, GameDetailsOf<T>)>,
+        /// The initial games in the collection of buyers, as (buyer, publisher, game id).
+        pub owned_games: Vec<(BuyerId<T>, PublisherId<T>, GameId)>,
     }
 
-    /// Build genesis storage. The tag storage is populated here.
+    /// Build genesis storage. The tags, the games and the collections of the buyers are populated
+    /// here.
     #[pallet::genesis_build]
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
-            TAGS.iter().enumerate().for_each(|(i, tag)| {
+            TAGS.iter().for_each(|(tag_id, tag, category)| {
                 let tag = Tag::try_from(tag.as_bytes().to_vec())
-                    .expect("Failed to create tag at genesis build");
-                Tags::<T>::insert(i as TagId, tag);
-            })
+                    .expect("The tag sizes are checked at compile time; qed");
+                TagNames::<T>::insert(&tag, tag_id);
+                Tags::<T>::insert(tag_id, TagDetails::new(tag, *category, None));
+            });
+
+            for (tag_id, name, category, parent) in &self.tags {
+                assert!(!Tags::<T>::contains_key(tag_id), "A genesis tag id is already used");
+                assert!(!TagNames::<T>::contains_key(name), "A genesis tag name is already used");
+                let details = TagDetails::new(name.clone(), *category, *parent);
+                assert!(details.is_valid(), "The name of a genesis tag is invalid");
+                Pallet::<T>::ensure_valid_parent(*tag_id, *parent)
+                    .expect("The parent of a genesis tag is invalid");
+                Pallet::<T>::insert_tag(*tag_id, details);
+            }
+
+            for (publisher, game_id, details) in &self.games {
+                assert!(
+                    T::PublisherManager::is_valid_publisher(publisher),
+                    "The publisher of a genesis game is invalid"
+                );
+                assert!(
+                    !PublishedGames::<T>::contains_key(publisher, game_id),
+                    "A genesis game is duplicated"
+                );
+                assert!(
+                    details.is_valid(Pallet::<T>::is_active_tag),
+                    "The details of a genesis game are invalid"
+                );
+                Pallet::<T>::ensure_tag_rules(&details.tags)
+                    .expect("The tags of a genesis game break the tag rules");
+                Pallet::<T>::insert_game(publisher, *game_id, details.clone());
+            }
+
+            for (buyer, publisher, game_id) in &self.owned_games {
+                assert!(
+                    PublishedGames::<T>::contains_key(publisher, game_id),
+                    "A genesis owned game is not found"
+                );
+                OwnedGames::<T>::insert(buyer, (publisher, game_id), ());
+            }
         }
     }
 
@@ -69,6 +135,8 @@ pub mod pallet {
     pub enum HoldReason {
         /// The game payment.
         GamePayment,
+        /// The payment of an instant purchase which can still be refunded.
+        RefundablePayment,
     }
 
     /// The pallet's configuration trait.
@@ -89,6 +157,22 @@ pub mod pallet {
 
         /// Used to operate on publishers.
         type PublisherManager: PublisherManager<PublisherId = PublisherId<Self>>;
+
+        /// The number of blocks after which a pending order expires and the deposit held from
+        /// the buyer is released.
+        #[pallet::constant]
+        type OrderTimeout: Get<BlockNumberFor<Self>>;
+
+        /// The origin which may add, rename, classify, deprecate and merge tags.
+        type TagOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The maximum number of top-level genres a game can be tagged with.
+        #[pallet::constant]
+        type MaxTopLevelGenres: Get<u32>;
+
+        /// The minimum number of genres, of any level, a game must be tagged with.
+        #[pallet::constant]
+        type MinGenres: Get<u32>;
     }
 
     /// Storage for the game details. Is a map of PublisherId -> GameId -> GameDetails.
@@ -103,22 +187,138 @@ pub mod pallet {
         OptionQuery,
     >;
 
-    /// Storage for the game tags. Is a map of TagId -> Tag.
+    /// Storage for the number of games of a publisher. Is a map of PublisherId -> u32.
     #[pallet::storage]
-    pub type Tags<T> = CountedStorageMap<_, Blake2_128Concat, TagId, Tag, OptionQuery>;
+    pub type GamesCount<T> = StorageMap<_, Twox64Concat, PublisherId<T>, u32, ValueQuery>;
 
-    /// Storage for the game orders. Is a map of PublisherId -> GameId -> BuyerId.
+    /// Storage for the game lifecycle statuses. Is a map of PublisherId -> GameId -> GameStatus.
     #[pallet::storage]
-    pub type PublisherOrders<T> = StorageDoubleMap<
+    pub type GameStatuses<T> = StorageDoubleMap<
         _,
         Twox64Concat,
         PublisherId<T>,
         Blake2_128Concat,
         GameId,
-        BuyerId<T>,
+        GameStatus,
+        ValueQuery,
+    >;
+
+    /// Storage for the game releases. Is a map of (PublisherId, GameId, SemVer) -> ReleaseDetails.
+    ///
+    /// The releases which are not yanked are linked together from the oldest to the latest one.
+    #[pallet::storage]
+    pub type Releases<T> = StorageNMap<
+        _,
+        (
+            NMapKey<Twox64Concat, PublisherId<T>>,
+            NMapKey<Blake2_128Concat, GameId>,
+            NMapKey<Blake2_128Concat, SemVer>,
+        ),
+        ReleaseDetailsOf<T>,
+        OptionQuery,
+    >;
+
+    /// Storage for the latest release of a game which is not yanked. Is a map of PublisherId ->
+    /// GameId -> SemVer.
+    #[pallet::storage]
+    pub type LatestRelease<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PublisherId<T>,
+        Blake2_128Concat,
+        GameId,
+        SemVer,
+        OptionQuery,
+    >;
+
+    /// Storage for the highest version ever published for a game, whether it is yanked or not.
+    /// Is a map of PublisherId -> GameId -> SemVer.
+    #[pallet::storage]
+    pub type HighestRelease<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PublisherId<T>,
+        Blake2_128Concat,
+        GameId,
+        SemVer,
+        OptionQuery,
+    >;
+
+    /// Storage for the refund policies of instant games. Is a map of PublisherId -> GameId ->
+    /// RefundPolicy.
+    #[pallet::storage]
+    pub type RefundPolicies<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PublisherId<T>,
+        Blake2_128Concat,
+        GameId,
+        RefundPolicyOf<T>,
+        OptionQuery,
+    >;
+
+    /// Storage for the game tags. Is a map of TagId -> TagDetails.
+    #[pallet::storage]
+    pub type Tags<T> = CountedStorageMap<_, Blake2_128Concat, TagId, TagDetails, OptionQuery>;
+
+    /// Storage for the tag names, keeping them unique. Is a map of Tag -> TagId.
+    #[pallet::storage]
+    pub type TagNames<T> = StorageMap<_, Blake2_128Concat, Tag, TagId, OptionQuery>;
+
+    /// Storage for the number of tags refining a tag. Is a map of TagId -> u32.
+    #[pallet::storage]
+    pub type TagChildren<T> = StorageMap<_, Blake2_128Concat, TagId, u32, ValueQuery>;
+
+    /// Storage for the active games by tag, to browse the catalogue. Is a map of TagId ->
+    /// GlobalGameId -> ().
+    #[pallet::storage]
+    pub type GamesByTag<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        TagId,
+        Blake2_128Concat,
+        GlobalGameId<T>,
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage for the tag merge in progress, whose games are being moved from the first tag to
+    /// the second one.
+    #[pallet::storage]
+    pub type TagMerge<T> = StorageValue<_, (TagId, TagId), OptionQuery>;
+
+    /// Storage for the last game whose tags have been merged by the tag merge in progress.
+    #[pallet::storage]
+    pub type TagMergeCursor<T> = StorageValue<_, GlobalGameId<T>, OptionQuery>;
+
+    /// Storage for the game orders. Is a map of (PublisherId, GameId, BuyerId) -> ().
+    ///
+    /// Every buyer with a pending order for a game has its own entry, so a publisher can iterate
+    /// the pending orders of a game by prefix.
+    #[pallet::storage]
+    pub type PublisherOrders<T> = StorageNMap<
+        _,
+        (
+            NMapKey<Twox64Concat, PublisherId<T>>,
+            NMapKey<Blake2_128Concat, GameId>,
+            NMapKey<Twox64Concat, BuyerId<T>>,
+        ),
+        (),
         OptionQuery,
     >;
 
+    /// Storage for the number of pending orders. Is a map of PublisherId -> GameId -> u32.
+    #[pallet::storage]
+    pub type PendingOrdersCount<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PublisherId<T>,
+        Blake2_128Concat,
+        GameId,
+        u32,
+        ValueQuery,
+    >;
+
     /// Storage for the game orders. Is a map of BuyerId -> GlobalGameId -> OrderDetails.
     #[pallet::storage]
     pub type BuyerOrders<T> = StorageDoubleMap<
@@ -131,6 +331,58 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Storage for the order expiries. Is a map of (BlockNumber, BuyerId, GlobalGameId) -> ().
+    ///
+    /// Every pending order has an entry under the block at which it expires, so the expired
+    /// orders can be found without iterating all the orders.
+    #[pallet::storage]
+    pub type OrderExpiries<T> = StorageNMap<
+        _,
+        (
+            NMapKey<Twox64Concat, BlockNumberFor<T>>,
+            NMapKey<Twox64Concat, BuyerId<T>>,
+            NMapKey<Blake2_128Concat, GlobalGameId<T>>,
+        ),
+        (),
+        OptionQuery,
+    >;
+
+    /// Storage for the first block whose expired orders have not been processed yet.
+    #[pallet::storage]
+    pub type ExpiryCursor<T> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+    /// Storage for the catalogs which are being moved to the new account of their publisher. Is
+    /// a map of PublisherId -> PublisherId.
+    #[pallet::storage]
+    pub type CatalogTransfers<T> =
+        StorageMap<_, Twox64Concat, PublisherId<T>, PublisherId<T>, OptionQuery>;
+
+    /// Storage for the deliveries of fulfilled orders. Is a map of BuyerId -> GlobalGameId ->
+    /// Delivery.
+    #[pallet::storage]
+    pub type Deliveries<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BuyerId<T>,
+        Blake2_128Concat,
+        GlobalGameId<T>,
+        Delivery,
+        OptionQuery,
+    >;
+
+    /// Storage for the instant purchases which are not settled yet. Is a map of BuyerId ->
+    /// GlobalGameId -> PurchaseDetails.
+    #[pallet::storage]
+    pub type Purchases<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        BuyerId<T>,
+        Blake2_128Concat,
+        GlobalGameId<T>,
+        PurchaseDetailsOf<T>,
+        OptionQuery,
+    >;
+
     /// Storage for the game ownership. Is a map of BuyerId -> GlobalGameId -> ().
     #[pallet::storage]
     pub type OwnedGames<T> = StorageDoubleMap<
@@ -154,6 +406,55 @@ pub mod pallet {
             /// The game id.
             game_id: GameId,
         },
+        /// A game has been updated.
+        GameUpdated {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The fields of the game which changed, holding their new values.
+            diff: GameDetailsUpdateOf<T>,
+        },
+        /// The status of a game has changed.
+        GameStatusChanged {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The previous status of the game.
+            from: GameStatus,
+            /// The new status of the game.
+            to: GameStatus,
+        },
+        /// A new release of a game has been published.
+        ReleasePublished {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The version of the release.
+            version: SemVer,
+            /// The CID of the release that can be downloaded.
+            cid: Cid,
+        },
+        /// A release of a game has been yanked.
+        ReleaseYanked {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The version of the release.
+            version: SemVer,
+        },
+        /// The refund policy of a game has been set.
+        RefundPolicySet {
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The new refund policy, if any.
+            policy: Option<RefundPolicyOf<T>>,
+        },
         /// A game has been purchased.
         GamePurchased {
             /// The buyer of the game.
@@ -162,8 +463,33 @@ pub mod pallet {
             publisher: PublisherId<T>,
             /// The game id.
             game_id: GameId,
-            /// The CID of the game that can be downloaded.
-            cid: Cid,
+            /// The delivery of the game, holding the CID that can be downloaded.
+            delivery: Delivery,
+        },
+        /// An instant purchase has been refunded to the buyer.
+        PurchaseRefunded {
+            /// The buyer of the game.
+            buyer: BuyerId<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+        },
+        /// An instant purchase has been settled to the publisher.
+        PurchaseSettled {
+            /// The buyer of the game.
+            buyer: BuyerId<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+        },
+        /// The catalog of a publisher has been moved to their new account.
+        CatalogTransferred {
+            /// The previous account of the publisher.
+            publisher: PublisherId<T>,
+            /// The new account of the publisher.
+            new_publisher: PublisherId<T>,
         },
         /// An order has been placed.
         OrderPlaced {
@@ -192,6 +518,77 @@ pub mod pallet {
             /// The game id.
             game_id: GameId,
         },
+        /// An order has been refunded because the game was retired.
+        OrderRefunded {
+            /// The buyer of the game.
+            buyer: BuyerId<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+        },
+        /// An order has been rejected by the publisher.
+        OrderRejected {
+            /// The buyer of the game.
+            buyer: BuyerId<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+            /// The reason the order was rejected for.
+            reason: RejectionReason,
+        },
+        /// An order has expired and the deposit has been released to the buyer.
+        OrderExpired {
+            /// The buyer of the game.
+            buyer: BuyerId<T>,
+            /// The publisher of the game.
+            publisher: PublisherId<T>,
+            /// The game id.
+            game_id: GameId,
+        },
+        /// A tag has been added.
+        TagAdded {
+            /// The tag id.
+            tag_id: TagId,
+            /// The name of the tag.
+            name: Tag,
+        },
+        /// A tag has been renamed.
+        TagRenamed {
+            /// The tag id.
+            tag_id: TagId,
+            /// The new name of the tag.
+            name: Tag,
+        },
+        /// A tag has been deprecated and cannot be given to games anymore.
+        TagDeprecated {
+            /// The tag id.
+            tag_id: TagId,
+        },
+        /// The games of a tag are being moved to another tag.
+        TagMergeStarted {
+            /// The tag which is merged.
+            from: TagId,
+            /// The tag the games are moved to.
+            into: TagId,
+        },
+        /// A tag has been merged into another tag and removed.
+        TagMerged {
+            /// The tag which has been merged.
+            from: TagId,
+            /// The tag the games have been moved to.
+            into: TagId,
+        },
+        /// The category or the parent of a tag has been changed.
+        TagClassified {
+            /// The identifier of the tag.
+            tag_id: TagId,
+            /// The new category of the tag.
+            category: TagCategory,
+            /// The new parent of the tag.
+            parent: Option<TagId>,
+        },
     }
 
     /// Errors.
@@ -209,6 +606,84 @@ pub mod pallet {
         OrderAlreadyPlaced,
         /// The order is not found.
         OrderNotFound,
+        /// The delivery is invalid.
+        DeliveryInvalid,
+        /// The game update does not change anything.
+        GameNotChanged,
+        /// The game is not active, so it cannot be purchased.
+        GameNotActive,
+        /// The game has been retired.
+        GameRetired,
+        /// The game cannot move to the requested status.
+        InvalidStatusTransition,
+        /// The game has more pending orders than the given witness.
+        TooManyPendingOrders,
+        /// The release details are invalid.
+        ReleaseDetailsInvalid,
+        /// The release already exists.
+        ReleaseAlreadyExists,
+        /// The release version is not greater than the latest release.
+        ReleaseVersionTooLow,
+        /// The release is not found.
+        ReleaseNotFound,
+        /// The release has already been yanked.
+        ReleaseAlreadyYanked,
+        /// The order has not expired yet.
+        OrderNotExpired,
+        /// The rejection reason is invalid.
+        RejectionReasonInvalid,
+        /// The refund policy is invalid.
+        RefundPolicyInvalid,
+        /// The purchase is not found, or has already been settled.
+        PurchaseNotFound,
+        /// The refund window of the purchase has closed.
+        RefundWindowClosed,
+        /// The refund window of the purchase is still open.
+        RefundWindowOpen,
+        /// The buyer has downloaded the game too many times for a refund.
+        TooManyDownloads,
+        /// The publisher is suspended.
+        PublisherSuspended,
+        /// The member does not have the role required for the call.
+        NoPermission,
+        /// The catalog of the publisher is being moved to or from another account.
+        CatalogTransferInProgress,
+        /// The tag already exists.
+        TagAlreadyExists,
+        /// The tag is not found.
+        TagNotFound,
+        /// The tag name is invalid.
+        TagNameInvalid,
+        /// The tag name is already used by another tag.
+        TagNameTaken,
+        /// The tag is deprecated.
+        TagDeprecated,
+        /// A tag cannot be merged into itself.
+        TagMergeInvalid,
+        /// Another tag merge is in progress.
+        TagMergeInProgress,
+        /// The parent of a tag must be another active tag which has no parent itself, and a tag
+        /// refined by other tags cannot have a parent.
+        TagParentInvalid,
+        /// A tag which is refined by other tags cannot be merged.
+        TagHasChildren,
+        /// The game has more top-level genres than allowed.
+        TooManyTopLevelGenres,
+        /// The game does not have enough genres.
+        GenreRequired,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Expires the pending orders which have timed out, moves the catalogs which are being
+        /// transferred and merges the tags, as long as there is weight left.
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let mut meter = WeightMeter::with_limit(remaining_weight);
+            Self::expire_orders(now, &mut meter);
+            Self::transfer_catalogs(&mut meter);
+            Self::merge_tags(&mut meter);
+            meter.consumed()
+        }
     }
 
     /// Dispatchable functions ([`Call`]s).
@@ -227,7 +702,11 @@ pub mod pallet {
             game_id: GameId,
             details: GameDetailsOf<T>,
         ) -> DispatchResult {
-            let publisher = ensure_signed(origin)?;
+            let publisher = Self::ensure_member(origin, PublisherRole::CatalogManager)?;
+            ensure!(
+                !T::PublisherManager::is_suspended_publisher(&publisher),
+                Error::<T>::PublisherSuspended
+            );
             ensure!(
                 T::PublisherManager::is_valid_publisher(&publisher),
                 Error::<T>::InvalidPublisher
@@ -236,12 +715,10 @@ pub mod pallet {
                 !PublishedGames::<T>::contains_key(&publisher, game_id),
                 Error::<T>::GameAlreadyExists
             );
-            ensure!(
-                details.is_valid(|x| Tags::<T>::contains_key(x)),
-                Error::<T>::GameDetailsInvalid
-            );
+            ensure!(details.is_valid(Self::is_active_tag), Error::<T>::GameDetailsInvalid);
+            Self::ensure_tag_rules(&details.tags)?;
 
-            PublishedGames::<T>::insert(&publisher, game_id, details);
+            Self::insert_game(&publisher, game_id, details);
 
             Self::deposit_event(Event::GameAdded { publisher, game_id });
             Ok(())
@@ -252,8 +729,11 @@ pub mod pallet {
         /// This function purchases by a way depending on the game's distribution. If the game is
         /// distributed free of charge, the game is added to the buyer's collection. If the game
         /// supports instant distribution, the game is added to the buyer's collection and the
-        /// payment is sent to the publisher. If the game supports delayed distribution, an order is
-        /// created and the payment is sent to the publisher.
+        /// payment is sent to the publisher, or held for the publisher until the refund window
+        /// closes if the game has a refund policy. If the game supports delayed distribution, an
+        /// order is created and the payment is sent to the publisher. Games of a suspended,
+        /// deregistered or under-deposited publisher cannot be purchased, and the games of a
+        /// transferred publisher are purchased from its current account.
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::game_buy())]
         pub fn game_buy(
@@ -262,39 +742,88 @@ pub mod pallet {
             game_id: GameId,
         ) -> DispatchResult {
             let buyer = ensure_signed(origin)?;
+            // The games of a transferred publisher are sold by their current account
+            let publisher = T::PublisherManager::current_publisher(&publisher);
+            Self::ensure_not_transferring(&publisher)?;
             ensure!(
                 !BuyerOrders::<T>::contains_key(&buyer, (&publisher, game_id)),
                 Error::<T>::OrderAlreadyPlaced
             );
             ensure!(
-                !OwnedGames::<T>::contains_key(&buyer, (&publisher, game_id)),
+                !Self::owns_game(buyer.clone(), publisher.clone(), game_id),
                 Error::<T>::GameAlreadyExists
             );
 
             let game_details =
                 PublishedGames::<T>::get(&publisher, game_id).ok_or(Error::<T>::GameNotFound)?;
+            ensure!(
+                GameStatuses::<T>::get(&publisher, game_id).is_active(),
+                Error::<T>::GameNotActive
+            );
+            ensure!(
+                !T::PublisherManager::is_suspended_publisher(&publisher),
+                Error::<T>::PublisherSuspended
+            );
+            ensure!(
+                T::PublisherManager::is_valid_publisher(&publisher),
+                Error::<T>::InvalidPublisher
+            );
 
             match game_details.distribution {
                 Distribution::Free { cid } => {
                     // Simply add the game to a buyer's collection
                     OwnedGames::<T>::insert(&buyer, (&publisher, game_id), ());
 
-                    Self::deposit_event(Event::GamePurchased { buyer, publisher, game_id, cid });
+                    Self::deposit_event(Event::GamePurchased {
+                        buyer,
+                        publisher,
+                        game_id,
+                        delivery: Delivery::Plain(cid),
+                    });
                 },
                 Distribution::Instant { price, cid } => {
-                    // Transfer money and add the game to a buyer's collection
-                    T::Currency::transfer(&buyer, &publisher, price, Preserve)?;
+                    match RefundPolicies::<T>::get(&publisher, game_id) {
+                        Some(policy) => {
+                            // Hold the payment for the publisher until the refund window closes
+                            T::Currency::transfer_and_hold(
+                                &HoldReason::RefundablePayment.into(),
+                                &buyer,
+                                &publisher,
+                                price,
+                                Exact,
+                                Preserve,
+                                Polite,
+                            )?;
+                            let refundable_until = frame_system::Pallet::<T>::block_number()
+                                .saturating_add(policy.window);
+                            let purchase = PurchaseDetails {
+                                payment: price,
+                                refundable_until,
+                                max_downloads: policy.max_downloads,
+                            };
+                            Purchases::<T>::insert(&buyer, (&publisher, game_id), purchase);
+                        },
+                        // Transfer money straight to the publisher
+                        None => T::Currency::transfer(&buyer, &publisher, price, Preserve)?,
+                    };
+                    // Add the game to a buyer's collection
                     OwnedGames::<T>::insert(&buyer, (&publisher, game_id), ());
 
-                    Self::deposit_event(Event::GamePurchased { buyer, publisher, game_id, cid });
+                    Self::deposit_event(Event::GamePurchased {
+                        buyer,
+                        publisher,
+                        game_id,
+                        delivery: Delivery::Plain(cid),
+                    });
                 },
                 Distribution::Individual { price } => {
                     // Place an order
                     T::Currency::hold(&HoldReason::GamePayment.into(), &buyer, price)?;
 
-                    let order = OrderDetails { deposit: price };
-                    BuyerOrders::<T>::insert(&buyer, (&publisher, game_id), &order);
-                    PublisherOrders::<T>::insert(&publisher, game_id, &buyer);
+                    let expires_at = frame_system::Pallet::<T>::block_number()
+                        .saturating_add(T::OrderTimeout::get());
+                    let order = OrderDetails { deposit: price, expires_at };
+                    Self::insert_order(&buyer, &publisher, game_id, &order);
 
                     Self::deposit_event(Event::OrderPlaced { buyer, publisher, game_id });
                 },
@@ -317,18 +846,7 @@ pub mod pallet {
         ) -> DispatchResult {
             let buyer = ensure_signed(origin)?;
 
-            let order = BuyerOrders::<T>::get(&buyer, (&publisher, game_id))
-                .ok_or(Error::<T>::OrderNotFound)?;
-
-            T::Currency::release(
-                &HoldReason::GamePayment.into(),
-                &buyer,
-                order.deposit,
-                BestEffort,
-            )?;
-
-            BuyerOrders::<T>::remove(&buyer, (&publisher, game_id));
-            PublisherOrders::<T>::remove(&publisher, game_id);
+            Self::release_order(&buyer, &publisher, game_id)?;
 
             Self::deposit_event(Event::OrderCancelled { buyer, publisher, game_id });
             Ok(())
@@ -339,15 +857,19 @@ pub mod pallet {
         /// This function is triggered by the publisher when they want to fulfill an order.
         /// It checks that the order exists, transfers the deposit from the buyer to the publisher,
         /// and then removes the order from the system, adding the game to the owned games list
-        /// for the buyer. A `OrderFulfilled` event is emitted once the order is fulfilled.
+        /// for the buyer. The delivery is either a plain CID or a CID encrypted to the buyer's
+        /// public key, and is stored in the `Deliveries` storage. A `OrderFulfilled` event followed
+        /// by a `GamePurchased` event are emitted once the order is fulfilled.
         #[pallet::call_index(3)]
         #[pallet::weight(T::WeightInfo::order_fulfill())]
         pub fn order_fulfill(
             origin: OriginFor<T>,
             game_id: GameId,
             buyer: BuyerId<T>,
+            delivery: Delivery,
         ) -> DispatchResult {
-            let publisher = ensure_signed(origin)?;
+            let publisher = Self::ensure_member(origin, PublisherRole::OrderFulfiller)?;
+            ensure!(delivery.is_valid(), Error::<T>::DeliveryInvalid);
 
             let order = BuyerOrders::<T>::get(&buyer, (&publisher, game_id))
                 .ok_or(Error::<T>::OrderNotFound)?;
@@ -362,14 +884,1123 @@ pub mod pallet {
                 Polite,
             )?;
 
-            BuyerOrders::<T>::remove(&buyer, (&publisher, game_id));
-            PublisherOrders::<T>::remove(&publisher, game_id);
+            Self::remove_order(&buyer, &publisher, game_id, &order);
             OwnedGames::<T>::insert(&buyer, (&publisher, game_id), ());
+            Deliveries::<T>::insert(&buyer, (&publisher, game_id), &delivery);
+
+            Self::deposit_event(Event::OrderFulfilled {
+                buyer: buyer.clone(),
+                publisher: publisher.clone(),
+                game_id,
+            });
+            Self::deposit_event(Event::GamePurchased { buyer, publisher, game_id, delivery });
+            Ok(())
+        }
+
+        /// Updates the details of a game.
+        ///
+        /// This function is triggered by the publisher when they want to change the name, the
+        /// tags or the distribution of a game. Only the fields which are set in the update are
+        /// changed, and the resulting details must be valid. Pending orders keep the deposit held
+        /// when they were placed: the publisher fulfills them at the original price, and buyers
+        /// who disagree with the new terms can still cancel them. A `GameUpdated` event holding the
+        /// changed fields is emitted once the game is updated.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::game_update())]
+        pub fn game_update(
+            origin: OriginFor<T>,
+            game_id: GameId,
+            update: GameDetailsUpdateOf<T>,
+        ) -> DispatchResult {
+            let publisher = Self::ensure_member(origin, PublisherRole::CatalogManager)?;
+            ensure!(
+                T::PublisherManager::is_valid_publisher(&publisher),
+                Error::<T>::InvalidPublisher
+            );
 
-            Self::deposit_event(Event::OrderFulfilled { buyer, publisher, game_id });
+            let mut details =
+                PublishedGames::<T>::get(&publisher, game_id).ok_or(Error::<T>::GameNotFound)?;
+            let status = GameStatuses::<T>::get(&publisher, game_id);
+            ensure!(status != GameStatus::Retired, Error::<T>::GameRetired);
+            let tags = details.tags.clone();
+            let diff = update.apply(&mut details);
+            ensure!(!diff.is_empty(), Error::<T>::GameNotChanged);
+            // The game keeps the deprecated tags it already had
+            ensure!(
+                details.is_valid(|tag| Self::is_active_tag(tag) || tags.contains(tag)),
+                Error::<T>::GameDetailsInvalid
+            );
+            if details.tags != tags {
+                Self::ensure_tag_rules(&details.tags)?;
+            }
+            Self::apply_tag_merge(&mut details);
+
+            if status.is_active() {
+                Self::index_game_tags(&(publisher.clone(), game_id), &tags, &details.tags);
+            }
+            PublishedGames::<T>::insert(&publisher, game_id, details);
 
-            // TODO: deposit GamePurchased event
+            Self::deposit_event(Event::GameUpdated { publisher, game_id, diff });
             Ok(())
         }
+
+        /// Changes the status of a game.
+        ///
+        /// This function is triggered by the publisher when they want to hide, delist or list
+        /// again a game. Only active games can be purchased, while the games which are already
+        /// owned by buyers can still be downloaded whatever the status. Only a valid publisher can
+        /// list a game again. Retiring a game is done through `game_retire`. A
+        /// `GameStatusChanged` event is emitted once the status changes.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::game_status_set())]
+        pub fn game_status_set(
+            origin: OriginFor<T>,
+            game_id: GameId,
+            status: GameStatus,
+        ) -> DispatchResult {
+            let publisher = Self::ensure_member(origin, PublisherRole::CatalogManager)?;
+            ensure!(status != GameStatus::Retired, Error::<T>::InvalidStatusTransition);
+            ensure!(
+                !status.is_active() || T::PublisherManager::is_valid_publisher(&publisher),
+                Error::<T>::InvalidPublisher
+            );
+            ensure!(
+                PublishedGames::<T>::contains_key(&publisher, game_id),
+                Error::<T>::GameNotFound
+            );
+
+            Self::set_status(publisher, game_id, status)
+        }
+
+        /// Permanently retires a game.
+        ///
+        /// This function is triggered by the publisher when they want to stop selling a game for
+        /// good. Every pending order of the game is refunded, releasing the deposit held from the
+        /// buyer and emitting an `OrderRefunded` event. `pending_orders` is a witness of the
+        /// number of pending orders, which bounds the weight of the call. A `GameStatusChanged`
+        /// event is emitted once the game is retired.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::game_retire(*pending_orders))]
+        pub fn game_retire(
+            origin: OriginFor<T>,
+            game_id: GameId,
+            pending_orders: u32,
+        ) -> DispatchResultWithPostInfo {
+            let publisher = Self::ensure_member(origin, PublisherRole::CatalogManager)?;
+            ensure!(
+                PublishedGames::<T>::contains_key(&publisher, game_id),
+                Error::<T>::GameNotFound
+            );
+            let count = PendingOrdersCount::<T>::get(&publisher, game_id);
+            ensure!(count <= pending_orders, Error::<T>::TooManyPendingOrders);
+
+            Self::set_status(publisher.clone(), game_id, GameStatus::Retired)?;
+
+            for buyer in Self::pending_orders(&publisher, game_id) {
+                Self::release_order(&buyer, &publisher, game_id)?;
+                Self::deposit_event(Event::OrderRefunded {
+                    buyer,
+                    publisher: publisher.clone(),
+                    game_id,
+                });
+            }
+
+            Ok(Some(T::WeightInfo::game_retire(count)).into())
+        }
+
+        /// Publishes a new release of a game.
+        ///
+        /// This function is triggered by the publisher when they ship a new build of a game. The
+        /// release records the CID of the build, the CID of its changelog and the current block,
+        /// and its version must be greater than any release published before, even a yanked
+        /// one. Earlier releases are kept, so buyers can still download any of them. A
+        /// `ReleasePublished` event is emitted once the release is published.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::release_publish())]
+        pub fn release_publish(
+            origin: OriginFor<T>,
+            game_id: GameId,
+            version: SemVer,
+            cid: Cid,
+            changelog: Cid,
+        ) -> DispatchResult {
+            let publisher = Self::ensure_member(origin, PublisherRole::CatalogManager)?;
+            ensure!(
+                T::PublisherManager::is_valid_publisher(&publisher),
+                Error::<T>::InvalidPublisher
+            );
+            ensure!(
+                PublishedGames::<T>::contains_key(&publisher, game_id),
+                Error::<T>::GameNotFound
+            );
+            ensure!(
+                !Releases::<T>::contains_key((&publisher, game_id, version)),
+                Error::<T>::ReleaseAlreadyExists
+            );
+            ensure!(
+                HighestRelease::<T>::get(&publisher, game_id)
+                    .is_none_or(|highest| version > highest),
+                Error::<T>::ReleaseVersionTooLow
+            );
+
+            let previous = LatestRelease::<T>::get(&publisher, game_id);
+            let release = ReleaseDetails {
+                cid: cid.clone(),
+                changelog,
+                released_at: frame_system::Pallet::<T>::block_number(),
+                yanked: false,
+                previous,
+                next: None,
+            };
+            ensure!(release.is_valid(), Error::<T>::ReleaseDetailsInvalid);
+
+            if let Some(previous) = previous {
+                Releases::<T>::mutate((&publisher, game_id, previous), |release| {
+                    if let Some(release) = release {
+                        release.next = Some(version);
+                    }
+                });
+            }
+            Releases::<T>::insert((&publisher, game_id, version), release);
+            LatestRelease::<T>::insert(&publisher, game_id, version);
+            HighestRelease::<T>::insert(&publisher, game_id, version);
+
+            Self::deposit_event(Event::ReleasePublished { publisher, game_id, version, cid });
+            Ok(())
+        }
+
+        /// Yanks a release of a game.
+        ///
+        /// This function is triggered by the publisher when a release must not be installed any
+        /// more, e.g. because it is broken. The release is kept in the history, but it is unlinked
+        /// from the other releases, so that the latest release falls back to the previous one
+        /// which is not yanked. A `ReleaseYanked` event is emitted once the release is yanked.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::release_yank())]
+        pub fn release_yank(
+            origin: OriginFor<T>,
+            game_id: GameId,
+            version: SemVer,
+        ) -> DispatchResult {
+            let publisher = Self::ensure_member(origin, PublisherRole::CatalogManager)?;
+
+            let mut release = Releases::<T>::get((&publisher, game_id, version))
+                .ok_or(Error::<T>::ReleaseNotFound)?;
+            ensure!(!release.yanked, Error::<T>::ReleaseAlreadyYanked);
+
+            let (previous, next) = (release.previous.take(), release.next.take());
+            if let Some(previous) = previous {
+                Releases::<T>::mutate((&publisher, game_id, previous), |release| {
+                    if let Some(release) = release {
+                        release.next = next;
+                    }
+                });
+            }
+            match (next, previous) {
+                (Some(next), _) => Releases::<T>::mutate((&publisher, game_id, next), |release| {
+                    if let Some(release) = release {
+                        release.previous = previous;
+                    }
+                }),
+                (None, Some(previous)) => LatestRelease::<T>::insert(&publisher, game_id, previous),
+                (None, None) => LatestRelease::<T>::remove(&publisher, game_id),
+            }
+
+            release.yanked = true;
+            Releases::<T>::insert((&publisher, game_id, version), release);
+
+            Self::deposit_event(Event::ReleaseYanked { publisher, game_id, version });
+            Ok(())
+        }
+
+        /// Expires a pending order which has timed out.
+        ///
+        /// This function can be triggered by anyone once the order has expired, so the buyer does
+        /// not have to wait for the expired orders to be processed when the chain is busy. It
+        /// releases the deposit held from the buyer and removes the order. A `OrderExpired` event
+        /// is emitted once the order is successfully expired.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::order_expire())]
+        pub fn order_expire(
+            origin: OriginFor<T>,
+            buyer: BuyerId<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let order = BuyerOrders::<T>::get(&buyer, (&publisher, game_id))
+                .ok_or(Error::<T>::OrderNotFound)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(order.is_expired(&now), Error::<T>::OrderNotExpired);
+
+            Self::expire_order(buyer, publisher, game_id)
+        }
+
+        /// Rejects an order for a game.
+        ///
+        /// This function is triggered by the publisher when they cannot fulfill an order, e.g. when
+        /// the game is not available in the buyer's region. It checks that the order exists, and
+        /// then releases the deposit from the buyer. A `OrderRejected` event holding the reason is
+        /// emitted once the order is successfully rejected.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::order_reject())]
+        pub fn order_reject(
+            origin: OriginFor<T>,
+            game_id: GameId,
+            buyer: BuyerId<T>,
+            reason: RejectionReason,
+        ) -> DispatchResult {
+            let publisher = Self::ensure_member(origin, PublisherRole::OrderFulfiller)?;
+            ensure!(reason.is_valid(), Error::<T>::RejectionReasonInvalid);
+
+            Self::release_order(&buyer, &publisher, game_id)?;
+
+            Self::deposit_event(Event::OrderRejected { buyer, publisher, game_id, reason });
+            Ok(())
+        }
+
+        /// Sets the refund policy of a game.
+        ///
+        /// This function is triggered by the publisher to allow the buyers of an instantly
+        /// distributed game to ask for a refund within a number of blocks after the purchase. The
+        /// policy is removed by passing `None`. Existing purchases keep the policy they were made
+        /// under. A `RefundPolicySet` event is emitted once the policy is successfully set.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::refund_policy_set())]
+        pub fn refund_policy_set(
+            origin: OriginFor<T>,
+            game_id: GameId,
+            policy: Option<RefundPolicyOf<T>>,
+        ) -> DispatchResult {
+            let publisher = Self::ensure_member(origin, PublisherRole::Finance)?;
+            ensure!(
+                PublishedGames::<T>::contains_key(&publisher, game_id),
+                Error::<T>::GameNotFound
+            );
+            ensure!(policy.as_ref().is_none_or(|p| p.is_valid()), Error::<T>::RefundPolicyInvalid);
+
+            RefundPolicies::<T>::set(&publisher, game_id, policy.clone());
+
+            Self::deposit_event(Event::RefundPolicySet { publisher, game_id, policy });
+            Ok(())
+        }
+
+        /// Refunds an instant purchase of a game.
+        ///
+        /// This function is triggered by the buyer within the refund window of the purchase. The
+        /// buyer reports the number of times they downloaded the game, which must not exceed the
+        /// maximum allowed by the policy. The payment held for the publisher is returned to the
+        /// buyer and the game is removed from the buyer's collection. A `PurchaseRefunded` event is
+        /// emitted once the purchase is successfully refunded.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::purchase_refund())]
+        pub fn purchase_refund(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+            downloads: u32,
+        ) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+
+            let (purchased_id, purchase) = Self::find_purchase(&buyer, &publisher, game_id)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(purchase.is_refundable(&now), Error::<T>::RefundWindowClosed);
+            ensure!(downloads <= purchase.max_downloads, Error::<T>::TooManyDownloads);
+
+            // The payment is held on the current account of the publisher
+            let publisher = T::PublisherManager::current_publisher(&publisher);
+            T::Currency::transfer_on_hold(
+                &HoldReason::RefundablePayment.into(),
+                &publisher,
+                &buyer,
+                purchase.payment,
+                BestEffort,
+                Free,
+                Polite,
+            )?;
+
+            Purchases::<T>::remove(&buyer, &purchased_id);
+            OwnedGames::<T>::remove(&buyer, &purchased_id);
+
+            Self::deposit_event(Event::PurchaseRefunded { buyer, publisher, game_id });
+            Ok(())
+        }
+
+        /// Settles an instant purchase of a game.
+        ///
+        /// This function can be triggered by anyone once the refund window of the purchase has
+        /// closed, and is usually triggered by the publisher. It releases the payment held for the
+        /// publisher. A `PurchaseSettled` event is emitted once the purchase is successfully
+        /// settled.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::purchase_settle())]
+        pub fn purchase_settle(
+            origin: OriginFor<T>,
+            buyer: BuyerId<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let (purchased_id, purchase) = Self::find_purchase(&buyer, &publisher, game_id)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(!purchase.is_refundable(&now), Error::<T>::RefundWindowOpen);
+
+            // The payment is held on the current account of the publisher
+            let publisher = T::PublisherManager::current_publisher(&publisher);
+            T::Currency::release(
+                &HoldReason::RefundablePayment.into(),
+                &publisher,
+                purchase.payment,
+                BestEffort,
+            )?;
+
+            Purchases::<T>::remove(&buyer, &purchased_id);
+
+            Self::deposit_event(Event::PurchaseSettled { buyer, publisher, game_id });
+            Ok(())
+        }
+
+        /// Adds a new tag.
+        ///
+        /// This function allows the `TagOrigin` to add a tag under a new id, e.g. for a new genre.
+        /// The name of the tag must be a non-empty string which is not used by another tag. The
+        /// tag may refine a parent tag, which must be active and have no parent itself. A
+        /// `TagAdded` event is emitted once the tag is successfully added.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::tag_add())]
+        pub fn tag_add(
+            origin: OriginFor<T>,
+            tag_id: TagId,
+            name: Tag,
+            category: TagCategory,
+            parent: Option<TagId>,
+        ) -> DispatchResult {
+            T::TagOrigin::ensure_origin(origin)?;
+            ensure!(!Tags::<T>::contains_key(tag_id), Error::<T>::TagAlreadyExists);
+            let details = TagDetails::new(name.clone(), category, parent);
+            ensure!(details.is_valid(), Error::<T>::TagNameInvalid);
+            ensure!(!TagNames::<T>::contains_key(&name), Error::<T>::TagNameTaken);
+            Self::ensure_valid_parent(tag_id, parent)?;
+
+            Self::insert_tag(tag_id, details);
+
+            Self::deposit_event(Event::TagAdded { tag_id, name });
+            Ok(())
+        }
+
+        /// Renames a tag.
+        ///
+        /// This function allows the `TagOrigin` to change the name of a tag, keeping its games.
+        /// The new name must be a non-empty string which is not used by another tag. A
+        /// `TagRenamed` event is emitted once the tag is successfully renamed.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::tag_rename())]
+        pub fn tag_rename(origin: OriginFor<T>, tag_id: TagId, name: Tag) -> DispatchResult {
+            T::TagOrigin::ensure_origin(origin)?;
+            let mut details = Tags::<T>::get(tag_id).ok_or(Error::<T>::TagNotFound)?;
+            ensure!(!TagNames::<T>::contains_key(&name), Error::<T>::TagNameTaken);
+            let previous = core::mem::replace(&mut details.name, name.clone());
+            ensure!(details.is_valid(), Error::<T>::TagNameInvalid);
+
+            TagNames::<T>::remove(&previous);
+            TagNames::<T>::insert(&name, tag_id);
+            Tags::<T>::insert(tag_id, details);
+
+            Self::deposit_event(Event::TagRenamed { tag_id, name });
+            Ok(())
+        }
+
+        /// Deprecates a tag.
+        ///
+        /// This function allows the `TagOrigin` to stop a tag from being given to games. The games
+        /// which already have the tag keep it. A `TagDeprecated` event is emitted once the tag is
+        /// successfully deprecated.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::tag_deprecate())]
+        pub fn tag_deprecate(origin: OriginFor<T>, tag_id: TagId) -> DispatchResult {
+            T::TagOrigin::ensure_origin(origin)?;
+            Self::deprecate_tag(tag_id)
+        }
+
+        /// Merges a tag into another tag.
+        ///
+        /// This function allows the `TagOrigin` to fold a tag into another one, e.g. when two tags
+        /// name the same genre. The merged tag is deprecated at once, and its games are moved to
+        /// the other tag in bounded batches when the blocks have weight left. The merged tag is
+        /// removed once all of its games have been moved. Only one merge can be in progress at a
+        /// time. A `TagMergeStarted` event is emitted once the merge is successfully started, and
+        /// a `TagMerged` event once it is complete.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::tag_merge())]
+        pub fn tag_merge(origin: OriginFor<T>, from: TagId, into: TagId) -> DispatchResult {
+            T::TagOrigin::ensure_origin(origin)?;
+            ensure!(from != into, Error::<T>::TagMergeInvalid);
+            ensure!(!TagMerge::<T>::exists(), Error::<T>::TagMergeInProgress);
+            let from_details = Tags::<T>::get(from).ok_or(Error::<T>::TagNotFound)?;
+            let into_details = Tags::<T>::get(into).ok_or(Error::<T>::TagNotFound)?;
+            ensure!(into_details.is_active(), Error::<T>::TagDeprecated);
+            ensure!(TagChildren::<T>::get(from) == 0, Error::<T>::TagHasChildren);
+
+            if from_details.is_active() {
+                Self::deprecate_tag(from)?;
+            }
+            TagMerge::<T>::put((from, into));
+
+            Self::deposit_event(Event::TagMergeStarted { from, into });
+            Ok(())
+        }
+
+        /// Changes the category and the parent of a tag.
+        ///
+        /// This function allows the `TagOrigin` to move a tag to another category, or to make it
+        /// refine another tag. The parent must be active and have no parent itself, and a tag
+        /// refined by other tags cannot get a parent, so that the tags form a hierarchy of
+        /// two levels at most. The games which already have the tag are not checked against
+        /// the tag rules again until their tags change. A `TagClassified` event is emitted
+        /// once the tag is successfully classified.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::tag_classify())]
+        pub fn tag_classify(
+            origin: OriginFor<T>,
+            tag_id: TagId,
+            category: TagCategory,
+            parent: Option<TagId>,
+        ) -> DispatchResult {
+            T::TagOrigin::ensure_origin(origin)?;
+            let mut details = Tags::<T>::get(tag_id).ok_or(Error::<T>::TagNotFound)?;
+            Self::ensure_valid_parent(tag_id, parent)?;
+
+            if details.parent != parent {
+                if let Some(previous) = details.parent {
+                    Self::remove_tag_child(previous);
+                }
+                if let Some(parent) = parent {
+                    TagChildren::<T>::mutate(parent, |children| {
+                        *children = children.saturating_add(1)
+                    });
+                }
+            }
+            details.category = category;
+            details.parent = parent;
+            Tags::<T>::insert(tag_id, details);
+
+            Self::deposit_event(Event::TagClassified { tag_id, category, parent });
+            Ok(())
+        }
+    }
+
+    /// View functions, queried by wallets and launchers without a custom node RPC.
+    #[pallet::view_functions]
+    impl<T: Config> Pallet<T> {
+        /// Returns whether a buyer owns a game, including when it was bought from a previous
+        /// account of its publisher.
+        pub fn owns_game(buyer: BuyerId<T>, publisher: PublisherId<T>, game_id: GameId) -> bool {
+            Self::game_lineage(&publisher, game_id)
+                .iter()
+                .any(|global_game_id| OwnedGames::<T>::contains_key(&buyer, global_game_id))
+        }
+
+        /// Returns the delivery of a fulfilled order for a game, including when it was ordered from
+        /// a previous account of its publisher.
+        pub fn delivery(
+            buyer: BuyerId<T>,
+            publisher: PublisherId<T>,
+            game_id: GameId,
+        ) -> Option<Delivery> {
+            Self::game_lineage(&publisher, game_id)
+                .iter()
+                .find_map(|global_game_id| Deliveries::<T>::get(&buyer, global_game_id))
+        }
+
+        /// Returns up to `limit` games owned by a buyer, in storage order, starting after the
+        /// `cursor` game when there is one. The games are identified as they were bought, so they
+        /// may be under a previous account of their publisher. At most `MAX_PAGE_SIZE` games are
+        /// returned.
+        pub fn owned_games(
+            buyer: BuyerId<T>,
+            cursor: Option<GlobalGameId<T>>,
+            limit: u32,
+        ) -> Vec<GlobalGameId<T>> {
+            let games = match cursor {
+                Some(global_game_id) => OwnedGames::<T>::iter_key_prefix_from(
+                    &buyer,
+                    OwnedGames::<T>::hashed_key_for(&buyer, global_game_id),
+                ),
+                None => OwnedGames::<T>::iter_key_prefix(&buyer),
+            };
+            games.take(limit.min(MAX_PAGE_SIZE) as usize).collect()
+        }
+
+        /// Returns up to `limit` pending orders of a buyer, in storage order, starting after the
+        /// order for the `start` game when there is one. At most `MAX_PAGE_SIZE` orders are
+        /// returned.
+        pub fn buyer_orders(
+            buyer: BuyerId<T>,
+            start: Option<GlobalGameId<T>>,
+            limit: u32,
+        ) -> Vec<(GlobalGameId<T>, OrderDetailsOf<T>)> {
+            let orders = match start {
+                Some(global_game_id) => BuyerOrders::<T>::iter_prefix_from(
+                    &buyer,
+                    BuyerOrders::<T>::hashed_key_for(&buyer, global_game_id),
+                ),
+                None => BuyerOrders::<T>::iter_prefix(&buyer),
+            };
+            orders.take(limit.min(MAX_PAGE_SIZE) as usize).collect()
+        }
+
+        /// Returns up to `limit` buyers which have a pending order for the given game, in storage
+        /// order, starting after the `start` buyer when there is one. At most `MAX_PAGE_SIZE`
+        /// buyers are returned.
+        pub fn publisher_orders(
+            publisher: PublisherId<T>,
+            game_id: GameId,
+            start: Option<BuyerId<T>>,
+            limit: u32,
+        ) -> Vec<BuyerId<T>> {
+            let buyers = match start {
+                Some(buyer) => PublisherOrders::<T>::iter_key_prefix_from(
+                    (publisher.clone(), game_id),
+                    PublisherOrders::<T>::hashed_key_for((&publisher, game_id, buyer)),
+                ),
+                None => PublisherOrders::<T>::iter_key_prefix((publisher, game_id)),
+            };
+            buyers.take(limit.min(MAX_PAGE_SIZE) as usize).collect()
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the publisher on whose behalf the signer of the origin acts, checking that the
+    /// signer holds the given role.
+    fn ensure_member(
+        origin: OriginFor<T>,
+        role: PublisherRole,
+    ) -> Result<PublisherId<T>, DispatchError> {
+        let account = ensure_signed(origin)?;
+        let publisher = T::PublisherManager::publisher_of(&account);
+        ensure!(
+            T::PublisherManager::has_permission(&publisher, &account, role),
+            Error::<T>::NoPermission
+        );
+        Self::ensure_not_transferring(&publisher)?;
+        Ok(publisher)
+    }
+
+    /// Checks that the catalog of a publisher is not being moved to or from another account.
+    fn ensure_not_transferring(publisher: &PublisherId<T>) -> DispatchResult {
+        ensure!(
+            !<Self as PublisherCatalog<_>>::is_transferring(publisher),
+            Error::<T>::CatalogTransferInProgress
+        );
+        Ok(())
+    }
+
+    /// Returns the identifiers of a game under the current and the previous accounts of its
+    /// publisher, starting with the current one.
+    pub fn game_lineage(publisher: &PublisherId<T>, game_id: GameId) -> Vec<GlobalGameId<T>> {
+        let current = T::PublisherManager::current_publisher(publisher);
+        let previous = T::PublisherManager::previous_publishers(&current);
+        core::iter::once(current)
+            .chain(previous.into_iter().rev())
+            .map(|publisher| (publisher, game_id))
+            .collect()
+    }
+
+    /// Returns the purchase of a game which is not settled yet, along with the identifier the
+    /// game was purchased under.
+    fn find_purchase(
+        buyer: &BuyerId<T>,
+        publisher: &PublisherId<T>,
+        game_id: GameId,
+    ) -> Result<(GlobalGameId<T>, PurchaseDetailsOf<T>), DispatchError> {
+        Self::game_lineage(publisher, game_id)
+            .into_iter()
+            .find_map(|global_game_id| {
+                Purchases::<T>::get(buyer, &global_game_id)
+                    .map(|purchase| (global_game_id, purchase))
+            })
+            .ok_or(Error::<T>::PurchaseNotFound.into())
+    }
+
+    /// Stores an order in both the buyer and the publisher order indexes.
+    pub(crate) fn insert_order(
+        buyer: &BuyerId<T>,
+        publisher: &PublisherId<T>,
+        game_id: GameId,
+        order: &OrderDetailsOf<T>,
+    ) {
+        BuyerOrders::<T>::insert(buyer, (publisher, game_id), order);
+        PublisherOrders::<T>::insert((publisher, game_id, buyer), ());
+        OrderExpiries::<T>::insert((order.expires_at, buyer, (publisher, game_id)), ());
+        PendingOrdersCount::<T>::mutate(publisher, game_id, |count| {
+            *count = count.saturating_add(1)
+        });
+    }
+
+    /// Removes an order from both the buyer and the publisher order indexes.
+    pub(crate) fn remove_order(
+        buyer: &BuyerId<T>,
+        publisher: &PublisherId<T>,
+        game_id: GameId,
+        order: &OrderDetailsOf<T>,
+    ) {
+        BuyerOrders::<T>::remove(buyer, (publisher, game_id));
+        PublisherOrders::<T>::remove((publisher, game_id, buyer));
+        OrderExpiries::<T>::remove((order.expires_at, buyer, (publisher, game_id)));
+        PendingOrdersCount::<T>::mutate_exists(publisher, game_id, |count| {
+            *count = count.and_then(|c| c.checked_sub(1)).filter(|c| *c > 0)
+        });
+    }
+
+    /// Releases the deposit held for an order and removes the order.
+    pub(crate) fn release_order(
+        buyer: &BuyerId<T>,
+        publisher: &PublisherId<T>,
+        game_id: GameId,
+    ) -> DispatchResult {
+        let order =
+            BuyerOrders::<T>::get(buyer, (publisher, game_id)).ok_or(Error::<T>::OrderNotFound)?;
+
+        T::Currency::release(&HoldReason::GamePayment.into(), buyer, order.deposit, BestEffort)?;
+
+        Self::remove_order(buyer, publisher, game_id, &order);
+        Ok(())
+    }
+
+    /// Releases the deposit of an expired order, emitting a `OrderExpired` event.
+    fn expire_order(
+        buyer: BuyerId<T>,
+        publisher: PublisherId<T>,
+        game_id: GameId,
+    ) -> DispatchResult {
+        Self::release_order(&buyer, &publisher, game_id)?;
+
+        Self::deposit_event(Event::OrderExpired { buyer, publisher, game_id });
+        Ok(())
+    }
+
+    /// Expires the orders which have timed out up to the given block, within the weight meter.
+    ///
+    /// The blocks are processed in order starting from `ExpiryCursor`. Every expired order is
+    /// accounted with the `order_expire` weight and every block with a single read, so the work
+    /// stops as soon as the meter runs out and resumes from the same place later.
+    pub(crate) fn expire_orders(now: BlockNumberFor<T>, meter: &mut WeightMeter) {
+        let cursor_weight = T::DbWeight::get().reads_writes(1, 1);
+        if meter.try_consume(cursor_weight).is_err() {
+            return;
+        }
+
+        let block_weight = T::DbWeight::get().reads(1);
+        let order_weight = T::WeightInfo::order_expire();
+        let mut cursor = ExpiryCursor::<T>::get();
+
+        'blocks: while cursor <= now && meter.try_consume(block_weight).is_ok() {
+            while let Some((buyer, (publisher, game_id))) =
+                OrderExpiries::<T>::iter_key_prefix((cursor,)).next()
+            {
+                if meter.try_consume(order_weight).is_err() {
+                    break 'blocks;
+                }
+
+                let key = (cursor, buyer.clone(), (publisher.clone(), game_id));
+                match BuyerOrders::<T>::get(&buyer, (&publisher, game_id)) {
+                    Some(order) if order.expires_at == cursor => {
+                        // The entry is removed along with the order. Should the order fail to
+                        // expire, the entry is dropped so that it does not block the cursor, and
+                        // the order can still be expired with `order_expire`.
+                        if Self::expire_order(buyer, publisher, game_id).is_err() {
+                            OrderExpiries::<T>::remove(key);
+                        }
+                    },
+                    // A stale entry for an order which no longer exists.
+                    _ => OrderExpiries::<T>::remove(key),
+                }
+            }
+
+            cursor.saturating_inc();
+        }
+
+        ExpiryCursor::<T>::put(cursor);
+    }
+
+    /// Moves the catalogs which are being transferred, within the weight meter.
+    ///
+    /// Every step moves a single release or game and is accounted with the
+    /// `catalog_transfer_step` weight, along with a single read to find the transfer, so the work
+    /// stops as soon as the meter runs out and resumes from the same place later.
+    pub(crate) fn transfer_catalogs(meter: &mut WeightMeter) {
+        let read_weight = T::DbWeight::get().reads(1);
+        let step_weight = T::WeightInfo::catalog_transfer_step();
+
+        while meter.try_consume(read_weight).is_ok() {
+            let Some((publisher, new_publisher)) = CatalogTransfers::<T>::iter().next() else {
+                break;
+            };
+            if meter.try_consume(step_weight).is_err() {
+                break;
+            }
+            Self::transfer_catalog_step(publisher, new_publisher);
+        }
+    }
+
+    /// Moves a single release or game of a catalog which is being transferred, or completes the
+    /// transfer once the whole catalog has been moved, emitting a `CatalogTransferred` event.
+    ///
+    /// The releases of a game are moved before the game itself, so that the game is found again
+    /// by the next step until all of its releases have been moved.
+    ///
+    /// The records of the buyers (`OwnedGames`, `Purchases` and `Deliveries`) are keyed by buyer
+    /// first, so they are not moved: they stay under the identifier the game was bought under and
+    /// are found again through the lineage of the publisher.
+    pub(crate) fn transfer_catalog_step(publisher: PublisherId<T>, new_publisher: PublisherId<T>) {
+        let Some(game_id) = PublishedGames::<T>::iter_key_prefix(&publisher).next() else {
+            CatalogTransfers::<T>::remove(&publisher);
+            Self::deposit_event(Event::CatalogTransferred { publisher, new_publisher });
+            return;
+        };
+
+        if let Some(version) = Releases::<T>::iter_key_prefix((publisher.clone(), game_id)).next() {
+            if let Some(release) = Releases::<T>::take((&publisher, game_id, version)) {
+                Releases::<T>::insert((&new_publisher, game_id, version), release);
+            }
+            return;
+        }
+
+        let status = GameStatuses::<T>::get(&publisher, game_id);
+        if let Some(mut details) = PublishedGames::<T>::take(&publisher, game_id) {
+            let tags = details.tags.clone();
+            // The tag merge may have already passed the new key of the game
+            Self::apply_tag_merge(&mut details);
+            if status.is_active() {
+                Self::index_game_tags(&(publisher.clone(), game_id), &tags, &[]);
+                Self::index_game_tags(&(new_publisher.clone(), game_id), &[], &details.tags);
+            }
+            PublishedGames::<T>::insert(&new_publisher, game_id, details);
+        }
+        if GameStatuses::<T>::contains_key(&publisher, game_id) {
+            GameStatuses::<T>::remove(&publisher, game_id);
+            GameStatuses::<T>::insert(&new_publisher, game_id, status);
+        }
+        if let Some(policy) = RefundPolicies::<T>::take(&publisher, game_id) {
+            RefundPolicies::<T>::insert(&new_publisher, game_id, policy);
+        }
+        if let Some(version) = LatestRelease::<T>::take(&publisher, game_id) {
+            LatestRelease::<T>::insert(&new_publisher, game_id, version);
+        }
+        if let Some(version) = HighestRelease::<T>::take(&publisher, game_id) {
+            HighestRelease::<T>::insert(&new_publisher, game_id, version);
+        }
+    }
+
+    /// Returns whether a tag exists and can be given to games.
+    fn is_active_tag(tag_id: &TagId) -> bool {
+        Tags::<T>::get(tag_id).is_some_and(|tag| tag.is_active())
+    }
+
+    /// Deprecates a tag, emitting a `TagDeprecated` event.
+    fn deprecate_tag(tag_id: TagId) -> DispatchResult {
+        Tags::<T>::try_mutate(tag_id, |tag| {
+            let tag = tag.as_mut().ok_or(Error::<T>::TagNotFound)?;
+            ensure!(tag.is_active(), Error::<T>::TagDeprecated);
+            tag.status = TagStatus::Deprecated;
+            Ok::<_, DispatchError>(())
+        })?;
+
+        Self::deposit_event(Event::TagDeprecated { tag_id });
+        Ok(())
+    }
+
+    /// Adds a new tag, counting it among the children of its parent.
+    fn insert_tag(tag_id: TagId, details: TagDetails) {
+        if let Some(parent) = details.parent {
+            TagChildren::<T>::mutate(parent, |children| *children = children.saturating_add(1));
+        }
+        TagNames::<T>::insert(&details.name, tag_id);
+        Tags::<T>::insert(tag_id, details);
+    }
+
+    /// Adds a new game to the catalog of a publisher. A new game is active, so it is listed at
+    /// once.
+    fn insert_game(publisher: &PublisherId<T>, game_id: GameId, details: GameDetailsOf<T>) {
+        Self::index_game_tags(&(publisher.clone(), game_id), &[], &details.tags);
+        PublishedGames::<T>::insert(publisher, game_id, details);
+        GamesCount::<T>::mutate(publisher, |count| *count = count.saturating_add(1));
+    }
+
+    /// Checks that a tag can refine the given parent: the parent must be another active tag,
+    /// which has no parent itself, and the tag must not be refined by other tags. The tags thus
+    /// form a hierarchy of two levels at most.
+    fn ensure_valid_parent(tag_id: TagId, parent: Option<TagId>) -> DispatchResult {
+        let Some(parent) = parent else {
+            return Ok(());
+        };
+        ensure!(parent != tag_id, Error::<T>::TagParentInvalid);
+        ensure!(TagChildren::<T>::get(tag_id) == 0, Error::<T>::TagParentInvalid);
+        let details = Tags::<T>::get(parent).ok_or(Error::<T>::TagParentInvalid)?;
+        ensure!(details.is_active() && details.parent.is_none(), Error::<T>::TagParentInvalid);
+        Ok(())
+    }
+
+    /// Decrements the number of tags refining a tag.
+    fn remove_tag_child(parent: TagId) {
+        TagChildren::<T>::mutate_exists(parent, |children| {
+            *children = children.map(|n| n.saturating_sub(1)).filter(|n| *n > 0)
+        });
+    }
+
+    /// Checks that the tags of a game follow the category rules: no more than
+    /// `MaxTopLevelGenres` top-level genres, and at least `MinGenres` genres of any level.
+    fn ensure_tag_rules(tags: &[TagId]) -> DispatchResult {
+        let categories: Vec<TagCategory> =
+            tags.iter().filter_map(Tags::<T>::get).map(|tag| tag.category).collect();
+        let top_level = categories
+            .iter()
+            .filter(|category| **category == TagCategory::TopLevelGenre)
+            .count();
+        let genres = categories.iter().filter(|category| category.is_genre()).count();
+        ensure!(
+            top_level <= T::MaxTopLevelGenres::get() as usize,
+            Error::<T>::TooManyTopLevelGenres
+        );
+        ensure!(genres >= T::MinGenres::get() as usize, Error::<T>::GenreRequired);
+        Ok(())
+    }
+
+    /// Moves a game from its old tags to its new tags in the `GamesByTag` index.
+    fn index_game_tags(game: &GlobalGameId<T>, old: &[TagId], new: &[TagId]) {
+        for tag_id in old.iter().filter(|tag_id| !new.contains(tag_id)) {
+            GamesByTag::<T>::remove(tag_id, game);
+        }
+        for tag_id in new.iter().filter(|tag_id| !old.contains(tag_id)) {
+            GamesByTag::<T>::insert(tag_id, game, ());
+        }
+    }
+
+    /// Moves the tags of a game as the tag merge in progress does, if there is one.
+    fn apply_tag_merge(details: &mut GameDetailsOf<T>) {
+        if let Some((from, into)) = TagMerge::<T>::get() {
+            details.merge_tag(from, into);
+        }
+    }
+
+    /// Merges the tags of the games as long as there is weight left.
+    pub(crate) fn merge_tags(meter: &mut WeightMeter) {
+        let read_weight = T::DbWeight::get().reads(1);
+        let step_weight = T::WeightInfo::tag_merge_step();
+
+        while meter.try_consume(read_weight).is_ok() {
+            let Some((from, into)) = TagMerge::<T>::get() else {
+                break;
+            };
+            if meter.try_consume(step_weight).is_err() {
+                break;
+            }
+            Self::tag_merge_step(from, into);
+        }
+    }
+
+    /// Moves the next game of the tag merge in progress from the `from` tag to the `into` tag, or
+    /// completes the merge once all the games have been visited, removing the `from` tag and
+    /// emitting a `TagMerged` event.
+    pub(crate) fn tag_merge_step(from: TagId, into: TagId) {
+        let next = match TagMergeCursor::<T>::get() {
+            Some((publisher, game_id)) => PublishedGames::<T>::iter_from(
+                PublishedGames::<T>::hashed_key_for(&publisher, game_id),
+            )
+            .next(),
+            None => PublishedGames::<T>::iter().next(),
+        };
+        let Some((publisher, game_id, mut details)) = next else {
+            TagMerge::<T>::kill();
+            TagMergeCursor::<T>::kill();
+            if let Some(tag) = Tags::<T>::take(from) {
+                TagNames::<T>::remove(&tag.name);
+                if let Some(parent) = tag.parent {
+                    Self::remove_tag_child(parent);
+                }
+            }
+            Self::deposit_event(Event::TagMerged { from, into });
+            return;
+        };
+
+        let tags = details.tags.clone();
+        if details.merge_tag(from, into) {
+            if GameStatuses::<T>::get(&publisher, game_id).is_active() {
+                Self::index_game_tags(&(publisher.clone(), game_id), &tags, &details.tags);
+            }
+            PublishedGames::<T>::insert(&publisher, game_id, details);
+        }
+        TagMergeCursor::<T>::put((publisher, game_id));
+    }
+
+    /// Moves a game to the given status, emitting a `GameStatusChanged` event.
+    fn set_status(publisher: PublisherId<T>, game_id: GameId, to: GameStatus) -> DispatchResult {
+        let from = GameStatuses::<T>::get(&publisher, game_id);
+        ensure!(from.can_transition_to(&to), Error::<T>::InvalidStatusTransition);
+
+        GameStatuses::<T>::insert(&publisher, game_id, to);
+        // Only the active games are listed by tag
+        if from.is_active() != to.is_active() {
+            let game = (publisher.clone(), game_id);
+            let tags = PublishedGames::<T>::get(&publisher, game_id)
+                .map(|details| details.tags)
+                .unwrap_or_default();
+            if to.is_active() {
+                Self::index_game_tags(&game, &[], &tags);
+            } else {
+                Self::index_game_tags(&game, &tags, &[]);
+            }
+        }
+
+        Self::deposit_event(Event::GameStatusChanged { publisher, game_id, from, to });
+        Ok(())
+    }
+
+    /// Returns the latest release of a game which is not yanked, following the transfers of its
+    /// publisher.
+    pub fn latest_release(
+        publisher: &PublisherId<T>,
+        game_id: GameId,
+    ) -> Option<(SemVer, ReleaseDetailsOf<T>)> {
+        let publisher = T::PublisherManager::current_publisher(publisher);
+        let version = LatestRelease::<T>::get(&publisher, game_id)?;
+        Releases::<T>::get((&publisher, game_id, version)).map(|release| (version, release))
+    }
+
+    /// Returns the buyers which have a pending order for the given game.
+    pub fn pending_orders(publisher: &PublisherId<T>, game_id: GameId) -> Vec<BuyerId<T>> {
+        PublisherOrders::<T>::iter_key_prefix((publisher.clone(), game_id)).collect()
+    }
+
+    /// Returns a game along with its identifier under the current account of its publisher.
+    pub fn game(
+        publisher: &PublisherId<T>,
+        game_id: GameId,
+    ) -> Option<(GlobalGameId<T>, GameDetailsOf<T>)> {
+        let publisher = T::PublisherManager::current_publisher(publisher);
+        PublishedGames::<T>::get(&publisher, game_id).map(|details| ((publisher, game_id), details))
+    }
+
+    /// Returns up to `limit` games of a publisher, in storage order, starting after the `cursor`
+    /// game when there is one. The games are identified under the current account of the
+    /// publisher.
+    pub fn games_of(
+        publisher: &PublisherId<T>,
+        cursor: Option<GameId>,
+        limit: u32,
+    ) -> Vec<(GlobalGameId<T>, GameDetailsOf<T>)> {
+        let publisher = T::PublisherManager::current_publisher(publisher);
+        let games = match cursor {
+            Some(game_id) => PublishedGames::<T>::iter_prefix_from(
+                &publisher,
+                PublishedGames::<T>::hashed_key_for(&publisher, game_id),
+            ),
+            None => PublishedGames::<T>::iter_prefix(&publisher),
+        };
+        games
+            .take(limit as usize)
+            .map(|(game_id, details)| ((publisher.clone(), game_id), details))
+            .collect()
+    }
+
+    /// Returns up to `limit` active games with the given tag, in storage order, starting after the
+    /// `cursor` game when there is one.
+    pub fn games_by_tag(
+        tag_id: TagId,
+        cursor: Option<GlobalGameId<T>>,
+        limit: u32,
+    ) -> Vec<(GlobalGameId<T>, GameDetailsOf<T>)> {
+        let games = match cursor {
+            Some(global_game_id) => GamesByTag::<T>::iter_key_prefix_from(
+                tag_id,
+                GamesByTag::<T>::hashed_key_for(tag_id, global_game_id),
+            ),
+            None => GamesByTag::<T>::iter_key_prefix(tag_id),
+        };
+        games
+            .filter_map(|(publisher, game_id)| {
+                let details = PublishedGames::<T>::get(&publisher, game_id)?;
+                Some(((publisher, game_id), details))
+            })
+            .take(limit as usize)
+            .collect()
+    }
+}
+
+impl<T: Config> PublisherCatalog<PublisherId<T>> for Pallet<T> {
+    fn games_count(publisher_id: &PublisherId<T>) -> u32 {
+        GamesCount::<T>::get(publisher_id)
+    }
+
+    fn has_pending_orders(publisher_id: &PublisherId<T>) -> bool {
+        PendingOrdersCount::<T>::iter_prefix_values(publisher_id).next().is_some()
+    }
+
+    fn has_active_games(publisher_id: &PublisherId<T>) -> bool {
+        PublishedGames::<T>::iter_key_prefix(publisher_id)
+            .any(|game_id| GameStatuses::<T>::get(publisher_id, game_id).is_active())
+    }
+
+    fn delist_games(publisher_id: &PublisherId<T>) {
+        for game_id in PublishedGames::<T>::iter_key_prefix(publisher_id) {
+            if GameStatuses::<T>::get(publisher_id, game_id).is_active() {
+                // An active game can always be delisted.
+                let _ = Self::set_status(publisher_id.clone(), game_id, GameStatus::Delisted);
+            }
+        }
+    }
+
+    fn transfer_catalog(
+        publisher_id: &PublisherId<T>,
+        new_publisher_id: &PublisherId<T>,
+    ) -> DispatchResult {
+        // The payments of the purchases which are not settled yet follow the publisher
+        let reason = HoldReason::RefundablePayment.into();
+        let held = T::Currency::balance_on_hold(&reason, publisher_id);
+        if !held.is_zero() {
+            T::Currency::transfer_on_hold(
+                &reason,
+                publisher_id,
+                new_publisher_id,
+                held,
+                Exact,
+                OnHold,
+                Force,
+            )?;
+        }
+
+        GamesCount::<T>::insert(new_publisher_id, GamesCount::<T>::take(publisher_id));
+        CatalogTransfers::<T>::insert(publisher_id, new_publisher_id);
+        Ok(())
+    }
+
+    fn is_transferring(publisher_id: &PublisherId<T>) -> bool {
+        CatalogTransfers::<T>::contains_key(publisher_id) ||
+            T::PublisherManager::previous_publishers(publisher_id)
+                .last()
+                .is_some_and(|previous| CatalogTransfers::<T>::contains_key(previous))
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add_active_games(publisher_id: &PublisherId<T>, n: u32) {
+        use liganite_primitives::{testing::bounded_vec, MAX_NAME_SIZE};
+
+        let count = GamesCount::<T>::get(publisher_id);
+        for game_id in count..count.saturating_add(n) {
+            let details = GameDetails {
+                name: bounded_vec(&scale_info::prelude::vec![b'a'; MAX_NAME_SIZE as usize]),
+                tags: Default::default(),
+                distribution: Distribution::Free {
+                    cid: bounded_vec(
+                        b"bafybeigdyrzt3whh4p5fy7uj5zd7qvmdtg7okjqcyawh5hj7sgl2xylz4u",
+                    ),
+                },
+            };
+            PublishedGames::<T>::insert(publisher_id, game_id as GameId, details);
+        }
+        GamesCount::<T>::insert(publisher_id, count.saturating_add(n));
     }
 }
@@ -0,0 +1,470 @@
+//! Storage migrations for the games pallet.
+
+use super::*;
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+
+pub mod v1 {
+    use super::*;
+
+    pub(crate) mod v0 {
+        use super::*;
+        use frame_support::storage_alias;
+
+        /// The order index before v1: a single buyer per game.
+        #[storage_alias]
+        pub type PublisherOrders<T: Config> = StorageDoubleMap<
+            Pallet<T>,
+            Twox64Concat,
+            PublisherId<T>,
+            Blake2_128Concat,
+            GameId,
+            BuyerId<T>,
+            OptionQuery,
+        >;
+    }
+
+    /// Rebuilds the publisher order index so that every pending order has its own entry.
+    ///
+    /// The old index kept a single buyer per game and could lose buyers, so the new index is
+    /// rebuilt from `BuyerOrders`, which always held every pending order.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            // The old and the new index share the storage prefix, so the old entries have to be
+            // cleared before the new ones are written.
+            let removed = v0::PublisherOrders::<T>::clear(u32::MAX, None).unique as u64;
+
+            let mut migrated = 0u64;
+            // Only the keys are read, as the order details are translated by a later migration.
+            for (buyer, (publisher, game_id)) in BuyerOrders::<T>::iter_keys() {
+                PublisherOrders::<T>::insert((&publisher, game_id, &buyer), ());
+                PendingOrdersCount::<T>::mutate(&publisher, game_id, |count| {
+                    *count = count.saturating_add(1)
+                });
+                migrated += 1;
+            }
+
+            T::DbWeight::get().reads_writes(migrated.saturating_mul(2), removed + migrated * 2)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((BuyerOrders::<T>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let orders = u32::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("Failed to decode the order count"))?;
+            let indexed = PublisherOrders::<T>::iter().count() as u32;
+            let counted = PendingOrdersCount::<T>::iter_values().sum::<u32>();
+
+            ensure!(orders == indexed, "Every buyer order must be indexed");
+            ensure!(orders == counted, "Every buyer order must be counted");
+            Ok(())
+        }
+    }
+
+    /// Migrates the games pallet from storage version 0 to 1.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v2 {
+    use super::*;
+
+    pub(crate) mod v1 {
+        use super::*;
+
+        /// The order details before v2: no expiry.
+        #[derive(Encode, Decode)]
+        pub struct OrderDetails<Currency> {
+            pub deposit: Currency,
+        }
+    }
+
+    /// Sets an expiry on every pending order, so that the held deposits are eventually released.
+    ///
+    /// The existing orders expire `OrderTimeout` blocks after the upgrade, and the expiry cursor
+    /// starts at the upgrade block.
+    pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let now = frame_system::Pallet::<T>::block_number();
+            let expires_at = now.saturating_add(T::OrderTimeout::get());
+
+            let mut migrated = 0u64;
+            BuyerOrders::<T>::translate::<v1::OrderDetails<CurrencyOf<T>>, _>(
+                |buyer, (publisher, game_id), old| {
+                    OrderExpiries::<T>::insert((expires_at, &buyer, (&publisher, game_id)), ());
+                    migrated += 1;
+                    Some(OrderDetails { deposit: old.deposit, expires_at })
+                },
+            );
+            ExpiryCursor::<T>::put(now);
+
+            T::DbWeight::get().reads_writes(migrated + 1, migrated * 2 + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((BuyerOrders::<T>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let orders = u32::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("Failed to decode the order count"))?;
+            let migrated = BuyerOrders::<T>::iter().count() as u32;
+            let indexed = OrderExpiries::<T>::iter_keys().count() as u32;
+
+            ensure!(orders == migrated, "Every buyer order must be migrated");
+            ensure!(orders == indexed, "Every buyer order must have an expiry");
+            Ok(())
+        }
+    }
+
+    /// Migrates the games pallet from storage version 1 to 2.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v3 {
+    use super::*;
+
+    /// Counts the games of every publisher, so that the catalog of a publisher can be wound down
+    /// within a known weight.
+    pub struct InnerMigrateV2ToV3<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut migrated = 0u64;
+            for (publisher, _) in PublishedGames::<T>::iter_keys() {
+                GamesCount::<T>::mutate(&publisher, |count| *count = count.saturating_add(1));
+                migrated += 1;
+            }
+
+            T::DbWeight::get().reads_writes(migrated * 2, migrated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((PublishedGames::<T>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let games = u32::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("Failed to decode the game count"))?;
+            let counted = GamesCount::<T>::iter_values().sum::<u32>();
+
+            ensure!(games == counted, "Every published game must be counted");
+            Ok(())
+        }
+    }
+
+    /// Migrates the games pallet from storage version 2 to 3.
+    pub type MigrateV2ToV3<T> = VersionedMigration<
+        2,
+        3,
+        InnerMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v4 {
+    use super::*;
+    use frame_support::storage_alias;
+    #[cfg(feature = "try-runtime")]
+    use scale_info::prelude::collections::BTreeSet;
+
+    pub(crate) mod v3 {
+        use super::*;
+
+        /// The tags before v4: only their name.
+        #[storage_alias]
+        pub type Tags<T: Config> = CountedStorageMap<Pallet<T>, Blake2_128Concat, TagId, Tag>;
+    }
+
+    /// The tag details of v4: no category nor parent.
+    #[derive(Encode, Decode, Debug, PartialEq)]
+    pub struct TagDetails {
+        pub name: Tag,
+        pub status: TagStatus,
+    }
+
+    /// The tags of v4.
+    #[storage_alias]
+    pub type Tags<T: Config> = CountedStorageMap<Pallet<T>, Blake2_128Concat, TagId, TagDetails>;
+
+    /// Turns every tag into tag details, so that tags can be deprecated, and indexes the tag
+    /// names so that they stay unique.
+    ///
+    /// Some predefined tags share their name, in which case the name is indexed for the tag with
+    /// the lowest id.
+    pub struct InnerMigrateV3ToV4<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV3ToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut migrated = 0u64;
+            Tags::<T>::translate::<Tag, _>(|tag_id, name| {
+                TagNames::<T>::mutate(&name, |indexed| {
+                    if indexed.is_none_or(|id| tag_id < id) {
+                        *indexed = Some(tag_id);
+                    }
+                });
+                migrated += 1;
+                Some(TagDetails { name, status: TagStatus::Active })
+            });
+
+            T::DbWeight::get().reads_writes(migrated * 2, migrated * 2)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let names = v3::Tags::<T>::iter_values().collect::<BTreeSet<_>>();
+            Ok((v3::Tags::<T>::count(), names.len() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let (tags, names) = <(u32, u32)>::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("Failed to decode the tag counts"))?;
+            let migrated = Tags::<T>::iter_keys().count() as u32;
+            let named = TagNames::<T>::iter_keys().count() as u32;
+
+            ensure!(tags == migrated, "Every tag must be migrated");
+            ensure!(Tags::<T>::count() == migrated, "The tag counter must be kept");
+            ensure!(names == named, "Every distinct tag name must be indexed");
+            Ok(())
+        }
+    }
+
+    /// Migrates the games pallet from storage version 3 to 4.
+    pub type MigrateV3ToV4<T> = VersionedMigration<
+        3,
+        4,
+        InnerMigrateV3ToV4<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v5 {
+    use super::*;
+
+    /// Classifies every tag, so that the games can be checked against the tag rules.
+    ///
+    /// The predefined tags get the category they have in `TAGS`, while the tags added since
+    /// genesis get the default category. No tag gets a parent.
+    pub struct InnerMigrateV4ToV5<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV4ToV5<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut migrated = 0u64;
+            Tags::<T>::translate::<v4::TagDetails, _>(|tag_id, tag| {
+                migrated += 1;
+                let category = TAGS
+                    .iter()
+                    .find(|(id, name, _)| *id == tag_id && name.as_bytes() == tag.name.as_slice())
+                    .map(|(_, _, category)| *category)
+                    .unwrap_or_default();
+                Some(TagDetails { name: tag.name, status: tag.status, category, parent: None })
+            });
+
+            T::DbWeight::get().reads_writes(migrated, migrated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok(Tags::<T>::count().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let tags = u32::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("Failed to decode the tag count"))?;
+            let migrated = Tags::<T>::iter_values().count() as u32;
+
+            ensure!(tags == migrated, "Every tag must be migrated");
+            Ok(())
+        }
+    }
+
+    /// Migrates the games pallet from storage version 4 to 5.
+    pub type MigrateV4ToV5<T> = VersionedMigration<
+        4,
+        5,
+        InnerMigrateV4ToV5<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v6 {
+    use super::*;
+
+    /// Indexes the active games by tag, so that the catalogue can be browsed by tag without
+    /// iterating every game.
+    pub struct InnerMigrateV5ToV6<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV5ToV6<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let (mut games, mut indexed) = (0u64, 0u64);
+            for (publisher, game_id, details) in PublishedGames::<T>::iter() {
+                games += 1;
+                if !GameStatuses::<T>::get(&publisher, game_id).is_active() {
+                    continue;
+                }
+                for tag_id in details.tags.iter() {
+                    GamesByTag::<T>::insert(tag_id, (&publisher, game_id), ());
+                    indexed += 1;
+                }
+            }
+
+            T::DbWeight::get().reads_writes(games * 2, indexed)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let tags = PublishedGames::<T>::iter()
+                .filter(|(publisher, game_id, _)| {
+                    GameStatuses::<T>::get(publisher, game_id).is_active()
+                })
+                .map(|(_, _, details)| details.tags.len() as u32)
+                .sum::<u32>();
+            Ok(tags.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let tags = u32::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("Failed to decode the tag count"))?;
+            let indexed = GamesByTag::<T>::iter_keys().count() as u32;
+
+            ensure!(tags == indexed, "Every tag of the active games must be indexed");
+            Ok(())
+        }
+    }
+
+    /// Migrates the games pallet from storage version 5 to 6.
+    pub type MigrateV5ToV6<T> = VersionedMigration<
+        5,
+        6,
+        InnerMigrateV5ToV6<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v7 {
+    use super::*;
+
+    /// Deprecates the duplicated tags which were added at genesis, and points their names back to
+    /// the tags they duplicate.
+    ///
+    /// The duplicates have been removed from `TAGS`, and their ids are retired in `RETIRED_TAGS`.
+    /// The games which have them keep them, until the `TagOrigin` merges them.
+    pub struct InnerMigrateV6ToV7<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV6ToV7<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut migrated = 0u64;
+            for (retired, kept) in RETIRED_TAGS {
+                let Some(mut tag) = Tags::<T>::get(retired) else {
+                    continue;
+                };
+                tag.status = TagStatus::Deprecated;
+                if Tags::<T>::get(kept).is_some_and(|kept_tag| kept_tag.name == tag.name) {
+                    TagNames::<T>::insert(&tag.name, kept);
+                }
+                Tags::<T>::insert(retired, tag);
+                migrated += 1;
+            }
+
+            T::DbWeight::get().reads_writes(RETIRED_TAGS.len() as u64 * 2, migrated * 2)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            for (retired, _) in RETIRED_TAGS {
+                ensure!(
+                    Tags::<T>::get(retired).is_none_or(|tag| !tag.is_active()),
+                    "Every retired tag must be deprecated"
+                );
+            }
+            Ok(())
+        }
+    }
+
+    /// Migrates the games pallet from storage version 6 to 7.
+    pub type MigrateV6ToV7<T> = VersionedMigration<
+        6,
+        7,
+        InnerMigrateV6ToV7<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v8 {
+    use super::*;
+
+    /// Records the highest version ever published for every game with releases, so that a new
+    /// release cannot go below a yanked one.
+    pub struct InnerMigrateV7ToV8<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV7ToV8<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut releases = 0u64;
+            let mut written = 0u64;
+            for (publisher, game_id, version) in Releases::<T>::iter_keys() {
+                releases += 1;
+                HighestRelease::<T>::mutate(&publisher, game_id, |highest| {
+                    if highest.is_none_or(|highest| version > highest) {
+                        *highest = Some(version);
+                        written += 1;
+                    }
+                });
+            }
+
+            T::DbWeight::get().reads_writes(releases * 2, written)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            for (publisher, game_id, version) in Releases::<T>::iter_keys() {
+                ensure!(
+                    HighestRelease::<T>::get(&publisher, game_id)
+                        .is_some_and(|highest| version <= highest),
+                    "Every release must be bounded by the highest release of its game"
+                );
+            }
+            Ok(())
+        }
+    }
+
+    /// Migrates the games pallet from storage version 7 to 8.
+    pub type MigrateV7ToV8<T> = VersionedMigration<
+        7,
+        8,
+        InnerMigrateV7ToV8<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
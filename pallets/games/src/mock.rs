@@ -1,10 +1,10 @@
 use crate as liganite_games;
 use frame_support::{
-    derive_impl,
-    traits::{ConstU64, VariantCountOf},
+    derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, VariantCountOf},
 };
+use frame_system::EnsureRoot;
 use liganite_primitives::{
-    publisher::PublisherManager,
     testing::bounded_vec,
     types::{BuyerId, PublisherDetails, PublisherId},
 };
@@ -73,6 +73,19 @@ impl liganite_publish::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
+    type Catalog = Games;
+    type DepositGracePeriod = ConstU64<10>;
+    type SlashOrigin = EnsureRoot<u64>;
+    type Treasury = ();
+    type SuspendOrigin = EnsureRoot<u64>;
+    type MaxMembers = ConstU32<4>;
+    type MaxTransfers = ConstU32<2>;
+    type MaxDepositIncreases = ConstU32<2>;
+    type VerifierOrigin = EnsureRoot<u64>;
+}
+
+parameter_types! {
+    pub static MinGenres: u32 = 0;
 }
 
 impl liganite_games::Config for Test {
@@ -81,42 +94,64 @@ impl liganite_games::Config for Test {
     type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type PublisherManager = Publish;
+    type OrderTimeout = ConstU64<ORDER_TIMEOUT>;
+    type TagOrigin = EnsureRoot<u64>;
+    type MaxTopLevelGenres = ConstU32<3>;
+    type MinGenres = MinGenres;
 }
 
-pub const PUBLISHER_DEPOSIT: Balance = 1_000_000;
 pub const INITIAL_BALANCE: Balance = 1_000_000_000;
+pub const ORDER_TIMEOUT: u64 = 100;
 
 pub const INVALID_PUBLISHER: PublisherId<Test> = 0;
 pub const PUBLISHER: PublisherId<Test> = 1;
 pub const FUNDED_BUYER: BuyerId<Test> = 11;
 pub const NON_FUNDED_BUYER: BuyerId<Test> = 12;
+pub const FUNDED_BUYER_2: BuyerId<Test> = 13;
+pub const CATALOG_MANAGER: PublisherId<Test> = 21;
+pub const ORDER_FULFILLER: PublisherId<Test> = 22;
+pub const NEW_PUBLISHER: PublisherId<Test> = 31;
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
+    new_test_ext_with_genesis(vec![], Default::default())
+}
+
+// Build genesis storage according to the mock runtime, with the given genesis publishers and
+// games pallet config.
+pub fn new_test_ext_with_genesis(
+    publishers: Vec<(PublisherId<Test>, PublisherDetails)>,
+    games: liganite_games::GenesisConfig<Test>,
+) -> sp_io::TestExternalities {
     let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
     pallet_balances::GenesisConfig::<Test> {
-        balances: vec![(PUBLISHER, INITIAL_BALANCE), (FUNDED_BUYER, INITIAL_BALANCE)],
+        balances: vec![
+            (PUBLISHER, INITIAL_BALANCE),
+            (FUNDED_BUYER, INITIAL_BALANCE),
+            (FUNDED_BUYER_2, INITIAL_BALANCE),
+            (NEW_PUBLISHER, INITIAL_BALANCE),
+        ],
         ..Default::default()
     }
     .assimilate_storage(&mut storage)
     .unwrap();
 
-    liganite_publish::GenesisConfig::<Test> { publisher_deposit: PUBLISHER_DEPOSIT }
+    // The mock publisher is inserted without holding a deposit, so none is required
+    liganite_publish::GenesisConfig::<Test> { publisher_deposit: 0, publishers }
         .assimilate_storage(&mut storage)
         .unwrap();
 
-    liganite_games::GenesisConfig::<Test>::default()
-        .assimilate_storage(&mut storage)
-        .unwrap();
+    games.assimilate_storage(&mut storage).unwrap();
 
     let mut ext = sp_io::TestExternalities::new(storage);
     ext.execute_with(|| {
-        Publish::insert_publisher(
-            &PUBLISHER,
-            &PublisherDetails {
+        liganite_publish::Publishers::<Test>::insert(
+            PUBLISHER,
+            PublisherDetails {
                 name: bounded_vec(b"PUBLISHER"),
                 url: bounded_vec(b"https://publisher.mock"),
+                ..Default::default()
             },
         )
     });
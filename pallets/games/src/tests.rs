@@ -1,12 +1,63 @@
 use crate::{
-    mock::*, BuyerOrders, Error, Event, HoldReason, OwnedGames, PublishedGames, PublisherOrders,
+    migrations, mock::*, BuyerOrders, CatalogTransfers, Deliveries, Error, Event, ExpiryCursor,
+    GameStatuses, GamesByTag, GamesCount, HighestRelease, HoldReason, LatestRelease, OrderExpiries,
+    OwnedGames, PendingOrdersCount, PublishedGames, PublisherOrders, Purchases, RefundPolicies,
+    Releases, TagChildren, TagMerge, TagMergeCursor, TagNames, Tags, WeightInfo, MAX_PAGE_SIZE,
 };
-use frame_support::{assert_noop, assert_ok, traits::fungible};
+use frame_support::{
+    assert_noop, assert_ok,
+    storage::unhashed,
+    traits::{fungible, Hooks, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+};
+use frame_system::pallet_prelude::HeaderFor;
+use liganite_license::{Finality, License, LicenseProof, Trust};
 use liganite_primitives::{
+    publisher::{PublisherCatalog, PublisherManager},
+    tags::{RETIRED_TAGS, TAGS},
     testing::bounded_vec,
-    types::{Distribution, GameDetails, OrderDetails},
+    types::{
+        Cid, Delivery, Distribution, GameDetails, GameDetailsUpdate, GameStatus, OrderDetails,
+        PublisherDetails, PublisherRole, PurchaseDetails, RefundPolicy, RejectionReason, SemVer,
+        Tag, TagCategory, TagDetails, TagId, TagStatus,
+    },
 };
-use sp_runtime::TokenError;
+use sp_runtime::{traits::Header as _, DispatchError, TokenError};
+
+fn delivery() -> Delivery {
+    Delivery::Plain(bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"))
+}
+
+fn publish_release(game_id: u16, version: SemVer) {
+    assert_ok!(Games::release_publish(
+        RuntimeOrigin::signed(PUBLISHER),
+        game_id,
+        version,
+        release_cid(),
+        changelog_cid()
+    ));
+}
+
+fn add_refundable_game(game_id: u16, price: Balance, policy: RefundPolicy<u64>) {
+    let details = GameDetails {
+        name: bounded_vec(b"Example Game"),
+        tags: Default::default(),
+        distribution: Distribution::Instant {
+            price,
+            cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+        },
+    };
+    PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+    assert_ok!(Games::refund_policy_set(RuntimeOrigin::signed(PUBLISHER), game_id, Some(policy)));
+}
+
+fn release_cid() -> Cid {
+    bounded_vec(b"bafybeigdyrzt3whh4p5fy7uj5zd7qvmdtg7okjqcyawh5hj7sgl2xylz4u")
+}
+
+fn changelog_cid() -> Cid {
+    bounded_vec(b"bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku")
+}
 
 #[test]
 fn test_game_add() {
@@ -23,6 +74,7 @@ fn test_game_add() {
         assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details.clone()));
 
         assert_eq!(PublishedGames::<Test>::get(PUBLISHER, game_id), Some(details));
+        assert_eq!(GamesCount::<Test>::get(PUBLISHER), 1);
         System::assert_last_event(Event::GameAdded { publisher: PUBLISHER, game_id }.into());
     })
 }
@@ -68,162 +120,208 @@ fn test_game_add_invalid_publisher() {
 }
 
 #[test]
-fn test_game_add_empty_name() {
+fn test_game_add_suspended_publisher() {
     new_test_ext().execute_with(|| {
         let game_id = 1;
         let details = GameDetails {
-            name: bounded_vec(b""),
+            name: bounded_vec(b"Example Game"),
             tags: bounded_vec(&[1, 2, 3]),
             distribution: Distribution::Instant {
                 price: 1234,
                 cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
             },
         };
+        assert_ok!(Publish::publisher_suspend(RuntimeOrigin::root(), PUBLISHER));
+
         assert_noop!(
             Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details),
-            Error::<Test>::GameDetailsInvalid
+            Error::<Test>::PublisherSuspended
         );
     });
 }
 
 #[test]
-fn test_game_buy_free() {
+fn test_game_add_by_member() {
     new_test_ext().execute_with(|| {
         let game_id = 1;
-        let cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
-            tags: Default::default(),
-            distribution: Distribution::Free { cid: cid.clone() },
+            tags: bounded_vec(&[1, 2, 3]),
+            distribution: Distribution::Instant {
+                price: 1234,
+                cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            },
         };
-        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
-
-        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
-        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
+        assert_ok!(Publish::member_grant(
+            RuntimeOrigin::signed(PUBLISHER),
+            CATALOG_MANAGER,
+            PublisherRole::CatalogManager
+        ));
+        assert_ok!(Publish::member_grant(
+            RuntimeOrigin::signed(PUBLISHER),
+            ORDER_FULFILLER,
+            PublisherRole::OrderFulfiller
+        ));
 
-        System::assert_last_event(
-            Event::GamePurchased { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id, cid }.into(),
+        assert_noop!(
+            Games::game_add(RuntimeOrigin::signed(ORDER_FULFILLER), game_id, details.clone()),
+            Error::<Test>::NoPermission
         );
+        assert_ok!(Games::game_add(
+            RuntimeOrigin::signed(CATALOG_MANAGER),
+            game_id,
+            details.clone()
+        ));
+
+        // The game is added to the catalog of the publisher
+        assert_eq!(PublishedGames::<Test>::get(PUBLISHER, game_id), Some(details));
+        assert_eq!(PublishedGames::<Test>::get(CATALOG_MANAGER, game_id), None);
+        System::assert_last_event(Event::GameAdded { publisher: PUBLISHER, game_id }.into());
     });
 }
 
 #[test]
-fn test_game_buy_instant() {
+fn test_game_add_empty_name() {
     new_test_ext().execute_with(|| {
         let game_id = 1;
-        let price = 12345;
-        let cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
         let details = GameDetails {
-            name: bounded_vec(b"Example Game"),
-            tags: Default::default(),
-            distribution: Distribution::Instant { price, cid: cid.clone() },
+            name: bounded_vec(b""),
+            tags: bounded_vec(&[1, 2, 3]),
+            distribution: Distribution::Instant {
+                price: 1234,
+                cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            },
         };
-        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
-
-        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
-        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
-        assert_eq!(
-            <Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER),
-            INITIAL_BALANCE - price
-        );
-        assert_eq!(
-            <Balances as fungible::Inspect<_>>::balance(&PUBLISHER),
-            INITIAL_BALANCE + price
-        );
-
-        System::assert_last_event(
-            Event::GamePurchased { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id, cid }.into(),
+        assert_noop!(
+            Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details),
+            Error::<Test>::GameDetailsInvalid
         );
     });
 }
 
 #[test]
-fn test_game_buy_individual() {
+fn test_game_update() {
     new_test_ext().execute_with(|| {
         let game_id = 1;
-        let price = 12345;
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
-            tags: Default::default(),
-            distribution: Distribution::Individual { price },
+            tags: bounded_vec(&[1, 2, 3]),
+            distribution: Distribution::Instant {
+                price: 1234,
+                cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            },
         };
-        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details.clone());
 
-        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        let new_distribution = Distribution::Instant {
+            price: 4321,
+            cid: bounded_vec(b"QmRJzSVrU5kMkXzDCrePyx3TX7gGu8cXsogX5xLyfMuNPG"),
+        };
+        let update = GameDetailsUpdate {
+            name: Some(bounded_vec(b"Example Game")),
+            tags: Some(bounded_vec(&[4, 5])),
+            distribution: Some(new_distribution.clone()),
+        };
+        assert_ok!(Games::game_update(RuntimeOrigin::signed(PUBLISHER), game_id, update));
 
-        let expected = OrderDetails { deposit: price };
-        assert_eq!(BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(expected));
-        assert_eq!(PublisherOrders::<Test>::get(PUBLISHER, game_id), Some(FUNDED_BUYER));
-        assert_eq!(
-            <Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER),
-            INITIAL_BALANCE - price
-        );
-        assert_eq!(
-            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
-                &HoldReason::GamePayment.into(),
-                &FUNDED_BUYER
-            ),
-            price
-        );
+        let expected = GameDetails {
+            name: details.name,
+            tags: bounded_vec(&[4, 5]),
+            distribution: new_distribution.clone(),
+        };
+        assert_eq!(PublishedGames::<Test>::get(PUBLISHER, game_id), Some(expected));
+
+        let diff = GameDetailsUpdate {
+            name: None,
+            tags: Some(bounded_vec(&[4, 5])),
+            distribution: Some(new_distribution),
+        };
         System::assert_last_event(
-            Event::OrderPlaced { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id }.into(),
+            Event::GameUpdated { publisher: PUBLISHER, game_id, diff }.into(),
         );
     })
 }
 
 #[test]
-fn test_game_buy_invalid_game() {
+fn test_game_update_game_not_found() {
     new_test_ext().execute_with(|| {
+        let update = GameDetailsUpdate {
+            name: Some(bounded_vec(b"New Name")),
+            tags: None,
+            distribution: None,
+        };
+
         assert_noop!(
-            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1),
+            Games::game_update(RuntimeOrigin::signed(PUBLISHER), 1, update),
             Error::<Test>::GameNotFound
         );
     })
 }
 
 #[test]
-fn test_game_buy_no_funds() {
+fn test_game_update_invalid_publisher() {
+    new_test_ext().execute_with(|| {
+        let update = GameDetailsUpdate {
+            name: Some(bounded_vec(b"New Name")),
+            tags: None,
+            distribution: None,
+        };
+
+        assert_noop!(
+            Games::game_update(RuntimeOrigin::signed(INVALID_PUBLISHER), 1, update),
+            Error::<Test>::InvalidPublisher
+        );
+    })
+}
+
+#[test]
+fn test_game_update_not_changed() {
     new_test_ext().execute_with(|| {
         let game_id = 1;
-        let price = 12345;
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
             tags: Default::default(),
-            distribution: Distribution::Instant {
-                price,
-                cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
-            },
+            distribution: Distribution::Individual { price: 1234 },
         };
-        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details.clone());
 
+        let update = GameDetailsUpdate {
+            name: Some(details.name),
+            tags: None,
+            distribution: Some(details.distribution),
+        };
         assert_noop!(
-            Games::game_buy(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, game_id),
-            TokenError::FundsUnavailable
+            Games::game_update(RuntimeOrigin::signed(PUBLISHER), game_id, update),
+            Error::<Test>::GameNotChanged
         );
     })
 }
 
 #[test]
-fn test_order_place_no_funds() {
+fn test_game_update_invalid_details() {
     new_test_ext().execute_with(|| {
         let game_id = 1;
-        let price = 12345;
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
             tags: Default::default(),
-            distribution: Distribution::Individual { price },
+            distribution: Distribution::Individual { price: 1234 },
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
 
+        let update = GameDetailsUpdate {
+            name: None,
+            tags: None,
+            distribution: Some(Distribution::Individual { price: 0 }),
+        };
         assert_noop!(
-            Games::game_buy(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, game_id),
-            TokenError::FundsUnavailable
+            Games::game_update(RuntimeOrigin::signed(PUBLISHER), game_id, update),
+            Error::<Test>::GameDetailsInvalid
         );
     })
 }
 
 #[test]
-fn test_order_place_already_placed() {
+fn test_game_update_keeps_pending_order_deposit() {
     new_test_ext().execute_with(|| {
         let game_id = 1;
         let price = 12345;
@@ -236,78 +334,111 @@ fn test_order_place_already_placed() {
 
         assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
 
-        assert_noop!(
-            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id),
-            Error::<Test>::OrderAlreadyPlaced
+        let update = GameDetailsUpdate {
+            name: None,
+            tags: None,
+            distribution: Some(Distribution::Individual { price: price * 2 }),
+        };
+        assert_ok!(Games::game_update(RuntimeOrigin::signed(PUBLISHER), game_id, update));
+
+        assert_eq!(
+            BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)),
+            Some(OrderDetails { deposit: price, expires_at: 1 + ORDER_TIMEOUT })
+        );
+
+        assert_ok!(Games::order_fulfill(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            FUNDED_BUYER,
+            delivery()
+        ));
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&PUBLISHER),
+            INITIAL_BALANCE + price
         );
     })
 }
 
 #[test]
-fn test_order_place_owned_game() {
+fn test_game_status_set() {
     new_test_ext().execute_with(|| {
         let game_id = 1;
-        OwnedGames::<Test>::insert(FUNDED_BUYER, (PUBLISHER, game_id), ());
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 1234 },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        assert_eq!(GameStatuses::<Test>::get(PUBLISHER, game_id), GameStatus::Active);
 
-        assert_noop!(
-            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id),
-            Error::<Test>::GameAlreadyExists
+        assert_ok!(Games::game_status_set(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            GameStatus::Delisted
+        ));
+
+        assert_eq!(GameStatuses::<Test>::get(PUBLISHER, game_id), GameStatus::Delisted);
+        System::assert_last_event(
+            Event::GameStatusChanged {
+                publisher: PUBLISHER,
+                game_id,
+                from: GameStatus::Active,
+                to: GameStatus::Delisted,
+            }
+            .into(),
         );
+
+        assert_ok!(Games::game_status_set(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            GameStatus::Active
+        ));
+
+        assert_eq!(GameStatuses::<Test>::get(PUBLISHER, game_id), GameStatus::Active);
     })
 }
 
 #[test]
-fn test_order_place_multiple() {
+fn test_game_status_set_game_not_found() {
     new_test_ext().execute_with(|| {
-        let game_id_1 = 1;
-        let price_1 = 11111;
-        let details = GameDetails {
-            name: bounded_vec(b"Example Game"),
-            tags: Default::default(),
-            distribution: Distribution::Individual { price: price_1 },
-        };
-        PublishedGames::<Test>::insert(PUBLISHER, game_id_1, details);
+        assert_noop!(
+            Games::game_status_set(RuntimeOrigin::signed(PUBLISHER), 1, GameStatus::Hidden),
+            Error::<Test>::GameNotFound
+        );
+    })
+}
 
-        let game_id_2 = 2;
-        let price_2 = 22222;
+#[test]
+fn test_game_status_set_invalid_transition() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
             tags: Default::default(),
-            distribution: Distribution::Individual { price: price_2 },
+            distribution: Distribution::Individual { price: 1234 },
         };
-        PublishedGames::<Test>::insert(PUBLISHER, game_id_2, details);
-
-        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id_1));
-        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id_2));
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
 
-        assert_eq!(
-            BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id_1)),
-            Some(OrderDetails { deposit: price_1 })
+        // retiring is only possible through `game_retire`
+        assert_noop!(
+            Games::game_status_set(RuntimeOrigin::signed(PUBLISHER), game_id, GameStatus::Retired),
+            Error::<Test>::InvalidStatusTransition
         );
-        assert_eq!(
-            BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id_2)),
-            Some(OrderDetails { deposit: price_2 })
+        assert_noop!(
+            Games::game_status_set(RuntimeOrigin::signed(PUBLISHER), game_id, GameStatus::Active),
+            Error::<Test>::InvalidStatusTransition
         );
-        assert_eq!(PublisherOrders::<Test>::get(PUBLISHER, game_id_1), Some(FUNDED_BUYER));
-        assert_eq!(PublisherOrders::<Test>::get(PUBLISHER, game_id_2), Some(FUNDED_BUYER));
 
-        let total = price_1 + price_2;
-        assert_eq!(
-            <Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER),
-            INITIAL_BALANCE - total
-        );
-        assert_eq!(
-            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
-                &HoldReason::GamePayment.into(),
-                &FUNDED_BUYER
-            ),
-            total
+        GameStatuses::<Test>::insert(PUBLISHER, game_id, GameStatus::Retired);
+        assert_noop!(
+            Games::game_status_set(RuntimeOrigin::signed(PUBLISHER), game_id, GameStatus::Active),
+            Error::<Test>::InvalidStatusTransition
         );
     })
 }
 
 #[test]
-fn test_order_cancel() {
+fn test_game_retire() {
     new_test_ext().execute_with(|| {
         let game_id = 1;
         let price = 12345;
@@ -317,106 +448,2925 @@ fn test_order_cancel() {
             distribution: Distribution::Individual { price },
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        OwnedGames::<Test>::insert(NON_FUNDED_BUYER, (PUBLISHER, game_id), ());
 
         assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER_2), PUBLISHER, game_id));
 
-        assert_ok!(Games::order_cancel(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
-
-        assert_eq!(BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
-        assert_eq!(PublisherOrders::<Test>::get(PUBLISHER, game_id), None);
-        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
-        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER), INITIAL_BALANCE);
-        assert_eq!(
-            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
-                &HoldReason::GamePayment.into(),
-                &FUNDED_BUYER
-            ),
-            0
-        );
-        System::assert_last_event(
-            Event::OrderCancelled { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id }.into(),
-        );
-    })
-}
+        assert_ok!(Games::game_retire(RuntimeOrigin::signed(PUBLISHER), game_id, 2));
 
-#[test]
-fn test_order_cancel_missing_order() {
-    new_test_ext().execute_with(|| {
-        assert_noop!(
-            Games::order_cancel(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1),
-            Error::<Test>::OrderNotFound
+        assert_eq!(GameStatuses::<Test>::get(PUBLISHER, game_id), GameStatus::Retired);
+        assert_eq!(PendingOrdersCount::<Test>::get(PUBLISHER, game_id), 0);
+        for buyer in [FUNDED_BUYER, FUNDED_BUYER_2] {
+            assert_eq!(BuyerOrders::<Test>::get(buyer, (PUBLISHER, game_id)), None);
+            assert_eq!(<Balances as fungible::Inspect<_>>::balance(&buyer), INITIAL_BALANCE);
+            assert_eq!(
+                <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                    &HoldReason::GamePayment.into(),
+                    &buyer
+                ),
+                0
+            );
+            System::assert_has_event(
+                Event::OrderRefunded { buyer, publisher: PUBLISHER, game_id }.into(),
+            );
+        }
+        // games which are already owned are kept
+        assert_eq!(OwnedGames::<Test>::get(NON_FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
+        System::assert_has_event(
+            Event::GameStatusChanged {
+                publisher: PUBLISHER,
+                game_id,
+                from: GameStatus::Active,
+                to: GameStatus::Retired,
+            }
+            .into(),
         );
     })
 }
 
 #[test]
-fn test_order_fulfill() {
+fn test_game_retire_too_many_pending_orders() {
     new_test_ext().execute_with(|| {
         let game_id = 1;
-        let price = 12345;
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
             tags: Default::default(),
-            distribution: Distribution::Individual { price },
+            distribution: Distribution::Individual { price: 12345 },
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
 
         assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER_2), PUBLISHER, game_id));
 
-        assert_ok!(Games::order_fulfill(RuntimeOrigin::signed(PUBLISHER), game_id, FUNDED_BUYER));
-
-        assert_eq!(BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
-        assert_eq!(PublisherOrders::<Test>::get(PUBLISHER, game_id), None);
-        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
-        assert_eq!(
-            <Balances as fungible::Inspect<_>>::balance(&PUBLISHER),
-            INITIAL_BALANCE + price
-        );
-        assert_eq!(
-            <Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER),
-            INITIAL_BALANCE - price
-        );
-        assert_eq!(
-            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
-                &HoldReason::GamePayment.into(),
-                &FUNDED_BUYER
-            ),
-            0
-        );
-        System::assert_last_event(
-            Event::OrderFulfilled { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id }.into(),
+        assert_noop!(
+            Games::game_retire(RuntimeOrigin::signed(PUBLISHER), game_id, 1),
+            Error::<Test>::TooManyPendingOrders
         );
     })
 }
 
 #[test]
-fn test_order_fulfill_missing_order() {
+fn test_game_retire_already_retired() {
     new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 12345 },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        GameStatuses::<Test>::insert(PUBLISHER, game_id, GameStatus::Retired);
+
         assert_noop!(
-            Games::order_fulfill(RuntimeOrigin::signed(PUBLISHER), 1, FUNDED_BUYER),
-            Error::<Test>::OrderNotFound
+            Games::game_retire(RuntimeOrigin::signed(PUBLISHER), game_id, 0),
+            Error::<Test>::InvalidStatusTransition
         );
     })
 }
 
 #[test]
-fn test_order_fulfill_invalid_game() {
+fn test_game_update_retired() {
     new_test_ext().execute_with(|| {
         let game_id = 1;
-        let price = 12345;
         let details = GameDetails {
             name: bounded_vec(b"Example Game"),
             tags: Default::default(),
-            distribution: Distribution::Individual { price },
+            distribution: Distribution::Individual { price: 12345 },
         };
         PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        GameStatuses::<Test>::insert(PUBLISHER, game_id, GameStatus::Retired);
 
-        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        let update = GameDetailsUpdate {
+            name: Some(bounded_vec(b"New Name")),
+            tags: None,
+            distribution: None,
+        };
+        assert_noop!(
+            Games::game_update(RuntimeOrigin::signed(PUBLISHER), game_id, update),
+            Error::<Test>::GameRetired
+        );
+    })
+}
+
+#[test]
+fn test_release_publish() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 12345 },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        let v1 = SemVer::new(1, 0, 0);
+        publish_release(game_id, v1);
+
+        let release = Releases::<Test>::get((PUBLISHER, game_id, v1)).unwrap();
+        assert_eq!(release.cid, release_cid());
+        assert_eq!(release.changelog, changelog_cid());
+        assert_eq!(release.released_at, 1);
+        assert_eq!((release.previous, release.next), (None, None));
+        assert_eq!(LatestRelease::<Test>::get(PUBLISHER, game_id), Some(v1));
+        System::assert_last_event(
+            Event::ReleasePublished {
+                publisher: PUBLISHER,
+                game_id,
+                version: v1,
+                cid: release_cid(),
+            }
+            .into(),
+        );
+
+        System::set_block_number(5);
+        let v2 = SemVer::new(1, 1, 0);
+        publish_release(game_id, v2);
+
+        let (version, release) = Games::latest_release(&PUBLISHER, game_id).unwrap();
+        assert_eq!(version, v2);
+        assert_eq!(release.released_at, 5);
+        assert_eq!((release.previous, release.next), (Some(v1), None));
+        assert_eq!(Releases::<Test>::get((PUBLISHER, game_id, v1)).unwrap().next, Some(v2));
+    })
+}
+
+#[test]
+fn test_release_publish_game_not_found() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Games::release_publish(
+                RuntimeOrigin::signed(PUBLISHER),
+                1,
+                SemVer::new(1, 0, 0),
+                release_cid(),
+                changelog_cid()
+            ),
+            Error::<Test>::GameNotFound
+        );
+    })
+}
+
+#[test]
+fn test_release_publish_invalid_details() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 12345 },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_noop!(
+            Games::release_publish(
+                RuntimeOrigin::signed(PUBLISHER),
+                game_id,
+                SemVer::new(1, 0, 0),
+                bounded_vec(b"NotARealCID123"),
+                changelog_cid()
+            ),
+            Error::<Test>::ReleaseDetailsInvalid
+        );
+    })
+}
+
+#[test]
+fn test_release_publish_version_too_low() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 12345 },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        publish_release(game_id, SemVer::new(1, 1, 0));
+
+        assert_noop!(
+            Games::release_publish(
+                RuntimeOrigin::signed(PUBLISHER),
+                game_id,
+                SemVer::new(1, 1, 0),
+                release_cid(),
+                changelog_cid()
+            ),
+            Error::<Test>::ReleaseAlreadyExists
+        );
+        assert_noop!(
+            Games::release_publish(
+                RuntimeOrigin::signed(PUBLISHER),
+                game_id,
+                SemVer::new(1, 0, 9),
+                release_cid(),
+                changelog_cid()
+            ),
+            Error::<Test>::ReleaseVersionTooLow
+        );
+    })
+}
+
+#[test]
+fn test_release_yank_latest() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 12345 },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        let (v1, v2) = (SemVer::new(1, 0, 0), SemVer::new(2, 0, 0));
+        publish_release(game_id, v1);
+        publish_release(game_id, v2);
+
+        assert_ok!(Games::release_yank(RuntimeOrigin::signed(PUBLISHER), game_id, v2));
+
+        let yanked = Releases::<Test>::get((PUBLISHER, game_id, v2)).unwrap();
+        assert!(yanked.yanked);
+        assert_eq!((yanked.previous, yanked.next), (None, None));
+        assert_eq!(LatestRelease::<Test>::get(PUBLISHER, game_id), Some(v1));
+        assert_eq!(Releases::<Test>::get((PUBLISHER, game_id, v1)).unwrap().next, None);
+        System::assert_last_event(
+            Event::ReleaseYanked { publisher: PUBLISHER, game_id, version: v2 }.into(),
+        );
+
+        assert_ok!(Games::release_yank(RuntimeOrigin::signed(PUBLISHER), game_id, v1));
+
+        assert_eq!(LatestRelease::<Test>::get(PUBLISHER, game_id), None);
+        assert_eq!(Games::latest_release(&PUBLISHER, game_id), None);
+    })
+}
+
+#[test]
+fn test_release_publish_below_yanked() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 12345 },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        publish_release(game_id, SemVer::new(1, 0, 0));
+        publish_release(game_id, SemVer::new(2, 0, 0));
+        assert_ok!(Games::release_yank(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            SemVer::new(2, 0, 0)
+        ));
+
+        // The yanked release still bounds the next versions
+        assert_noop!(
+            Games::release_publish(
+                RuntimeOrigin::signed(PUBLISHER),
+                game_id,
+                SemVer::new(1, 5, 0),
+                release_cid(),
+                changelog_cid()
+            ),
+            Error::<Test>::ReleaseVersionTooLow
+        );
+
+        publish_release(game_id, SemVer::new(2, 0, 1));
+        assert_eq!(LatestRelease::<Test>::get(PUBLISHER, game_id), Some(SemVer::new(2, 0, 1)));
+        assert_eq!(
+            Releases::<Test>::get((PUBLISHER, game_id, SemVer::new(2, 0, 1)))
+                .unwrap()
+                .previous,
+            Some(SemVer::new(1, 0, 0))
+        );
+    })
+}
+
+#[test]
+fn test_release_yank_middle() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 12345 },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        let (v1, v2, v3) = (SemVer::new(1, 0, 0), SemVer::new(1, 0, 1), SemVer::new(1, 0, 2));
+        publish_release(game_id, v1);
+        publish_release(game_id, v2);
+        publish_release(game_id, v3);
+
+        assert_ok!(Games::release_yank(RuntimeOrigin::signed(PUBLISHER), game_id, v2));
+
+        assert_eq!(LatestRelease::<Test>::get(PUBLISHER, game_id), Some(v3));
+        assert_eq!(Releases::<Test>::get((PUBLISHER, game_id, v1)).unwrap().next, Some(v3));
+        assert_eq!(Releases::<Test>::get((PUBLISHER, game_id, v3)).unwrap().previous, Some(v1));
+
+        // a yanked version cannot be published again
+        assert_noop!(
+            Games::release_publish(
+                RuntimeOrigin::signed(PUBLISHER),
+                game_id,
+                v2,
+                release_cid(),
+                changelog_cid()
+            ),
+            Error::<Test>::ReleaseAlreadyExists
+        );
+    })
+}
+
+#[test]
+fn test_release_yank_not_found() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 12345 },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        let version = SemVer::new(1, 0, 0);
+
+        assert_noop!(
+            Games::release_yank(RuntimeOrigin::signed(PUBLISHER), game_id, version),
+            Error::<Test>::ReleaseNotFound
+        );
+
+        publish_release(game_id, version);
+        assert_ok!(Games::release_yank(RuntimeOrigin::signed(PUBLISHER), game_id, version));
+
+        assert_noop!(
+            Games::release_yank(RuntimeOrigin::signed(PUBLISHER), game_id, version),
+            Error::<Test>::ReleaseAlreadyYanked
+        );
+    })
+}
+
+#[test]
+fn test_publisher_catalog() {
+    new_test_ext().execute_with(|| {
+        let price = 12345;
+        for game_id in 1..=2 {
+            let details = GameDetails {
+                name: bounded_vec(b"Example Game"),
+                tags: Default::default(),
+                distribution: Distribution::Individual { price },
+            };
+            assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details));
+        }
+        assert_ok!(Games::game_status_set(RuntimeOrigin::signed(PUBLISHER), 2, GameStatus::Hidden));
+
+        assert_eq!(Games::games_count(&PUBLISHER), 2);
+        assert!(Games::has_active_games(&PUBLISHER));
+        assert!(!Games::has_pending_orders(&PUBLISHER));
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1));
+        assert!(Games::has_pending_orders(&PUBLISHER));
+
+        Games::delist_games(&PUBLISHER);
+
+        // Only the active game is delisted
+        assert_eq!(GameStatuses::<Test>::get(PUBLISHER, 1), GameStatus::Delisted);
+        assert_eq!(GameStatuses::<Test>::get(PUBLISHER, 2), GameStatus::Hidden);
+        assert!(!Games::has_active_games(&PUBLISHER));
+    })
+}
+
+#[test]
+fn test_publisher_deregister_delists_games() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 12345 },
+        };
+        assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_noop!(
+            Publish::publisher_deregister(RuntimeOrigin::signed(PUBLISHER), true, 1),
+            liganite_publish::Error::<Test>::PublisherHasPendingOrders
+        );
+
+        assert_ok!(Games::order_cancel(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_noop!(
+            Publish::publisher_deregister(RuntimeOrigin::signed(PUBLISHER), false, 1),
+            liganite_publish::Error::<Test>::PublisherHasActiveGames
+        );
+
+        assert_ok!(Publish::publisher_deregister(RuntimeOrigin::signed(PUBLISHER), true, 1));
+
+        assert!(!Publish::is_valid_publisher(&PUBLISHER));
+        assert_eq!(GameStatuses::<Test>::get(PUBLISHER, game_id), GameStatus::Delisted);
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id),
+            Error::<Test>::GameNotActive
+        );
+    })
+}
+
+#[test]
+fn test_publisher_deregister_keeps_catalog() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: bounded_vec(&[1]),
+            distribution: Distribution::Free {
+                cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            },
+        };
+        assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details.clone()));
+        publish_release(game_id, SemVer::new(1, 0, 0));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_ok!(Publish::publisher_deregister(RuntimeOrigin::signed(PUBLISHER), true, 1));
+
+        // The delisted game leaves the catalogue, but its owners can still download it
+        assert_eq!(GamesByTag::<Test>::iter_key_prefix(1).count(), 0);
+        assert_eq!(PublishedGames::<Test>::get(PUBLISHER, game_id), Some(details));
+        assert_eq!(GamesCount::<Test>::get(PUBLISHER), 1);
+        assert!(Games::owns_game(FUNDED_BUYER, PUBLISHER, game_id));
+        assert_eq!(
+            Games::latest_release(&PUBLISHER, game_id).map(|(version, _)| version),
+            Some(SemVer::new(1, 0, 0))
+        );
+    })
+}
+
+#[test]
+fn test_publisher_deregister_relist_fails() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 1234 },
+        };
+        assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details));
+        assert_ok!(Publish::publisher_deregister(RuntimeOrigin::signed(PUBLISHER), true, 1));
+
+        // A deregistered publisher no longer holds a deposit, so it cannot sell its games again
+        assert_noop!(
+            Games::game_status_set(RuntimeOrigin::signed(PUBLISHER), game_id, GameStatus::Active),
+            Error::<Test>::InvalidPublisher
+        );
+        assert_ok!(Games::game_status_set(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            GameStatus::Hidden
+        ));
+    })
+}
+
+#[test]
+fn test_publisher_deregister_buy_fails() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 1234 },
+        };
+        assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details));
+        assert_ok!(Publish::publisher_deregister(RuntimeOrigin::signed(PUBLISHER), true, 1));
+        // Even if the game was listed again, e.g. before relisting was checked
+        GameStatuses::<Test>::insert(PUBLISHER, game_id, GameStatus::Active);
+
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id),
+            Error::<Test>::InvalidPublisher
+        );
+    })
+}
+
+#[test]
+fn test_game_buy_free() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Free { cid: cid.clone() },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
+
+        System::assert_last_event(
+            Event::GamePurchased {
+                buyer: FUNDED_BUYER,
+                publisher: PUBLISHER,
+                game_id,
+                delivery: Delivery::Plain(cid),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_game_buy_instant() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let cid = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Instant { price, cid: cid.clone() },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER),
+            INITIAL_BALANCE - price
+        );
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&PUBLISHER),
+            INITIAL_BALANCE + price
+        );
+
+        System::assert_last_event(
+            Event::GamePurchased {
+                buyer: FUNDED_BUYER,
+                publisher: PUBLISHER,
+                game_id,
+                delivery: Delivery::Plain(cid),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_refund_policy_set() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let policy = RefundPolicy { window: 50, max_downloads: 2 };
+        add_refundable_game(game_id, 12345, policy.clone());
+
+        assert_eq!(RefundPolicies::<Test>::get(PUBLISHER, game_id), Some(policy.clone()));
+        System::assert_last_event(
+            Event::RefundPolicySet { publisher: PUBLISHER, game_id, policy: Some(policy) }.into(),
+        );
+
+        assert_ok!(Games::refund_policy_set(RuntimeOrigin::signed(PUBLISHER), game_id, None));
+        assert_eq!(RefundPolicies::<Test>::get(PUBLISHER, game_id), None);
+    })
+}
+
+#[test]
+fn test_refund_policy_set_game_not_found() {
+    new_test_ext().execute_with(|| {
+        let policy = RefundPolicy { window: 50, max_downloads: 2 };
+        assert_noop!(
+            Games::refund_policy_set(RuntimeOrigin::signed(PUBLISHER), 1, Some(policy)),
+            Error::<Test>::GameNotFound
+        );
+    })
+}
+
+#[test]
+fn test_refund_policy_set_invalid() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        add_refundable_game(game_id, 12345, RefundPolicy { window: 50, max_downloads: 2 });
+
+        let policy = RefundPolicy { window: 0, max_downloads: 2 };
+        assert_noop!(
+            Games::refund_policy_set(RuntimeOrigin::signed(PUBLISHER), game_id, Some(policy)),
+            Error::<Test>::RefundPolicyInvalid
+        );
+    })
+}
+
+#[test]
+fn test_game_buy_instant_refundable() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        add_refundable_game(game_id, price, RefundPolicy { window: 50, max_downloads: 2 });
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
+        assert_eq!(
+            Purchases::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)),
+            Some(PurchaseDetails { payment: price, refundable_until: 51, max_downloads: 2 })
+        );
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER),
+            INITIAL_BALANCE - price
+        );
+        // The payment is held for the publisher
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&PUBLISHER), INITIAL_BALANCE);
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::RefundablePayment.into(),
+                &PUBLISHER
+            ),
+            price
+        );
+    })
+}
+
+#[test]
+fn test_purchase_refund() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        add_refundable_game(game_id, price, RefundPolicy { window: 50, max_downloads: 2 });
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        System::set_block_number(50);
+        assert_ok!(Games::purchase_refund(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            PUBLISHER,
+            game_id,
+            2
+        ));
+
+        assert_eq!(Purchases::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER), INITIAL_BALANCE);
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&PUBLISHER), INITIAL_BALANCE);
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::RefundablePayment.into(),
+                &PUBLISHER
+            ),
+            0
+        );
+        System::assert_last_event(
+            Event::PurchaseRefunded { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id }.into(),
+        );
+
+        // The game can be bought again
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+    })
+}
+
+#[test]
+fn test_purchase_refund_window_closed() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        add_refundable_game(game_id, 12345, RefundPolicy { window: 50, max_downloads: 2 });
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        System::set_block_number(51);
+        assert_noop!(
+            Games::purchase_refund(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, 0),
+            Error::<Test>::RefundWindowClosed
+        );
+    })
+}
+
+#[test]
+fn test_purchase_refund_too_many_downloads() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        add_refundable_game(game_id, 12345, RefundPolicy { window: 50, max_downloads: 2 });
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_noop!(
+            Games::purchase_refund(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, 3),
+            Error::<Test>::TooManyDownloads
+        );
+    })
+}
+
+#[test]
+fn test_purchase_refund_without_policy() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        add_refundable_game(game_id, 12345, RefundPolicy { window: 50, max_downloads: 2 });
+        assert_ok!(Games::refund_policy_set(RuntimeOrigin::signed(PUBLISHER), game_id, None));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_noop!(
+            Games::purchase_refund(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id, 0),
+            Error::<Test>::PurchaseNotFound
+        );
+    })
+}
+
+#[test]
+fn test_purchase_settle() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        add_refundable_game(game_id, price, RefundPolicy { window: 50, max_downloads: 2 });
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        System::set_block_number(51);
+        // Anyone can settle a purchase once the refund window has closed
+        assert_ok!(Games::purchase_settle(
+            RuntimeOrigin::signed(FUNDED_BUYER_2),
+            FUNDED_BUYER,
+            PUBLISHER,
+            game_id
+        ));
+
+        assert_eq!(Purchases::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&PUBLISHER),
+            INITIAL_BALANCE + price
+        );
+        System::assert_last_event(
+            Event::PurchaseSettled { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id }.into(),
+        );
+    })
+}
+
+#[test]
+fn test_purchase_settle_window_open() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        add_refundable_game(game_id, 12345, RefundPolicy { window: 50, max_downloads: 2 });
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        System::set_block_number(50);
+        assert_noop!(
+            Games::purchase_settle(
+                RuntimeOrigin::signed(PUBLISHER),
+                FUNDED_BUYER,
+                PUBLISHER,
+                game_id
+            ),
+            Error::<Test>::RefundWindowOpen
+        );
+    })
+}
+
+#[test]
+fn test_game_buy_individual() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        let expected = OrderDetails { deposit: price, expires_at: 1 + ORDER_TIMEOUT };
+        assert_eq!(BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(expected));
+        assert_eq!(PublisherOrders::<Test>::get((PUBLISHER, game_id, FUNDED_BUYER)), Some(()));
+        assert_eq!(PendingOrdersCount::<Test>::get(PUBLISHER, game_id), 1);
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER),
+            INITIAL_BALANCE - price
+        );
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::GamePayment.into(),
+                &FUNDED_BUYER
+            ),
+            price
+        );
+        System::assert_last_event(
+            Event::OrderPlaced { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id }.into(),
+        );
+    })
+}
+
+#[test]
+fn test_game_buy_invalid_game() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1),
+            Error::<Test>::GameNotFound
+        );
+    })
+}
+
+#[test]
+fn test_game_buy_not_active() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Free {
+                cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        for status in [GameStatus::Hidden, GameStatus::Delisted, GameStatus::Retired] {
+            GameStatuses::<Test>::insert(PUBLISHER, game_id, status);
+            assert_noop!(
+                Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id),
+                Error::<Test>::GameNotActive
+            );
+        }
+    })
+}
+
+#[test]
+fn test_game_buy_suspended_publisher() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Free {
+                cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        assert_ok!(Publish::publisher_suspend(RuntimeOrigin::root(), PUBLISHER));
+
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id),
+            Error::<Test>::PublisherSuspended
+        );
+
+        assert_ok!(Publish::publisher_reinstate(RuntimeOrigin::root(), PUBLISHER));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+    })
+}
+
+#[test]
+fn test_game_buy_under_deposited_publisher() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Free {
+                cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        assert_ok!(Publish::deposit_set(RuntimeOrigin::root(), 1000));
+
+        // The games still sell while the publisher can top up its deposit
+        System::set_block_number(5);
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        System::set_block_number(20);
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER_2), PUBLISHER, game_id),
+            Error::<Test>::InvalidPublisher
+        );
+    })
+}
+
+#[test]
+fn test_game_buy_no_funds() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Instant {
+                price,
+                cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, game_id),
+            TokenError::FundsUnavailable
+        );
+    })
+}
+
+#[test]
+fn test_order_place_no_funds() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(NON_FUNDED_BUYER), PUBLISHER, game_id),
+            TokenError::FundsUnavailable
+        );
+    })
+}
+
+#[test]
+fn test_order_place_already_placed() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id),
+            Error::<Test>::OrderAlreadyPlaced
+        );
+    })
+}
+
+#[test]
+fn test_order_place_owned_game() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        OwnedGames::<Test>::insert(FUNDED_BUYER, (PUBLISHER, game_id), ());
+
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id),
+            Error::<Test>::GameAlreadyExists
+        );
+    })
+}
+
+#[test]
+fn test_order_place_multiple() {
+    new_test_ext().execute_with(|| {
+        let game_id_1 = 1;
+        let price_1 = 11111;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: price_1 },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id_1, details);
+
+        let game_id_2 = 2;
+        let price_2 = 22222;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: price_2 },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id_2, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id_1));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id_2));
+
+        assert_eq!(
+            BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id_1)),
+            Some(OrderDetails { deposit: price_1, expires_at: 1 + ORDER_TIMEOUT })
+        );
+        assert_eq!(
+            BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id_2)),
+            Some(OrderDetails { deposit: price_2, expires_at: 1 + ORDER_TIMEOUT })
+        );
+        assert_eq!(PublisherOrders::<Test>::get((PUBLISHER, game_id_1, FUNDED_BUYER)), Some(()));
+        assert_eq!(PublisherOrders::<Test>::get((PUBLISHER, game_id_2, FUNDED_BUYER)), Some(()));
+
+        let total = price_1 + price_2;
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER),
+            INITIAL_BALANCE - total
+        );
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::GamePayment.into(),
+                &FUNDED_BUYER
+            ),
+            total
+        );
+    })
+}
+
+#[test]
+fn test_order_place_multiple_buyers() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER_2), PUBLISHER, game_id));
+
+        assert_eq!(PublisherOrders::<Test>::get((PUBLISHER, game_id, FUNDED_BUYER)), Some(()));
+        assert_eq!(PublisherOrders::<Test>::get((PUBLISHER, game_id, FUNDED_BUYER_2)), Some(()));
+        assert_eq!(PendingOrdersCount::<Test>::get(PUBLISHER, game_id), 2);
+
+        let mut pending = Games::pending_orders(&PUBLISHER, game_id);
+        pending.sort();
+        assert_eq!(pending, vec![FUNDED_BUYER, FUNDED_BUYER_2]);
+    })
+}
+
+#[test]
+fn test_order_cancel() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_ok!(Games::order_cancel(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_eq!(BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
+        assert_eq!(PublisherOrders::<Test>::get((PUBLISHER, game_id, FUNDED_BUYER)), None);
+        assert_eq!(PendingOrdersCount::<Test>::get(PUBLISHER, game_id), 0);
+        assert_eq!(OrderExpiries::<Test>::iter().count(), 0);
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER), INITIAL_BALANCE);
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::GamePayment.into(),
+                &FUNDED_BUYER
+            ),
+            0
+        );
+        System::assert_last_event(
+            Event::OrderCancelled { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id }.into(),
+        );
+    })
+}
+
+#[test]
+fn test_order_cancel_suspended_publisher() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_ok!(Publish::publisher_suspend(RuntimeOrigin::root(), PUBLISHER));
+
+        // New orders are blocked, but the existing ones can still be cancelled
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER_2), PUBLISHER, game_id),
+            Error::<Test>::PublisherSuspended
+        );
+        assert_ok!(Games::order_cancel(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_eq!(BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn test_order_cancel_keeps_other_buyers() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER_2), PUBLISHER, game_id));
+
+        assert_ok!(Games::order_cancel(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_eq!(PublisherOrders::<Test>::get((PUBLISHER, game_id, FUNDED_BUYER)), None);
+        assert_eq!(PublisherOrders::<Test>::get((PUBLISHER, game_id, FUNDED_BUYER_2)), Some(()));
+        assert_eq!(PendingOrdersCount::<Test>::get(PUBLISHER, game_id), 1);
+        assert_eq!(Games::pending_orders(&PUBLISHER, game_id), vec![FUNDED_BUYER_2]);
+    })
+}
+
+#[test]
+fn test_order_cancel_missing_order() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Games::order_cancel(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, 1),
+            Error::<Test>::OrderNotFound
+        );
+    })
+}
+
+#[test]
+fn test_order_reject() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        let reason = RejectionReason::Other(bounded_vec(b"Out of keys until next week"));
+        assert_ok!(Games::order_reject(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            FUNDED_BUYER,
+            reason.clone()
+        ));
+
+        assert_eq!(BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
+        assert_eq!(PublisherOrders::<Test>::get((PUBLISHER, game_id, FUNDED_BUYER)), None);
+        assert_eq!(PendingOrdersCount::<Test>::get(PUBLISHER, game_id), 0);
+        assert_eq!(OrderExpiries::<Test>::iter().count(), 0);
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER), INITIAL_BALANCE);
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::GamePayment.into(),
+                &FUNDED_BUYER
+            ),
+            0
+        );
+        System::assert_last_event(
+            Event::OrderRejected { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id, reason }
+                .into(),
+        );
+    })
+}
+
+#[test]
+fn test_order_reject_keeps_other_buyers() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER_2), PUBLISHER, game_id));
+
+        assert_ok!(Games::order_reject(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            FUNDED_BUYER,
+            RejectionReason::RegionUnsupported
+        ));
+
+        assert_eq!(PendingOrdersCount::<Test>::get(PUBLISHER, game_id), 1);
+        assert_eq!(Games::pending_orders(&PUBLISHER, game_id), vec![FUNDED_BUYER_2]);
+    })
+}
+
+#[test]
+fn test_order_reject_invalid_reason() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_noop!(
+            Games::order_reject(
+                RuntimeOrigin::signed(PUBLISHER),
+                game_id,
+                FUNDED_BUYER,
+                RejectionReason::Other(bounded_vec(b""))
+            ),
+            Error::<Test>::RejectionReasonInvalid
+        );
+    })
+}
+
+#[test]
+fn test_order_reject_missing_order() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Games::order_reject(
+                RuntimeOrigin::signed(PUBLISHER),
+                1,
+                FUNDED_BUYER,
+                RejectionReason::OutOfStock
+            ),
+            Error::<Test>::OrderNotFound
+        );
+    })
+}
+
+#[test]
+fn test_order_reject_other_publisher() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        // Only the publisher of the game can reject its orders
+        assert_noop!(
+            Games::order_reject(
+                RuntimeOrigin::signed(INVALID_PUBLISHER),
+                game_id,
+                FUNDED_BUYER,
+                RejectionReason::OutOfStock
+            ),
+            Error::<Test>::OrderNotFound
+        );
+    })
+}
+
+#[test]
+fn test_order_expire() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        let expires_at = 1 + ORDER_TIMEOUT;
+        assert_eq!(
+            OrderExpiries::<Test>::get((expires_at, FUNDED_BUYER, (PUBLISHER, game_id))),
+            Some(())
+        );
+
+        System::set_block_number(expires_at);
+        // Anyone can expire an order once it has timed out
+        assert_ok!(Games::order_expire(
+            RuntimeOrigin::signed(FUNDED_BUYER_2),
+            FUNDED_BUYER,
+            PUBLISHER,
+            game_id
+        ));
+
+        assert_eq!(BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
+        assert_eq!(PublisherOrders::<Test>::get((PUBLISHER, game_id, FUNDED_BUYER)), None);
+        assert_eq!(PendingOrdersCount::<Test>::get(PUBLISHER, game_id), 0);
+        assert_eq!(OrderExpiries::<Test>::iter().count(), 0);
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER), INITIAL_BALANCE);
+        System::assert_last_event(
+            Event::OrderExpired { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id }.into(),
+        );
+    })
+}
+
+#[test]
+fn test_order_expire_not_expired() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        System::set_block_number(ORDER_TIMEOUT);
+        assert_noop!(
+            Games::order_expire(
+                RuntimeOrigin::signed(FUNDED_BUYER),
+                FUNDED_BUYER,
+                PUBLISHER,
+                game_id
+            ),
+            Error::<Test>::OrderNotExpired
+        );
+    })
+}
+
+#[test]
+fn test_order_expire_missing_order() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Games::order_expire(RuntimeOrigin::signed(FUNDED_BUYER), FUNDED_BUYER, PUBLISHER, 1),
+            Error::<Test>::OrderNotFound
+        );
+    })
+}
+
+#[test]
+fn test_on_idle_expires_orders() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        System::set_block_number(2);
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER_2), PUBLISHER, game_id));
+
+        // Only the first order has expired
+        let now = 1 + ORDER_TIMEOUT;
+        Games::on_idle(now, Weight::MAX);
+
+        assert_eq!(BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
+        assert!(BuyerOrders::<Test>::get(FUNDED_BUYER_2, (PUBLISHER, game_id)).is_some());
+        assert_eq!(ExpiryCursor::<Test>::get(), now + 1);
+        System::assert_last_event(
+            Event::OrderExpired { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id }.into(),
+        );
+
+        Games::on_idle(now + 1, Weight::MAX);
+
+        assert_eq!(BuyerOrders::<Test>::get(FUNDED_BUYER_2, (PUBLISHER, game_id)), None);
+        assert_eq!(PendingOrdersCount::<Test>::get(PUBLISHER, game_id), 0);
+        assert_eq!(OrderExpiries::<Test>::iter().count(), 0);
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER_2), INITIAL_BALANCE);
+    })
+}
+
+#[test]
+fn test_on_idle_expires_orders_within_weight() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER_2), PUBLISHER, game_id));
+
+        // There is only enough weight to expire a single order
+        let now = 1 + ORDER_TIMEOUT;
+        let weight = <() as WeightInfo>::order_expire();
+        assert_eq!(Games::on_idle(now, weight), weight);
+
+        assert_eq!(PendingOrdersCount::<Test>::get(PUBLISHER, game_id), 1);
+        assert_eq!(ExpiryCursor::<Test>::get(), now);
+
+        // The remaining order is expired once there is weight left again
+        assert_eq!(Games::on_idle(now, weight), weight);
+
+        assert_eq!(PendingOrdersCount::<Test>::get(PUBLISHER, game_id), 0);
+        assert_eq!(OrderExpiries::<Test>::iter().count(), 0);
+
+        // Not even a single order fits
+        assert_eq!(Games::on_idle(now, Weight::zero()), Weight::zero());
+    })
+}
+
+#[test]
+fn test_order_fulfill_by_member() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_ok!(Publish::member_grant(
+            RuntimeOrigin::signed(PUBLISHER),
+            CATALOG_MANAGER,
+            PublisherRole::CatalogManager
+        ));
+        assert_ok!(Publish::member_grant(
+            RuntimeOrigin::signed(PUBLISHER),
+            ORDER_FULFILLER,
+            PublisherRole::OrderFulfiller
+        ));
+
+        assert_noop!(
+            Games::order_fulfill(
+                RuntimeOrigin::signed(CATALOG_MANAGER),
+                game_id,
+                FUNDED_BUYER,
+                delivery()
+            ),
+            Error::<Test>::NoPermission
+        );
+        assert_ok!(Games::order_fulfill(
+            RuntimeOrigin::signed(ORDER_FULFILLER),
+            game_id,
+            FUNDED_BUYER,
+            delivery()
+        ));
+
+        // The payment goes to the publisher, not to the member
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&PUBLISHER),
+            INITIAL_BALANCE + price
+        );
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&ORDER_FULFILLER), 0);
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
+    });
+}
+
+#[test]
+fn test_order_fulfill() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        let delivery = delivery();
+        assert_ok!(Games::order_fulfill(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            FUNDED_BUYER,
+            delivery.clone()
+        ));
+
+        assert_eq!(BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
+        assert_eq!(PublisherOrders::<Test>::get((PUBLISHER, game_id, FUNDED_BUYER)), None);
+        assert_eq!(PendingOrdersCount::<Test>::get(PUBLISHER, game_id), 0);
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&PUBLISHER),
+            INITIAL_BALANCE + price
+        );
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER),
+            INITIAL_BALANCE - price
+        );
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::GamePayment.into(),
+                &FUNDED_BUYER
+            ),
+            0
+        );
+        assert_eq!(
+            Deliveries::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)),
+            Some(delivery.clone())
+        );
+        System::assert_has_event(
+            Event::OrderFulfilled { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id }.into(),
+        );
+        System::assert_last_event(
+            Event::GamePurchased { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id, delivery }
+                .into(),
+        );
+    })
+}
+
+#[test]
+fn test_order_fulfill_encrypted() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        let delivery = Delivery::Encrypted(bounded_vec(&[0xab; 96]));
+        assert_ok!(Games::order_fulfill(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            FUNDED_BUYER,
+            delivery.clone()
+        ));
+
+        assert_eq!(
+            Deliveries::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)),
+            Some(delivery.clone())
+        );
+        System::assert_last_event(
+            Event::GamePurchased { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id, delivery }
+                .into(),
+        );
+    })
+}
+
+#[test]
+fn test_order_fulfill_invalid_delivery() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        assert_noop!(
+            Games::order_fulfill(
+                RuntimeOrigin::signed(PUBLISHER),
+                game_id,
+                FUNDED_BUYER,
+                Delivery::Plain(bounded_vec(b"NotARealCID123"))
+            ),
+            Error::<Test>::DeliveryInvalid
+        );
+        assert_noop!(
+            Games::order_fulfill(
+                RuntimeOrigin::signed(PUBLISHER),
+                game_id,
+                FUNDED_BUYER,
+                Delivery::Encrypted(bounded_vec(&[]))
+            ),
+            Error::<Test>::DeliveryInvalid
+        );
+    })
+}
+
+#[test]
+fn test_order_fulfill_missing_order() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Games::order_fulfill(RuntimeOrigin::signed(PUBLISHER), 1, FUNDED_BUYER, delivery()),
+            Error::<Test>::OrderNotFound
+        );
+    })
+}
+
+#[test]
+fn test_order_fulfill_invalid_game() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        let invalid_game_id = game_id + 1;
+        assert_noop!(
+            Games::order_fulfill(
+                RuntimeOrigin::signed(PUBLISHER),
+                invalid_game_id,
+                FUNDED_BUYER,
+                delivery()
+            ),
+            Error::<Test>::OrderNotFound
+        );
+    })
+}
+
+#[test]
+fn test_order_fulfill_multiple_buyers() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER_2), PUBLISHER, game_id));
+
+        for buyer in Games::pending_orders(&PUBLISHER, game_id) {
+            assert_ok!(Games::order_fulfill(
+                RuntimeOrigin::signed(PUBLISHER),
+                game_id,
+                buyer,
+                delivery()
+            ));
+        }
+
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), Some(()));
+        assert_eq!(OwnedGames::<Test>::get(FUNDED_BUYER_2, (PUBLISHER, game_id)), Some(()));
+        assert_eq!(PendingOrdersCount::<Test>::get(PUBLISHER, game_id), 0);
+        assert!(Games::pending_orders(&PUBLISHER, game_id).is_empty());
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&PUBLISHER),
+            INITIAL_BALANCE + price * 2
+        );
+    })
+}
+
+fn add_tagged_game(game_id: u16, tags: &[u16]) {
+    let details = GameDetails {
+        name: bounded_vec(b"Example Game"),
+        tags: bounded_vec(tags),
+        distribution: Distribution::Individual { price: 12345 },
+    };
+    PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+    for tag_id in tags {
+        GamesByTag::<Test>::insert(tag_id, (PUBLISHER, game_id), ());
+    }
+}
+
+#[test]
+fn test_games_of() {
+    new_test_ext().execute_with(|| {
+        for game_id in 1..=3 {
+            add_tagged_game(game_id, &[1]);
+        }
+
+        let first_page = Games::games_of(&PUBLISHER, None, 2);
+        assert_eq!(first_page.len(), 2);
+        let cursor = first_page.last().map(|((_, game_id), _)| *game_id);
+        let second_page = Games::games_of(&PUBLISHER, cursor, 2);
+        assert_eq!(second_page.len(), 1);
+
+        let mut game_ids = first_page
+            .iter()
+            .chain(second_page.iter())
+            .map(|((publisher, game_id), _)| {
+                assert_eq!(*publisher, PUBLISHER);
+                *game_id
+            })
+            .collect::<Vec<_>>();
+        game_ids.sort();
+        assert_eq!(game_ids, vec![1, 2, 3]);
+        assert!(Games::games_of(&FUNDED_BUYER, None, 2).is_empty());
+    })
+}
+
+#[test]
+fn test_games_by_tag() {
+    new_test_ext().execute_with(|| {
+        add_tagged_game(1, &[1]);
+        add_tagged_game(2, &[2]);
+        add_tagged_game(3, &[1, 2]);
+
+        let first_page = Games::games_by_tag(1, None, 1);
+        assert_eq!(first_page.len(), 1);
+        let cursor = first_page.last().map(|(global_game_id, _)| *global_game_id);
+        let second_page = Games::games_by_tag(1, cursor, 10);
+        assert_eq!(second_page.len(), 1);
+
+        let mut game_ids = first_page
+            .iter()
+            .chain(second_page.iter())
+            .map(|((_, game_id), _)| *game_id)
+            .collect::<Vec<_>>();
+        game_ids.sort();
+        assert_eq!(game_ids, vec![1, 3]);
+        assert!(Games::games_by_tag(3, None, 10).is_empty());
+    })
+}
+
+#[test]
+fn test_owned_games() {
+    new_test_ext().execute_with(|| {
+        for game_id in 1..=3 {
+            OwnedGames::<Test>::insert(FUNDED_BUYER, (PUBLISHER, game_id), ());
+        }
+
+        let first_page = Games::owned_games(FUNDED_BUYER, None, 2);
+        assert_eq!(first_page.len(), 2);
+        let second_page = Games::owned_games(FUNDED_BUYER, first_page.last().copied(), 2);
+        assert_eq!(second_page.len(), 1);
+
+        let mut game_ids = first_page
+            .iter()
+            .chain(second_page.iter())
+            .map(|(_, game_id)| *game_id)
+            .collect::<Vec<_>>();
+        game_ids.sort();
+        assert_eq!(game_ids, vec![1, 2, 3]);
+        assert!(Games::owned_games(FUNDED_BUYER_2, None, 2).is_empty());
+    })
+}
+
+#[test]
+fn test_owned_games_license_key() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            liganite_license::owned_game_key(&FUNDED_BUYER, &PUBLISHER, 1),
+            OwnedGames::<Test>::hashed_key_for(FUNDED_BUYER, (PUBLISHER, 1))
+        );
+    })
+}
+
+#[test]
+fn test_owned_games_license_proof() {
+    let game_id = 1;
+    let mut ext = new_test_ext();
+    ext.execute_with(|| {
+        add_refundable_game(game_id, 12345, RefundPolicy { window: 50, max_downloads: 2 });
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+    });
+    ext.commit_all().unwrap();
+    let state_root = *ext.as_backend().root();
+
+    // Prove the ownership entries read from the state, as a node does for `state_getReadProof`
+    let (_, storage_proof) = ext.execute_and_prove(|| {
+        assert!(OwnedGames::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)).is_some());
+        assert!(OwnedGames::<Test>::get(FUNDED_BUYER_2, (PUBLISHER, game_id)).is_none());
+    });
+    let header = HeaderFor::<Test>::new(
+        1,
+        Default::default(),
+        state_root,
+        Default::default(),
+        Default::default(),
+    );
+    let trust = Trust::Finalized(header.hash());
+    let proof = |buyer| LicenseProof {
+        buyer,
+        publisher: PUBLISHER,
+        game_id,
+        header: header.clone(),
+        storage_proof: storage_proof.clone(),
+        finality: Finality::HeaderChain(vec![]),
+    };
+
+    assert_eq!(
+        liganite_license::verify(proof(FUNDED_BUYER), &trust),
+        Ok(License { buyer: FUNDED_BUYER, publisher: PUBLISHER, game_id, block_number: 1 })
+    );
+    assert_eq!(
+        liganite_license::verify(proof(FUNDED_BUYER_2), &trust),
+        Err(liganite_license::Error::NotOwned)
+    );
+}
+
+#[test]
+fn test_buyer_and_publisher_orders() {
+    new_test_ext().execute_with(|| {
+        let price = 12345;
+        for game_id in 1..=2 {
+            let details = GameDetails {
+                name: bounded_vec(b"Example Game"),
+                tags: Default::default(),
+                distribution: Distribution::Individual { price },
+            };
+            PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+            assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+        }
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER_2), PUBLISHER, 1));
+
+        let mut orders = Games::buyer_orders(FUNDED_BUYER, None, MAX_PAGE_SIZE);
+        orders.sort_by_key(|(global_game_id, _)| *global_game_id);
+        let expires_at = 1 + ORDER_TIMEOUT;
+        assert_eq!(
+            orders,
+            vec![
+                ((PUBLISHER, 1), OrderDetails { deposit: price, expires_at }),
+                ((PUBLISHER, 2), OrderDetails { deposit: price, expires_at }),
+            ]
+        );
+        assert!(Games::buyer_orders(NON_FUNDED_BUYER, None, MAX_PAGE_SIZE).is_empty());
+
+        let mut buyers = Games::publisher_orders(PUBLISHER, 1, None, MAX_PAGE_SIZE);
+        buyers.sort();
+        assert_eq!(buyers, vec![FUNDED_BUYER, FUNDED_BUYER_2]);
+        assert_eq!(Games::publisher_orders(PUBLISHER, 2, None, MAX_PAGE_SIZE), vec![FUNDED_BUYER]);
+        assert!(Games::publisher_orders(PUBLISHER, 3, None, MAX_PAGE_SIZE).is_empty());
+    })
+}
+
+#[test]
+fn test_buyer_and_publisher_orders_pages() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let order = OrderDetails { deposit: 12345, expires_at: 1 + ORDER_TIMEOUT };
+        for buyer in 100..=102 {
+            BuyerOrders::<Test>::insert(buyer, (PUBLISHER, game_id), &order);
+            PublisherOrders::<Test>::insert((PUBLISHER, game_id, buyer), ());
+        }
+        for game_id in 1..=3 {
+            BuyerOrders::<Test>::insert(FUNDED_BUYER, (PUBLISHER, game_id), &order);
+        }
+
+        let first_page = Games::buyer_orders(FUNDED_BUYER, None, 2);
+        assert_eq!(first_page.len(), 2);
+        let start = first_page.last().map(|(global_game_id, _)| *global_game_id);
+        let second_page = Games::buyer_orders(FUNDED_BUYER, start, 2);
+        assert_eq!(second_page.len(), 1);
+        let mut game_ids = first_page
+            .iter()
+            .chain(second_page.iter())
+            .map(|((_, game_id), _)| *game_id)
+            .collect::<Vec<_>>();
+        game_ids.sort();
+        assert_eq!(game_ids, vec![1, 2, 3]);
+
+        let first_page = Games::publisher_orders(PUBLISHER, game_id, None, 2);
+        assert_eq!(first_page.len(), 2);
+        let second_page =
+            Games::publisher_orders(PUBLISHER, game_id, first_page.last().copied(), 2);
+        assert_eq!(second_page.len(), 1);
+        let mut buyers = first_page.into_iter().chain(second_page).collect::<Vec<_>>();
+        buyers.sort();
+        assert_eq!(buyers, vec![100, 101, 102]);
+    })
+}
+
+#[test]
+fn test_orders_page_size_is_capped() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let order = OrderDetails { deposit: 12345, expires_at: 1 + ORDER_TIMEOUT };
+        for buyer in 0..=u64::from(MAX_PAGE_SIZE) {
+            PublisherOrders::<Test>::insert((PUBLISHER, game_id, buyer), ());
+        }
+        for game_id in 0..=MAX_PAGE_SIZE as u16 {
+            BuyerOrders::<Test>::insert(FUNDED_BUYER, (PUBLISHER, game_id), &order);
+        }
+
+        assert_eq!(Games::buyer_orders(FUNDED_BUYER, None, u32::MAX).len(), MAX_PAGE_SIZE as usize);
+        assert_eq!(
+            Games::publisher_orders(PUBLISHER, game_id, None, u32::MAX).len(),
+            MAX_PAGE_SIZE as usize
+        );
+    })
+}
+
+fn transfer_publisher() {
+    assert_ok!(Publish::publisher_transfer_initiate(
+        RuntimeOrigin::signed(PUBLISHER),
+        NEW_PUBLISHER
+    ));
+    assert_ok!(Publish::publisher_transfer_accept(RuntimeOrigin::signed(NEW_PUBLISHER), PUBLISHER));
+}
+
+#[test]
+fn test_publisher_transfer_moves_catalog() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        let policy = RefundPolicy { window: 50, max_downloads: 2 };
+        add_refundable_game(game_id, price, policy);
+        GamesCount::<Test>::insert(PUBLISHER, 1);
+        let (v1, v2) = (SemVer::new(1, 0, 0), SemVer::new(1, 1, 0));
+        publish_release(game_id, v1);
+        publish_release(game_id, v2);
+        assert_ok!(Games::game_status_set(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            GameStatus::Delisted
+        ));
+        let details = PublishedGames::<Test>::get(PUBLISHER, game_id).unwrap();
+
+        transfer_publisher();
+
+        assert_eq!(CatalogTransfers::<Test>::get(PUBLISHER), Some(NEW_PUBLISHER));
+        assert_eq!(GamesCount::<Test>::get(PUBLISHER), 0);
+        assert_eq!(GamesCount::<Test>::get(NEW_PUBLISHER), 1);
+
+        Games::on_idle(1, Weight::MAX);
+
+        assert_eq!(CatalogTransfers::<Test>::get(PUBLISHER), None);
+        assert_eq!(PublishedGames::<Test>::get(PUBLISHER, game_id), None);
+        assert_eq!(PublishedGames::<Test>::get(NEW_PUBLISHER, game_id), Some(details));
+        assert_eq!(GameStatuses::<Test>::get(NEW_PUBLISHER, game_id), GameStatus::Delisted);
+        assert_eq!(RefundPolicies::<Test>::get(NEW_PUBLISHER, game_id), Some(policy));
+        assert_eq!(RefundPolicies::<Test>::get(PUBLISHER, game_id), None);
+        assert_eq!(LatestRelease::<Test>::get(NEW_PUBLISHER, game_id), Some(v2));
+        assert_eq!(LatestRelease::<Test>::get(PUBLISHER, game_id), None);
+        assert_eq!(HighestRelease::<Test>::get(NEW_PUBLISHER, game_id), Some(v2));
+        assert_eq!(HighestRelease::<Test>::get(PUBLISHER, game_id), None);
+        assert!(Releases::<Test>::get((NEW_PUBLISHER, game_id, v1)).is_some());
+        assert!(Releases::<Test>::get((NEW_PUBLISHER, game_id, v2)).is_some());
+        assert_eq!(Releases::<Test>::iter_prefix((PUBLISHER,)).count(), 0);
+        assert_eq!(
+            Games::latest_release(&PUBLISHER, game_id).map(|(version, _)| version),
+            Some(v2)
+        );
+        System::assert_last_event(
+            Event::CatalogTransferred { publisher: PUBLISHER, new_publisher: NEW_PUBLISHER }.into(),
+        );
+    })
+}
+
+#[test]
+fn test_publisher_transfer_moves_catalog_within_weight() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        add_refundable_game(game_id, 12345, RefundPolicy { window: 50, max_downloads: 2 });
+        publish_release(game_id, SemVer::new(1, 0, 0));
+
+        transfer_publisher();
+
+        // There is only enough weight to move a single release or game at a time
+        let weight = <() as WeightInfo>::catalog_transfer_step();
+        assert_eq!(Games::on_idle(1, weight), weight);
+        assert!(Releases::<Test>::get((NEW_PUBLISHER, game_id, SemVer::new(1, 0, 0))).is_some());
+        assert!(PublishedGames::<Test>::get(PUBLISHER, game_id).is_some());
+
+        assert_eq!(Games::on_idle(1, weight), weight);
+        assert!(PublishedGames::<Test>::get(NEW_PUBLISHER, game_id).is_some());
+        assert!(CatalogTransfers::<Test>::get(PUBLISHER).is_some());
+
+        // Not even a single step fits
+        assert_eq!(Games::on_idle(1, Weight::zero()), Weight::zero());
+        assert!(CatalogTransfers::<Test>::get(PUBLISHER).is_some());
+
+        assert_eq!(Games::on_idle(1, weight), weight);
+        assert_eq!(CatalogTransfers::<Test>::get(PUBLISHER), None);
+    })
+}
+
+#[test]
+fn test_publisher_transfer_in_progress() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        add_refundable_game(game_id, 12345, RefundPolicy { window: 50, max_downloads: 2 });
+
+        transfer_publisher();
+
+        assert!(Games::is_transferring(&PUBLISHER));
+        assert!(Games::is_transferring(&NEW_PUBLISHER));
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id),
+            Error::<Test>::CatalogTransferInProgress
+        );
+        assert_noop!(
+            Games::game_status_set(
+                RuntimeOrigin::signed(NEW_PUBLISHER),
+                game_id,
+                GameStatus::Delisted
+            ),
+            Error::<Test>::CatalogTransferInProgress
+        );
+
+        Games::on_idle(1, Weight::MAX);
+
+        assert!(!Games::is_transferring(&NEW_PUBLISHER));
+        assert_ok!(Games::game_status_set(
+            RuntimeOrigin::signed(NEW_PUBLISHER),
+            game_id,
+            GameStatus::Delisted
+        ));
+    })
+}
+
+#[test]
+fn test_publisher_transfer_keeps_purchases() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let price = 12345;
+        add_refundable_game(game_id, price, RefundPolicy { window: 50, max_downloads: 2 });
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), PUBLISHER, game_id));
+
+        transfer_publisher();
+        Games::on_idle(1, Weight::MAX);
+
+        // The payment on hold follows the publisher
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::RefundablePayment.into(),
+                &NEW_PUBLISHER
+            ),
+            price
+        );
+
+        // The game bought from the previous account is still owned
+        assert!(Games::owns_game(FUNDED_BUYER, NEW_PUBLISHER, game_id));
+        assert_noop!(
+            Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER), NEW_PUBLISHER, game_id),
+            Error::<Test>::GameAlreadyExists
+        );
+
+        // The games can still be bought through the previous account
+        assert_ok!(Games::game_buy(RuntimeOrigin::signed(FUNDED_BUYER_2), PUBLISHER, game_id));
+        assert!(OwnedGames::<Test>::contains_key(FUNDED_BUYER_2, (NEW_PUBLISHER, game_id)));
+
+        assert_ok!(Games::purchase_refund(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            NEW_PUBLISHER,
+            game_id,
+            0
+        ));
+
+        assert_eq!(Purchases::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)), None);
+        assert!(!Games::owns_game(FUNDED_BUYER, PUBLISHER, game_id));
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&FUNDED_BUYER), INITIAL_BALANCE);
+    })
+}
+
+#[test]
+fn test_publisher_transfer_keeps_deliveries() {
+    new_test_ext().execute_with(|| {
+        let (instant_game_id, individual_game_id) = (1, 2);
+        let price = 12345;
+        add_refundable_game(instant_game_id, price, RefundPolicy { window: 50, max_downloads: 2 });
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, individual_game_id, details);
+        assert_ok!(Games::game_buy(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            PUBLISHER,
+            instant_game_id
+        ));
+        assert_ok!(Games::game_buy(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            PUBLISHER,
+            individual_game_id
+        ));
+        let delivery = delivery();
+        assert_ok!(Games::order_fulfill(
+            RuntimeOrigin::signed(PUBLISHER),
+            individual_game_id,
+            FUNDED_BUYER,
+            delivery.clone()
+        ));
+
+        transfer_publisher();
+        Games::on_idle(1, Weight::MAX);
+        assert_eq!(CatalogTransfers::<Test>::get(PUBLISHER), None);
+
+        // The delivery stays under the previous account and is found through the new one
+        assert_eq!(
+            Deliveries::<Test>::get(FUNDED_BUYER, (PUBLISHER, individual_game_id)),
+            Some(delivery.clone())
+        );
+        assert_eq!(
+            Games::delivery(FUNDED_BUYER, NEW_PUBLISHER, individual_game_id),
+            Some(delivery.clone())
+        );
+        assert_eq!(Games::delivery(FUNDED_BUYER, PUBLISHER, individual_game_id), Some(delivery));
+        assert_eq!(Games::delivery(FUNDED_BUYER_2, NEW_PUBLISHER, individual_game_id), None);
+
+        assert_ok!(Games::purchase_refund(
+            RuntimeOrigin::signed(FUNDED_BUYER),
+            NEW_PUBLISHER,
+            instant_game_id,
+            0
+        ));
+        assert_eq!(Purchases::<Test>::get(FUNDED_BUYER, (PUBLISHER, instant_game_id)), None);
+        assert!(!Games::owns_game(FUNDED_BUYER, NEW_PUBLISHER, instant_game_id));
+        assert!(Games::owns_game(FUNDED_BUYER, NEW_PUBLISHER, individual_game_id));
+        System::assert_last_event(
+            Event::PurchaseRefunded {
+                buyer: FUNDED_BUYER,
+                publisher: NEW_PUBLISHER,
+                game_id: instant_game_id,
+            }
+            .into(),
+        );
+    })
+}
+
+#[test]
+fn test_migrate_v0_to_v1() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let order = OrderDetails { deposit: 12345, expires_at: 1 + ORDER_TIMEOUT };
+        StorageVersion::new(0).put::<Games>();
+
+        // The old index could only keep the last buyer of a game
+        BuyerOrders::<Test>::insert(FUNDED_BUYER, (PUBLISHER, game_id), &order);
+        BuyerOrders::<Test>::insert(FUNDED_BUYER_2, (PUBLISHER, game_id), &order);
+        migrations::v1::v0::PublisherOrders::<Test>::insert(PUBLISHER, game_id, FUNDED_BUYER_2);
+
+        migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<Games>(), 1);
+        assert_eq!(PublisherOrders::<Test>::get((PUBLISHER, game_id, FUNDED_BUYER)), Some(()));
+        assert_eq!(PublisherOrders::<Test>::get((PUBLISHER, game_id, FUNDED_BUYER_2)), Some(()));
+        assert_eq!(PublisherOrders::<Test>::iter().count(), 2);
+        assert_eq!(PendingOrdersCount::<Test>::get(PUBLISHER, game_id), 2);
+    })
+}
+
+#[test]
+fn test_migrate_v1_to_v2() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let deposit: Balance = 12345;
+        StorageVersion::new(1).put::<Games>();
+        System::set_block_number(10);
+
+        // The old order details only held the deposit
+        unhashed::put(
+            &BuyerOrders::<Test>::hashed_key_for(FUNDED_BUYER, (PUBLISHER, game_id)),
+            &deposit,
+        );
+
+        migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        let expires_at = 10 + ORDER_TIMEOUT;
+        assert_eq!(StorageVersion::get::<Games>(), 2);
+        assert_eq!(
+            BuyerOrders::<Test>::get(FUNDED_BUYER, (PUBLISHER, game_id)),
+            Some(OrderDetails { deposit, expires_at })
+        );
+        assert_eq!(
+            OrderExpiries::<Test>::get((expires_at, FUNDED_BUYER, (PUBLISHER, game_id))),
+            Some(())
+        );
+        assert_eq!(ExpiryCursor::<Test>::get(), 10);
+    })
+}
+
+#[test]
+fn test_migrate_v2_to_v3() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(2).put::<Games>();
+        for game_id in 1..=3 {
+            let details = GameDetails {
+                name: bounded_vec(b"Example Game"),
+                tags: Default::default(),
+                distribution: Distribution::Individual { price: 12345 },
+            };
+            PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        }
+
+        migrations::v3::MigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<Games>(), 3);
+        assert_eq!(GamesCount::<Test>::get(PUBLISHER), 3);
+    })
+}
+
+#[test]
+fn test_migrate_v3_to_v4() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(3).put::<Games>();
+        let _ = Tags::<Test>::clear(u32::MAX, None);
+        let _ = TagNames::<Test>::clear(u32::MAX, None);
+        let name: Tag = bounded_vec(b"Action");
+        let duplicated: Tag = bounded_vec(b"Experimental");
+        migrations::v4::v3::Tags::<Test>::insert(0, &name);
+        // Some predefined tags were duplicated
+        migrations::v4::v3::Tags::<Test>::insert(31, &duplicated);
+        migrations::v4::v3::Tags::<Test>::insert(3, &duplicated);
+
+        migrations::v4::MigrateV3ToV4::<Test>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<Games>(), 4);
+        assert_eq!(
+            migrations::v4::Tags::<Test>::get(0),
+            Some(migrations::v4::TagDetails { name: name.clone(), status: TagStatus::Active })
+        );
+        assert_eq!(
+            migrations::v4::Tags::<Test>::get(31),
+            Some(migrations::v4::TagDetails {
+                name: duplicated.clone(),
+                status: TagStatus::Active
+            })
+        );
+        assert_eq!(migrations::v4::Tags::<Test>::count(), 3);
+        assert_eq!(TagNames::<Test>::get(&name), Some(0));
+        // The duplicated name is indexed for the lowest tag id
+        assert_eq!(TagNames::<Test>::get(&duplicated), Some(3));
+        assert_eq!(TagNames::<Test>::iter().count(), 2);
+    })
+}
+
+const NEW_TAG: TagId = 1000;
+
+#[test]
+fn test_tag_add() {
+    new_test_ext().execute_with(|| {
+        let name: Tag = bounded_vec(b"Extraction Shooter");
+        assert_ok!(Games::tag_add(
+            RuntimeOrigin::root(),
+            NEW_TAG,
+            name.clone(),
+            TagCategory::SubGenre,
+            Some(0)
+        ));
+
+        assert_eq!(
+            Tags::<Test>::get(NEW_TAG),
+            Some(TagDetails::new(name.clone(), TagCategory::SubGenre, Some(0)))
+        );
+        assert_eq!(TagChildren::<Test>::get(0), 1);
+        assert_eq!(TagNames::<Test>::get(&name), Some(NEW_TAG));
+        System::assert_last_event(Event::TagAdded { tag_id: NEW_TAG, name }.into());
+
+        // The new tag can be given to games
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: bounded_vec(&[NEW_TAG]),
+            distribution: Distribution::Individual { price: 12345 },
+        };
+        assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), 1, details));
+    })
+}
 
-        let invalid_game_id = game_id + 1;
+#[test]
+fn test_tag_add_errors() {
+    new_test_ext().execute_with(|| {
+        let name: Tag = bounded_vec(b"Extraction Shooter");
         assert_noop!(
-            Games::order_fulfill(RuntimeOrigin::signed(PUBLISHER), invalid_game_id, FUNDED_BUYER),
-            Error::<Test>::OrderNotFound
+            Games::tag_add(
+                RuntimeOrigin::signed(PUBLISHER),
+                NEW_TAG,
+                name.clone(),
+                TagCategory::Genre,
+                None
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Games::tag_add(RuntimeOrigin::root(), 0, name.clone(), TagCategory::Genre, None),
+            Error::<Test>::TagAlreadyExists
+        );
+        assert_noop!(
+            Games::tag_add(
+                RuntimeOrigin::root(),
+                NEW_TAG,
+                bounded_vec(b""),
+                TagCategory::Genre,
+                None
+            ),
+            Error::<Test>::TagNameInvalid
+        );
+        assert_noop!(
+            Games::tag_add(
+                RuntimeOrigin::root(),
+                NEW_TAG,
+                bounded_vec(b"Action"),
+                TagCategory::Genre,
+                None
+            ),
+            Error::<Test>::TagNameTaken
+        );
+        assert_noop!(
+            Games::tag_add(
+                RuntimeOrigin::root(),
+                NEW_TAG,
+                name.clone(),
+                TagCategory::Genre,
+                Some(NEW_TAG)
+            ),
+            Error::<Test>::TagParentInvalid
+        );
+        assert_noop!(
+            Games::tag_add(
+                RuntimeOrigin::root(),
+                NEW_TAG,
+                name.clone(),
+                TagCategory::Genre,
+                Some(NEW_TAG + 1)
+            ),
+            Error::<Test>::TagParentInvalid
+        );
+
+        // The parent cannot have a parent itself
+        assert_ok!(Games::tag_classify(RuntimeOrigin::root(), 11, TagCategory::Genre, Some(0)));
+        assert_noop!(
+            Games::tag_add(RuntimeOrigin::root(), NEW_TAG, name, TagCategory::SubGenre, Some(11)),
+            Error::<Test>::TagParentInvalid
+        );
+    })
+}
+
+#[test]
+fn test_tag_rename() {
+    new_test_ext().execute_with(|| {
+        let previous = Tags::<Test>::get(0).unwrap().name;
+        let name: Tag = bounded_vec(b"Action Games");
+        assert_ok!(Games::tag_rename(RuntimeOrigin::root(), 0, name.clone()));
+
+        assert_eq!(Tags::<Test>::get(0).map(|tag| tag.name), Some(name.clone()));
+        assert_eq!(TagNames::<Test>::get(&name), Some(0));
+        assert_eq!(TagNames::<Test>::get(&previous), None);
+        System::assert_last_event(Event::TagRenamed { tag_id: 0, name }.into());
+
+        // The previous name can be used again
+        assert_ok!(Games::tag_add(
+            RuntimeOrigin::root(),
+            NEW_TAG,
+            previous,
+            TagCategory::Genre,
+            None
+        ));
+    })
+}
+
+#[test]
+fn test_tag_rename_errors() {
+    new_test_ext().execute_with(|| {
+        let name: Tag = bounded_vec(b"Action Games");
+        assert_noop!(
+            Games::tag_rename(RuntimeOrigin::signed(PUBLISHER), 0, name.clone()),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Games::tag_rename(RuntimeOrigin::root(), NEW_TAG, name),
+            Error::<Test>::TagNotFound
+        );
+        assert_noop!(
+            Games::tag_rename(RuntimeOrigin::root(), 0, bounded_vec(&[0xff, 0xfe])),
+            Error::<Test>::TagNameInvalid
+        );
+        assert_noop!(
+            Games::tag_rename(RuntimeOrigin::root(), 0, bounded_vec(b"Adventure")),
+            Error::<Test>::TagNameTaken
+        );
+    })
+}
+
+#[test]
+fn test_tag_deprecate() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        add_tagged_game(game_id, &[0]);
+
+        assert_noop!(
+            Games::tag_deprecate(RuntimeOrigin::signed(PUBLISHER), 0),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Games::tag_deprecate(RuntimeOrigin::root(), NEW_TAG),
+            Error::<Test>::TagNotFound
+        );
+        assert_ok!(Games::tag_deprecate(RuntimeOrigin::root(), 0));
+        assert_eq!(Tags::<Test>::get(0).map(|tag| tag.status), Some(TagStatus::Deprecated));
+        System::assert_last_event(Event::TagDeprecated { tag_id: 0 }.into());
+        assert_noop!(Games::tag_deprecate(RuntimeOrigin::root(), 0), Error::<Test>::TagDeprecated);
+
+        // A deprecated tag cannot be given to new games
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: bounded_vec(&[0]),
+            distribution: Distribution::Individual { price: 12345 },
+        };
+        assert_noop!(
+            Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id + 1, details),
+            Error::<Test>::GameDetailsInvalid
+        );
+
+        // The games which have the deprecated tag keep it
+        let update = GameDetailsUpdate {
+            name: Some(bounded_vec(b"New Name")),
+            tags: None,
+            distribution: None,
+        };
+        assert_ok!(Games::game_update(RuntimeOrigin::signed(PUBLISHER), game_id, update));
+        assert_eq!(PublishedGames::<Test>::get(PUBLISHER, game_id).unwrap().tags.to_vec(), vec![0]);
+
+        // But it cannot be given to other games
+        add_tagged_game(game_id + 1, &[1]);
+        let update =
+            GameDetailsUpdate { name: None, tags: Some(bounded_vec(&[0, 1])), distribution: None };
+        assert_noop!(
+            Games::game_update(RuntimeOrigin::signed(PUBLISHER), game_id + 1, update),
+            Error::<Test>::GameDetailsInvalid
+        );
+    })
+}
+
+#[test]
+fn test_tag_merge() {
+    new_test_ext().execute_with(|| {
+        add_tagged_game(1, &[0, 2]);
+        add_tagged_game(2, &[0, 1]);
+        add_tagged_game(3, &[2]);
+        let name = Tags::<Test>::get(0).unwrap().name;
+
+        assert_ok!(Games::tag_merge(RuntimeOrigin::root(), 0, 1));
+
+        assert_eq!(TagMerge::<Test>::get(), Some((0, 1)));
+        assert_eq!(Tags::<Test>::get(0).map(|tag| tag.status), Some(TagStatus::Deprecated));
+        System::assert_has_event(Event::TagDeprecated { tag_id: 0 }.into());
+        System::assert_last_event(Event::TagMergeStarted { from: 0, into: 1 }.into());
+
+        Games::on_idle(1, Weight::MAX);
+
+        let tags = |game_id| PublishedGames::<Test>::get(PUBLISHER, game_id).unwrap().tags.to_vec();
+        assert_eq!(tags(1), vec![1, 2]);
+        assert_eq!(tags(2), vec![1]);
+        assert_eq!(tags(3), vec![2]);
+        assert_eq!(TagMerge::<Test>::get(), None);
+        assert_eq!(TagMergeCursor::<Test>::get(), None);
+        assert_eq!(Tags::<Test>::get(0), None);
+        assert_eq!(TagNames::<Test>::get(&name), None);
+        System::assert_last_event(Event::TagMerged { from: 0, into: 1 }.into());
+    })
+}
+
+#[test]
+fn test_tag_merge_errors() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Games::tag_merge(RuntimeOrigin::signed(PUBLISHER), 0, 1),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(Games::tag_merge(RuntimeOrigin::root(), 0, 0), Error::<Test>::TagMergeInvalid);
+        assert_noop!(
+            Games::tag_merge(RuntimeOrigin::root(), NEW_TAG, 1),
+            Error::<Test>::TagNotFound
+        );
+        assert_noop!(
+            Games::tag_merge(RuntimeOrigin::root(), 0, NEW_TAG),
+            Error::<Test>::TagNotFound
+        );
+
+        assert_ok!(Games::tag_deprecate(RuntimeOrigin::root(), 1));
+        assert_noop!(Games::tag_merge(RuntimeOrigin::root(), 0, 1), Error::<Test>::TagDeprecated);
+
+        // A deprecated tag can still be merged
+        assert_ok!(Games::tag_merge(RuntimeOrigin::root(), 1, 2));
+        assert_noop!(
+            Games::tag_merge(RuntimeOrigin::root(), 3, 4),
+            Error::<Test>::TagMergeInProgress
+        );
+    })
+}
+
+#[test]
+fn test_tag_merge_within_weight() {
+    new_test_ext().execute_with(|| {
+        add_tagged_game(1, &[0]);
+        add_tagged_game(2, &[0]);
+        assert_ok!(Games::tag_merge(RuntimeOrigin::root(), 0, 1));
+
+        // There is only enough weight to merge the tags of a single game at a time
+        let weight = <() as WeightInfo>::tag_merge_step();
+        assert_eq!(Games::on_idle(1, weight), weight);
+        let merged =
+            |game_id| PublishedGames::<Test>::get(PUBLISHER, game_id).unwrap().tags[0] == 1;
+        assert_eq!(merged(1) as u8 + merged(2) as u8, 1);
+
+        // A game updated during the merge is merged at once
+        let unmerged = if merged(1) { 2 } else { 1 };
+        let update = GameDetailsUpdate {
+            name: Some(bounded_vec(b"New Name")),
+            tags: None,
+            distribution: None,
+        };
+        assert_ok!(Games::game_update(RuntimeOrigin::signed(PUBLISHER), unmerged, update));
+        assert!(merged(unmerged));
+
+        assert_eq!(Games::on_idle(1, weight), weight);
+        assert!(TagMerge::<Test>::get().is_some());
+        assert_eq!(Games::on_idle(1, weight), weight);
+        assert_eq!(TagMerge::<Test>::get(), None);
+        assert_eq!(Tags::<Test>::get(0), None);
+    })
+}
+
+#[test]
+fn test_migrate_v4_to_v5() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(4).put::<Games>();
+        let _ = Tags::<Test>::clear(u32::MAX, None);
+        let predefined: Tag = bounded_vec(b"Action");
+        let renamed: Tag = bounded_vec(b"Action Games");
+        let added: Tag = bounded_vec(b"Extraction Shooter");
+        let tag = |name: &Tag| migrations::v4::TagDetails {
+            name: name.clone(),
+            status: TagStatus::Active,
+        };
+        migrations::v4::Tags::<Test>::insert(0, tag(&predefined));
+        migrations::v4::Tags::<Test>::insert(1, tag(&renamed));
+        migrations::v4::Tags::<Test>::insert(NEW_TAG, tag(&added));
+
+        migrations::v5::MigrateV4ToV5::<Test>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<Games>(), 5);
+        assert_eq!(
+            Tags::<Test>::get(0),
+            Some(TagDetails::new(predefined, TagCategory::TopLevelGenre, None))
+        );
+        // A renamed tag may not mean the same anymore
+        assert_eq!(Tags::<Test>::get(1), Some(TagDetails::new(renamed, TagCategory::Other, None)));
+        assert_eq!(
+            Tags::<Test>::get(NEW_TAG),
+            Some(TagDetails::new(added, TagCategory::Other, None))
+        );
+        assert_eq!(Tags::<Test>::count(), 3);
+    })
+}
+
+#[test]
+fn test_genesis_tag_categories() {
+    new_test_ext().execute_with(|| {
+        let category = |tag_id| Tags::<Test>::get(tag_id).map(|tag| tag.category);
+        assert_eq!(category(0), Some(TagCategory::TopLevelGenre));
+        assert_eq!(category(11), Some(TagCategory::Genre));
+        assert_eq!(category(79), Some(TagCategory::SubGenre));
+        assert_eq!(category(399), Some(TagCategory::Hardware));
+    })
+}
+
+#[test]
+fn test_tag_classify() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Games::tag_classify(RuntimeOrigin::root(), 79, TagCategory::SubGenre, Some(11)));
+
+        let tag = Tags::<Test>::get(79).unwrap();
+        assert_eq!((tag.category, tag.parent), (TagCategory::SubGenre, Some(11)));
+        assert_eq!(TagChildren::<Test>::get(11), 1);
+        System::assert_last_event(
+            Event::TagClassified { tag_id: 79, category: TagCategory::SubGenre, parent: Some(11) }
+                .into(),
+        );
+
+        // Moving the tag to another parent updates the children of both parents
+        assert_ok!(Games::tag_classify(RuntimeOrigin::root(), 79, TagCategory::SubGenre, Some(12)));
+        assert_eq!(TagChildren::<Test>::get(11), 0);
+        assert_eq!(TagChildren::<Test>::get(12), 1);
+
+        assert_ok!(Games::tag_classify(RuntimeOrigin::root(), 79, TagCategory::Theme, None));
+        let tag = Tags::<Test>::get(79).unwrap();
+        assert_eq!((tag.category, tag.parent), (TagCategory::Theme, None));
+        assert_eq!(TagChildren::<Test>::get(12), 0);
+    })
+}
+
+#[test]
+fn test_tag_classify_errors() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Games::tag_classify(RuntimeOrigin::signed(PUBLISHER), 79, TagCategory::Genre, None),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Games::tag_classify(RuntimeOrigin::root(), NEW_TAG, TagCategory::Genre, None),
+            Error::<Test>::TagNotFound
+        );
+        assert_noop!(
+            Games::tag_classify(RuntimeOrigin::root(), 79, TagCategory::SubGenre, Some(79)),
+            Error::<Test>::TagParentInvalid
+        );
+        assert_noop!(
+            Games::tag_classify(RuntimeOrigin::root(), 79, TagCategory::SubGenre, Some(NEW_TAG)),
+            Error::<Test>::TagParentInvalid
+        );
+
+        assert_ok!(Games::tag_classify(RuntimeOrigin::root(), 79, TagCategory::SubGenre, Some(11)));
+        // The parent cannot have a parent itself
+        assert_noop!(
+            Games::tag_classify(RuntimeOrigin::root(), 80, TagCategory::SubGenre, Some(79)),
+            Error::<Test>::TagParentInvalid
+        );
+        // A tag refined by other tags cannot get a parent
+        assert_noop!(
+            Games::tag_classify(RuntimeOrigin::root(), 11, TagCategory::Genre, Some(0)),
+            Error::<Test>::TagParentInvalid
+        );
+    })
+}
+
+#[test]
+fn test_tag_merge_with_children() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Games::tag_classify(RuntimeOrigin::root(), 79, TagCategory::SubGenre, Some(11)));
+        assert_noop!(
+            Games::tag_merge(RuntimeOrigin::root(), 11, 12),
+            Error::<Test>::TagHasChildren
+        );
+
+        // Merging a child tag releases its parent
+        assert_ok!(Games::tag_merge(RuntimeOrigin::root(), 79, 80));
+        Games::on_idle(1, Weight::MAX);
+        assert_eq!(Tags::<Test>::get(79), None);
+        assert_eq!(TagChildren::<Test>::get(11), 0);
+        assert_ok!(Games::tag_merge(RuntimeOrigin::root(), 11, 12));
+    })
+}
+
+#[test]
+fn test_game_tag_rules() {
+    new_test_ext().execute_with(|| {
+        let details = |tags: &[TagId]| GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: bounded_vec(tags),
+            distribution: Distribution::Individual { price: 12345 },
+        };
+        assert_noop!(
+            Games::game_add(RuntimeOrigin::signed(PUBLISHER), 1, details(&[0, 1, 2, 3])),
+            Error::<Test>::TooManyTopLevelGenres
+        );
+        assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), 1, details(&[0, 1, 2, 140])));
+
+        MinGenres::set(2);
+        assert_noop!(
+            Games::game_add(RuntimeOrigin::signed(PUBLISHER), 2, details(&[0, 140, 169])),
+            Error::<Test>::GenreRequired
+        );
+        // The genres of every level count
+        assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), 2, details(&[11, 79])));
+
+        // The rules are checked again when the tags change
+        let update = GameDetailsUpdate {
+            name: None,
+            tags: Some(bounded_vec(&[11, 140])),
+            distribution: None,
+        };
+        assert_noop!(
+            Games::game_update(RuntimeOrigin::signed(PUBLISHER), 2, update),
+            Error::<Test>::GenreRequired
+        );
+
+        // But not when they are kept
+        MinGenres::set(4);
+        let update = GameDetailsUpdate {
+            name: Some(bounded_vec(b"New Name")),
+            tags: None,
+            distribution: None,
+        };
+        assert_ok!(Games::game_update(RuntimeOrigin::signed(PUBLISHER), 2, update));
+    })
+}
+
+fn games_with_tag(tag_id: TagId) -> Vec<(u64, u16)> {
+    let mut games = GamesByTag::<Test>::iter_key_prefix(tag_id).collect::<Vec<_>>();
+    games.sort();
+    games
+}
+
+#[test]
+fn test_games_by_tag_follows_game_updates() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: bounded_vec(&[1, 2]),
+            distribution: Distribution::Individual { price: 12345 },
+        };
+        assert_ok!(Games::game_add(RuntimeOrigin::signed(PUBLISHER), game_id, details));
+        assert_eq!(games_with_tag(1), vec![(PUBLISHER, game_id)]);
+        assert_eq!(games_with_tag(2), vec![(PUBLISHER, game_id)]);
+
+        let update =
+            GameDetailsUpdate { name: None, tags: Some(bounded_vec(&[2, 3])), distribution: None };
+        assert_ok!(Games::game_update(RuntimeOrigin::signed(PUBLISHER), game_id, update));
+        assert!(games_with_tag(1).is_empty());
+        assert_eq!(games_with_tag(2), vec![(PUBLISHER, game_id)]);
+        assert_eq!(games_with_tag(3), vec![(PUBLISHER, game_id)]);
+    })
+}
+
+#[test]
+fn test_games_by_tag_follows_game_status() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        add_tagged_game(game_id, &[1, 2]);
+
+        // Only the active games are listed
+        assert_ok!(Games::game_status_set(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            GameStatus::Delisted
+        ));
+        assert!(games_with_tag(1).is_empty());
+        assert!(games_with_tag(2).is_empty());
+
+        // The tags of a game which is not listed can change
+        let update =
+            GameDetailsUpdate { name: None, tags: Some(bounded_vec(&[3])), distribution: None };
+        assert_ok!(Games::game_update(RuntimeOrigin::signed(PUBLISHER), game_id, update));
+        assert!(games_with_tag(3).is_empty());
+
+        assert_ok!(Games::game_status_set(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            GameStatus::Active
+        ));
+        assert_eq!(games_with_tag(3), vec![(PUBLISHER, game_id)]);
+
+        assert_ok!(Games::game_status_set(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            GameStatus::Hidden
+        ));
+        assert!(games_with_tag(3).is_empty());
+    })
+}
+
+#[test]
+fn test_games_by_tag_follows_tag_merge() {
+    new_test_ext().execute_with(|| {
+        add_tagged_game(1, &[11]);
+        add_tagged_game(2, &[11, 12]);
+
+        assert_ok!(Games::tag_merge(RuntimeOrigin::root(), 11, 12));
+        Games::on_idle(1, Weight::MAX);
+
+        assert!(games_with_tag(11).is_empty());
+        assert_eq!(games_with_tag(12), vec![(PUBLISHER, 1), (PUBLISHER, 2)]);
+    })
+}
+
+#[test]
+fn test_games_by_tag_follows_catalog_transfer() {
+    new_test_ext().execute_with(|| {
+        add_tagged_game(1, &[1]);
+        add_tagged_game(2, &[1]);
+        GamesCount::<Test>::insert(PUBLISHER, 2);
+        assert_ok!(Games::game_status_set(RuntimeOrigin::signed(PUBLISHER), 2, GameStatus::Hidden));
+
+        transfer_publisher();
+        Games::on_idle(1, Weight::MAX);
+
+        assert_eq!(games_with_tag(1), vec![(NEW_PUBLISHER, 1)]);
+        assert_eq!(Games::games_by_tag(1, None, 10).len(), 1);
+    })
+}
+
+#[test]
+fn test_migrate_v5_to_v6() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(5).put::<Games>();
+        add_tagged_game(1, &[1, 2]);
+        add_tagged_game(2, &[2]);
+        add_tagged_game(3, &[2]);
+        GameStatuses::<Test>::insert(PUBLISHER, 3, GameStatus::Delisted);
+        let _ = GamesByTag::<Test>::clear(u32::MAX, None);
+
+        migrations::v6::MigrateV5ToV6::<Test>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<Games>(), 6);
+        assert_eq!(games_with_tag(1), vec![(PUBLISHER, 1)]);
+        assert_eq!(games_with_tag(2), vec![(PUBLISHER, 1), (PUBLISHER, 2)]);
+    })
+}
+
+#[test]
+fn test_genesis_tags() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Tags::<Test>::count() as usize, TAGS.len());
+        assert_eq!(TagNames::<Test>::iter().count(), TAGS.len());
+        for (tag_id, name, _) in TAGS {
+            let name: Tag = bounded_vec(name.as_bytes());
+            assert_eq!(TagNames::<Test>::get(&name), Some(*tag_id));
+        }
+        for (retired, _) in RETIRED_TAGS {
+            assert_eq!(Tags::<Test>::get(retired), None);
+        }
+    })
+}
+
+fn genesis_game(tags: &[TagId]) -> GameDetails<Balance> {
+    GameDetails {
+        name: bounded_vec(b"Genesis Game"),
+        tags: bounded_vec(tags),
+        distribution: Distribution::Free {
+            cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+        },
+    }
+}
+
+fn genesis_publisher() -> PublisherDetails {
+    PublisherDetails {
+        name: bounded_vec(b"Genesis Publisher"),
+        url: bounded_vec(b"https://genesis.mock"),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_genesis_catalogue() {
+    let genesis = crate::GenesisConfig::<Test> {
+        tags: vec![(NEW_TAG, bounded_vec(b"Genesis Genre"), TagCategory::Genre, Some(1))],
+        games: vec![(NEW_PUBLISHER, 1, genesis_game(&[1, NEW_TAG]))],
+        owned_games: vec![(FUNDED_BUYER, NEW_PUBLISHER, 1)],
+    };
+    new_test_ext_with_genesis(vec![(NEW_PUBLISHER, genesis_publisher())], genesis).execute_with(
+        || {
+            let tag = Tags::<Test>::get(NEW_TAG).unwrap();
+            assert_eq!((tag.category, tag.parent), (TagCategory::Genre, Some(1)));
+            assert_eq!(TagNames::<Test>::get(&tag.name), Some(NEW_TAG));
+            assert_eq!(TagChildren::<Test>::get(1), 1);
+
+            assert_eq!(
+                PublishedGames::<Test>::get(NEW_PUBLISHER, 1),
+                Some(genesis_game(&[1, NEW_TAG]))
+            );
+            assert_eq!(GamesCount::<Test>::get(NEW_PUBLISHER), 1);
+            assert!(GameStatuses::<Test>::get(NEW_PUBLISHER, 1).is_active());
+            assert_eq!(games_with_tag(1), vec![(NEW_PUBLISHER, 1)]);
+            assert_eq!(games_with_tag(NEW_TAG), vec![(NEW_PUBLISHER, 1)]);
+
+            assert!(Games::owns_game(FUNDED_BUYER, NEW_PUBLISHER, 1));
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "The publisher of a genesis game is invalid")]
+fn test_genesis_game_invalid_publisher() {
+    let genesis = crate::GenesisConfig::<Test> {
+        games: vec![(NEW_PUBLISHER, 1, genesis_game(&[1]))],
+        ..Default::default()
+    };
+    new_test_ext_with_genesis(vec![], genesis);
+}
+
+#[test]
+#[should_panic(expected = "The parent of a genesis tag is invalid")]
+fn test_genesis_tag_invalid_parent() {
+    let genesis = crate::GenesisConfig::<Test> {
+        tags: vec![(NEW_TAG, bounded_vec(b"Genesis Genre"), TagCategory::Genre, Some(NEW_TAG + 1))],
+        ..Default::default()
+    };
+    new_test_ext_with_genesis(vec![], genesis);
+}
+
+#[test]
+fn test_migrate_v6_to_v7() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(6).put::<Games>();
+        // The chains started with the duplicated tags have both of them, and the name of the
+        // duplicate points to the last one
+        let name: Tag = bounded_vec(b"Stealth");
+        for tag_id in [251, 322] {
+            Tags::<Test>::insert(tag_id, TagDetails::new(name.clone(), TagCategory::Other, None));
+        }
+        TagNames::<Test>::insert(&name, 322);
+
+        migrations::v7::MigrateV6ToV7::<Test>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<Games>(), 7);
+        assert_eq!(TagNames::<Test>::get(&name), Some(68));
+        assert!(Tags::<Test>::get(68).unwrap().is_active());
+        for tag_id in [251, 322] {
+            assert_eq!(
+                Tags::<Test>::get(tag_id).map(|tag| tag.status),
+                Some(TagStatus::Deprecated)
+            );
+        }
+    })
+}
+
+#[test]
+fn test_migrate_v7_to_v8() {
+    new_test_ext().execute_with(|| {
+        let game_id = 1;
+        let details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: Default::default(),
+            distribution: Distribution::Individual { price: 12345 },
+        };
+        PublishedGames::<Test>::insert(PUBLISHER, game_id, details);
+        publish_release(game_id, SemVer::new(1, 0, 0));
+        publish_release(game_id, SemVer::new(2, 0, 0));
+        assert_ok!(Games::release_yank(
+            RuntimeOrigin::signed(PUBLISHER),
+            game_id,
+            SemVer::new(2, 0, 0)
+        ));
+        // The highest release was not recorded before v8
+        let _ = HighestRelease::<Test>::clear(u32::MAX, None);
+        StorageVersion::new(7).put::<Games>();
+
+        migrations::v8::MigrateV7ToV8::<Test>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<Games>(), 8);
+        assert_eq!(HighestRelease::<Test>::get(PUBLISHER, game_id), Some(SemVer::new(2, 0, 0)));
+        assert_noop!(
+            Games::release_publish(
+                RuntimeOrigin::signed(PUBLISHER),
+                game_id,
+                SemVer::new(1, 5, 0),
+                release_cid(),
+                changelog_cid()
+            ),
+            Error::<Test>::ReleaseVersionTooLow
         );
     })
 }
@@ -15,6 +15,23 @@ pub trait WeightInfo {
     fn order_place() -> Weight;
     fn order_cancel() -> Weight;
     fn order_fulfill() -> Weight;
+    fn game_update() -> Weight;
+    fn game_status_set() -> Weight;
+    fn game_retire(n: u32) -> Weight;
+    fn release_publish() -> Weight;
+    fn release_yank() -> Weight;
+    fn order_expire() -> Weight;
+    fn order_reject() -> Weight;
+    fn refund_policy_set() -> Weight;
+    fn purchase_refund() -> Weight;
+    fn purchase_settle() -> Weight;
+    fn catalog_transfer_step() -> Weight;
+    fn tag_add() -> Weight;
+    fn tag_rename() -> Weight;
+    fn tag_deprecate() -> Weight;
+    fn tag_merge() -> Weight;
+    fn tag_merge_step() -> Weight;
+    fn tag_classify() -> Weight;
 
     fn game_buy() -> Weight {
         Self::buy_free().max(Self::buy_instant()).max(Self::order_place())
@@ -46,4 +63,72 @@ impl WeightInfo for () {
     fn order_fulfill() -> Weight {
         Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
     }
+
+    fn game_update() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn game_status_set() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn game_retire(_n: u32) -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn release_publish() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn release_yank() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn order_expire() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn order_reject() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn refund_policy_set() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn purchase_refund() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn purchase_settle() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn catalog_transfer_step() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_add() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_rename() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_deprecate() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_merge() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_merge_step() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn tag_classify() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
 }
@@ -0,0 +1,44 @@
+//! Runtime API to query the publishers of the liganite network.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use liganite_primitives::types::{
+    PublisherDetails, PublisherStatus, VerificationDetails, VerificationEvidence,
+};
+use scale_codec::{Codec, Decode, Encode};
+use scale_info::{prelude::vec::Vec, TypeInfo};
+
+/// The state of a publisher, as shown to the buyers.
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, TypeInfo)]
+pub struct PublisherInfo<AccountId, Balance, BlockNumber> {
+    /// The current account of the publisher
+    pub publisher: AccountId,
+    /// The details of the publisher
+    pub details: PublisherDetails,
+    /// Whether the publisher is active or suspended
+    pub status: PublisherStatus,
+    /// The deposit held from the publisher
+    pub deposit: Balance,
+    /// The verification of the publisher, if they are verified
+    pub verification: Option<VerificationDetails<BlockNumber>>,
+    /// The previous accounts of the publisher, from the oldest to the most recent
+    pub previous: Vec<AccountId>,
+}
+
+sp_api::decl_runtime_apis! {
+    pub trait PublishApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Returns the publisher, following their ownership transfers when given a previous
+        /// account.
+        fn publisher(
+            publisher: AccountId,
+        ) -> Option<PublisherInfo<AccountId, Balance, BlockNumber>>;
+
+        /// Returns the verification of the publisher, if they are verified.
+        fn verification(publisher: AccountId) -> Option<VerificationDetails<BlockNumber>>;
+    }
+}
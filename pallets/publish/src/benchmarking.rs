@@ -7,7 +7,10 @@ use crate::Pallet as Publish;
 use frame_benchmarking::v2::*;
 use frame_support::sp_runtime::traits::Bounded;
 use frame_system::RawOrigin;
-use liganite_primitives::{testing::bounded_vec, MAX_NAME_SIZE, MAX_URL_SIZE};
+use liganite_primitives::{
+    testing::bounded_vec, types::Email, MAX_EMAIL_SIZE, MAX_NAME_SIZE, MAX_REASON_SIZE,
+    MAX_URL_SIZE,
+};
 use scale_info::prelude::vec;
 
 fn prefund_account<T: Config>(account: &T::AccountId) {
@@ -17,28 +20,45 @@ fn prefund_account<T: Config>(account: &T::AccountId) {
     T::Currency::set_balance(account, CurrencyOf::<T>::from(initial_balance));
 }
 
+/// Returns a valid email of the given length, which must be at least 13.
+fn email(len: u32) -> Email {
+    let mut email = vec![b'c'; (len - 12) as usize];
+    email.extend_from_slice(b"@example.com");
+    bounded_vec(&email)
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
 
     #[benchmark]
     fn deposit_set() {
-        let original_deposit = PublisherDeposit::<T>::get();
-        let new_deposit = CurrencyOf::<T>::from(1_234u32);
+        // Every increase but one is in its grace period
+        let increases = T::MaxDepositIncreases::get();
+        for i in 1..increases {
+            let deposit = PublisherDeposit::<T>::get().saturating_add(CurrencyOf::<T>::from(i));
+            assert!(Pallet::<T>::deposit_set(RawOrigin::Root.into(), deposit).is_ok());
+        }
+        let new_deposit = PublisherDeposit::<T>::get().saturating_add(CurrencyOf::<T>::from(1u32));
 
         #[extrinsic_call]
         _(RawOrigin::Root, new_deposit);
 
-        assert_ne!(original_deposit, new_deposit);
         assert_eq!(PublisherDeposit::<T>::get(), new_deposit);
+        assert_eq!(DepositDeadlines::<T>::get().len() as u32, increases);
     }
 
     #[benchmark]
-    fn publisher_register(a: Linear<1, MAX_NAME_SIZE>, b: Linear<8, MAX_URL_SIZE>) {
+    fn publisher_register(
+        a: Linear<1, MAX_NAME_SIZE>,
+        b: Linear<8, MAX_URL_SIZE>,
+        c: Linear<13, MAX_EMAIL_SIZE>,
+    ) {
         let name = bounded_vec(&vec![b'a'; a as usize]);
         let mut url = bounded_vec(b"http://".as_slice());
         assert!(url.try_extend(vec![b'b'; (b - 7) as usize].into_iter()).is_ok());
-        let details = PublisherDetails { name, url };
+        let details = PublisherDetails { name, url, email: Some(email(c)), ..Default::default() };
+        assert_eq!(details.optional_fields_len(), c);
         let caller: T::AccountId = whitelisted_caller();
         prefund_account::<T>(&caller);
 
@@ -48,5 +68,302 @@ mod benchmarks {
         assert_eq!(Publishers::<T>::get(caller), Some(details));
     }
 
+    #[benchmark]
+    fn publisher_deregister(n: Linear<0, 500>) {
+        let caller: T::AccountId = whitelisted_caller();
+        prefund_account::<T>(&caller);
+        let details = PublisherDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            url: bounded_vec(b"https://publisher.com"),
+            ..Default::default()
+        };
+        T::Currency::hold(
+            &HoldReason::PublisherDeposit.into(),
+            &caller,
+            PublisherDeposit::<T>::get(),
+        )
+        .expect("the publisher is funded; qed");
+        Publishers::<T>::insert(&caller, details);
+        T::Catalog::add_active_games(&caller, n);
+        for i in 0..T::MaxMembers::get() {
+            let member: T::AccountId = account("member", i, 0);
+            Members::<T>::insert(&caller, &member, PublisherRole::CatalogManager);
+            MemberOf::<T>::insert(&member, &caller);
+        }
+        MembersCount::<T>::insert(&caller, T::MaxMembers::get());
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), true, n);
+
+        assert_eq!(Publishers::<T>::get(&caller), None);
+        assert_eq!(MembersCount::<T>::get(caller), 0);
+    }
+
+    #[benchmark]
+    fn deposit_top_up() {
+        let caller: T::AccountId = whitelisted_caller();
+        prefund_account::<T>(&caller);
+        let details = PublisherDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            url: bounded_vec(b"https://publisher.com"),
+            ..Default::default()
+        };
+        Publishers::<T>::insert(&caller, details);
+        let deposit = CurrencyOf::<T>::from(1_000_000u32);
+        PublisherDeposit::<T>::put(deposit);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert_eq!(PublisherDeposits::<T>::get(caller), deposit);
+    }
+
+    #[benchmark]
+    fn deposit_reclaim_excess() {
+        let caller: T::AccountId = whitelisted_caller();
+        prefund_account::<T>(&caller);
+        let details = PublisherDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            url: bounded_vec(b"https://publisher.com"),
+            ..Default::default()
+        };
+        Publishers::<T>::insert(&caller, details);
+        let deposit = CurrencyOf::<T>::from(1_000_000u32);
+        T::Currency::hold(&HoldReason::PublisherDeposit.into(), &caller, deposit)
+            .expect("the publisher is funded; qed");
+        PublisherDeposits::<T>::insert(&caller, deposit);
+        PublisherDeposit::<T>::put(CurrencyOf::<T>::from(1_000u32));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert_eq!(PublisherDeposits::<T>::get(caller), CurrencyOf::<T>::from(1_000u32));
+    }
+
+    #[benchmark]
+    fn publisher_update(
+        a: Linear<1, MAX_NAME_SIZE>,
+        b: Linear<8, MAX_URL_SIZE>,
+        c: Linear<13, MAX_EMAIL_SIZE>,
+    ) {
+        let caller: T::AccountId = whitelisted_caller();
+        let details = PublisherDetails {
+            name: bounded_vec(b"publisher"),
+            url: bounded_vec(b"https://publisher.com"),
+            ..Default::default()
+        };
+        Publishers::<T>::insert(&caller, details);
+
+        let name = bounded_vec(&vec![b'a'; a as usize]);
+        let mut url = bounded_vec(b"http://".as_slice());
+        assert!(url.try_extend(vec![b'b'; (b - 7) as usize].into_iter()).is_ok());
+        let details = PublisherDetails { name, url, email: Some(email(c)), ..Default::default() };
+        assert_eq!(details.optional_fields_len(), c);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), details.clone());
+
+        assert_eq!(Publishers::<T>::get(caller), Some(details));
+    }
+
+    #[benchmark]
+    fn publisher_slash() -> Result<(), BenchmarkError> {
+        let origin =
+            T::SlashOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let publisher: T::AccountId = account("publisher", 0, 0);
+        prefund_account::<T>(&publisher);
+        if let Some(treasury) = T::Treasury::get() {
+            prefund_account::<T>(&treasury);
+        }
+        let details = PublisherDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            url: bounded_vec(b"https://publisher.com"),
+            ..Default::default()
+        };
+        Publishers::<T>::insert(&publisher, details);
+        let deposit = CurrencyOf::<T>::from(1_000_000u32);
+        T::Currency::hold(&HoldReason::PublisherDeposit.into(), &publisher, deposit)
+            .expect("the publisher is funded; qed");
+        PublisherDeposits::<T>::insert(&publisher, deposit);
+        let reason = bounded_vec(&vec![b'r'; MAX_REASON_SIZE as usize]);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, publisher.clone(), deposit, reason, true);
+
+        assert_eq!(PublisherDeposits::<T>::get(&publisher), CurrencyOf::<T>::zero());
+        assert_eq!(PublisherStatuses::<T>::get(publisher), PublisherStatus::Suspended);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn publisher_suspend() -> Result<(), BenchmarkError> {
+        let origin =
+            T::SuspendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let publisher: T::AccountId = account("publisher", 0, 0);
+        let details = PublisherDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            url: bounded_vec(b"https://publisher.com"),
+            ..Default::default()
+        };
+        Publishers::<T>::insert(&publisher, details);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, publisher.clone());
+
+        assert_eq!(PublisherStatuses::<T>::get(publisher), PublisherStatus::Suspended);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn publisher_reinstate() -> Result<(), BenchmarkError> {
+        let origin =
+            T::SuspendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let publisher: T::AccountId = account("publisher", 0, 0);
+        PublisherStatuses::<T>::insert(&publisher, PublisherStatus::Suspended);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, publisher.clone());
+
+        assert_eq!(PublisherStatuses::<T>::get(publisher), PublisherStatus::Active);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn member_grant() {
+        let caller: T::AccountId = whitelisted_caller();
+        let details = PublisherDetails {
+            name: bounded_vec(b"publisher"),
+            url: bounded_vec(b"https://publisher.com"),
+            ..Default::default()
+        };
+        Publishers::<T>::insert(&caller, details);
+        let member: T::AccountId = account("member", 0, 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), member.clone(), PublisherRole::Admin);
+
+        assert_eq!(Members::<T>::get(&caller, &member), Some(PublisherRole::Admin));
+        assert_eq!(MemberOf::<T>::get(member), Some(caller));
+    }
+
+    #[benchmark]
+    fn member_revoke() {
+        let caller: T::AccountId = whitelisted_caller();
+        let member: T::AccountId = account("member", 0, 0);
+        Members::<T>::insert(&caller, &member, PublisherRole::Admin);
+        MemberOf::<T>::insert(&member, &caller);
+        MembersCount::<T>::insert(&caller, 1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), member.clone());
+
+        assert_eq!(Members::<T>::get(&caller, &member), None);
+        assert_eq!(MemberOf::<T>::get(member), None);
+    }
+
+    #[benchmark]
+    fn publisher_transfer_initiate() {
+        let caller: T::AccountId = whitelisted_caller();
+        let new_owner: T::AccountId = account("new_owner", 0, 0);
+        let details = PublisherDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            url: bounded_vec(b"https://publisher.com"),
+            ..Default::default()
+        };
+        Publishers::<T>::insert(&caller, details);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), new_owner.clone());
+
+        assert_eq!(PendingTransfers::<T>::get(caller), Some(new_owner));
+    }
+
+    #[benchmark]
+    fn publisher_transfer_accept() {
+        let publisher: T::AccountId = account("publisher", 0, 0);
+        prefund_account::<T>(&publisher);
+        let caller: T::AccountId = whitelisted_caller();
+        let details = PublisherDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            url: bounded_vec(b"https://publisher.com"),
+            ..Default::default()
+        };
+        let deposit = PublisherDeposit::<T>::get();
+        T::Currency::hold(&HoldReason::PublisherDeposit.into(), &publisher, deposit)
+            .expect("the publisher is funded; qed");
+        PublisherDeposits::<T>::insert(&publisher, deposit);
+        Publishers::<T>::insert(&publisher, details.clone());
+        for i in 0..T::MaxMembers::get() {
+            let member: T::AccountId = account("member", i, 0);
+            Members::<T>::insert(&publisher, &member, PublisherRole::CatalogManager);
+            MemberOf::<T>::insert(&member, &publisher);
+        }
+        MembersCount::<T>::insert(&publisher, T::MaxMembers::get());
+        let lineage = (0..T::MaxTransfers::get().saturating_sub(1))
+            .map(|i| account("previous", i, 0))
+            .collect::<Vec<T::AccountId>>();
+        for previous in lineage.iter() {
+            Successors::<T>::insert(previous, &publisher);
+        }
+        PublisherLineage::<T>::insert(
+            &publisher,
+            BoundedVec::try_from(lineage).expect("the lineage is within bounds; qed"),
+        );
+        PendingTransfers::<T>::insert(&publisher, &caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), publisher.clone());
+
+        assert_eq!(Publishers::<T>::get(&caller), Some(details));
+        assert_eq!(Successors::<T>::get(publisher), Some(caller));
+    }
+
+    #[benchmark]
+    fn publisher_verify() -> Result<(), BenchmarkError> {
+        let origin =
+            T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let publisher: T::AccountId = account("publisher", 0, 0);
+        let details = PublisherDetails {
+            name: bounded_vec(&vec![b'a'; MAX_NAME_SIZE as usize]),
+            url: bounded_vec(b"https://publisher.com"),
+            ..Default::default()
+        };
+        Publishers::<T>::insert(&publisher, details);
+        let mut url = bounded_vec(b"https://publisher.com/".as_slice());
+        assert!(url
+            .try_extend(vec![b'a'; MAX_URL_SIZE as usize - url.len()].into_iter())
+            .is_ok());
+        let evidence = VerificationEvidence::Domain {
+            url,
+            proof: bounded_vec(b"bafkrgqe3nmefcemf5kx23jw4mpkux4kswm4umtvwmc2j6cf3ftvkv7flwpfrwe7nvlhyzvvn7wtfhec5nkkwxv3hvzjivkw6yv3f3og5edhcldy"),
+        };
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, publisher.clone(), evidence.clone());
+
+        assert_eq!(Verifications::<T>::get(publisher).map(|v| v.evidence), Some(evidence));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn publisher_unverify() -> Result<(), BenchmarkError> {
+        let origin =
+            T::VerifierOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let publisher: T::AccountId = account("publisher", 0, 0);
+        let verification = VerificationDetails {
+            evidence: VerificationEvidence::Document(bounded_vec(
+                b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX",
+            )),
+            verified_at: frame_system::Pallet::<T>::block_number(),
+        };
+        Verifications::<T>::insert(&publisher, verification);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, publisher.clone());
+
+        assert!(!Verifications::<T>::contains_key(publisher));
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(Publish, mock::new_test_ext(), mock::Test);
 }
@@ -3,13 +3,28 @@
 
 use frame_support::{
     pallet_prelude::*,
-    traits::fungible::{hold::Mutate as FunHoldMutate, Inspect as FunInspect, Mutate as FunMutate},
+    sp_runtime::traits::{Saturating, Zero},
+    traits::{
+        fungible::{
+            hold::{Inspect as FunHoldInspect, Mutate as FunHoldMutate},
+            Inspect as FunInspect, Mutate as FunMutate,
+        },
+        tokens::{
+            Fortitude::Force,
+            Precision::{BestEffort, Exact},
+            Restriction::{Free, OnHold},
+        },
+    },
 };
 use frame_system::pallet_prelude::*;
 use liganite_primitives::{
-    publisher::PublisherManager,
-    types::{AccountIdOf, PublisherDetails, PublisherId},
+    publisher::{PublisherCatalog, PublisherManager},
+    types::{
+        AccountIdOf, PublisherDetails, PublisherId, PublisherRole, PublisherStatus, ReasonMessage,
+        VerificationDetails, VerificationEvidence,
+    },
 };
+use scale_info::prelude::vec::Vec;
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
@@ -22,16 +37,22 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
 type CurrencyOf<T> = <<T as Config>::Currency as FunInspect<AccountIdOf<T>>>::Balance;
+type VerificationDetailsOf<T> = VerificationDetails<BlockNumberFor<T>>;
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::genesis_config]
@@ -39,13 +60,32 @@ pub mod pallet {
     pub struct GenesisConfig<T: Config> {
         /// The initial publisher deposit.
         pub publisher_deposit: CurrencyOf<T>,
+        /// The initial publishers, whose deposit is held from their balance.
+        pub publishers: Vec<(PublisherId<T>, PublisherDetails)>,
     }
 
-    /// Build genesis storage. Publisher deposit is populated here.
+    /// Build genesis storage. Publisher deposit and the initial publishers are populated here.
     #[pallet::genesis_build]
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
             PublisherDeposit::<T>::put(self.publisher_deposit);
+            EnforcedDeposit::<T>::put(self.publisher_deposit);
+
+            for (publisher, details) in &self.publishers {
+                assert!(
+                    !Publishers::<T>::contains_key(publisher),
+                    "A genesis publisher is duplicated"
+                );
+                assert!(details.is_valid(), "The details of a genesis publisher are invalid");
+                T::Currency::hold(
+                    &HoldReason::PublisherDeposit.into(),
+                    publisher,
+                    self.publisher_deposit,
+                )
+                .expect("A genesis publisher cannot cover the publisher deposit");
+                PublisherDeposits::<T>::insert(publisher, self.publisher_deposit);
+                Publishers::<T>::insert(publisher, details);
+            }
         }
     }
 
@@ -71,16 +111,122 @@ pub mod pallet {
         /// Used to operate on currencies.
         type Currency: FunMutate<Self::AccountId>
             + FunHoldMutate<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+        /// Used to wind down the catalog of a publisher when they leave.
+        type Catalog: PublisherCatalog<PublisherId<Self>>;
+
+        /// The number of blocks the publishers have to top up their deposit after the required
+        /// deposit is increased, before they are no longer valid.
+        #[pallet::constant]
+        type DepositGracePeriod: Get<BlockNumberFor<Self>>;
+
+        /// The origin which may slash the deposit of a publisher.
+        type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The account which receives the slashed deposits. They are burned when it is `None`.
+        type Treasury: Get<Option<AccountIdOf<Self>>>;
+
+        /// The origin which may suspend and reinstate a publisher.
+        type SuspendOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The origin which may verify a publisher and revoke their verification.
+        type VerifierOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The maximum number of members of a publisher.
+        #[pallet::constant]
+        type MaxMembers: Get<u32>;
+
+        /// The maximum number of times the ownership of a publisher can be transferred.
+        #[pallet::constant]
+        type MaxTransfers: Get<u32>;
+
+        /// The maximum number of increases of the publisher deposit whose grace periods run at
+        /// the same time.
+        #[pallet::constant]
+        type MaxDepositIncreases: Get<u32>;
     }
 
     #[pallet::storage]
     pub type PublisherDeposit<T> = StorageValue<_, CurrencyOf<T>, ValueQuery>;
 
+    /// Storage for the deposit the publishers must hold regardless of the grace periods. It lags
+    /// behind `PublisherDeposit` while an increase of the deposit is in its grace period.
+    #[pallet::storage]
+    pub type EnforcedDeposit<T> = StorageValue<_, CurrencyOf<T>, ValueQuery>;
+
+    /// Storage for the increases of the publisher deposit which are still in their grace period,
+    /// each with the block from which it is enforced, from the oldest to the most recent.
+    #[pallet::storage]
+    pub type DepositDeadlines<T: Config> = StorageValue<
+        _,
+        BoundedVec<(CurrencyOf<T>, BlockNumberFor<T>), T::MaxDepositIncreases>,
+        ValueQuery,
+    >;
+
+    /// Storage for the deposits held from the publishers. Is a map of PublisherId -> Balance.
+    #[pallet::storage]
+    pub type PublisherDeposits<T> =
+        StorageMap<_, Twox64Concat, PublisherId<T>, CurrencyOf<T>, ValueQuery>;
+
     /// Storage for the publisher details. Is a map of PublisherId -> PublisherDetails.
     #[pallet::storage]
     pub type Publishers<T> =
         StorageMap<_, Twox64Concat, PublisherId<T>, PublisherDetails, OptionQuery>;
 
+    /// Storage for the publisher statuses. Is a map of PublisherId -> PublisherStatus.
+    #[pallet::storage]
+    pub type PublisherStatuses<T> =
+        StorageMap<_, Twox64Concat, PublisherId<T>, PublisherStatus, ValueQuery>;
+
+    /// Storage for the members of the publishers. Is a map of PublisherId, AccountId ->
+    /// PublisherRole.
+    #[pallet::storage]
+    pub type Members<T> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        PublisherId<T>,
+        Twox64Concat,
+        AccountIdOf<T>,
+        PublisherRole,
+        OptionQuery,
+    >;
+
+    /// Storage for the publisher of every member. Is a map of AccountId -> PublisherId.
+    #[pallet::storage]
+    pub type MemberOf<T> = StorageMap<_, Twox64Concat, AccountIdOf<T>, PublisherId<T>, OptionQuery>;
+
+    /// Storage for the number of members of the publishers. Is a map of PublisherId -> u32.
+    #[pallet::storage]
+    pub type MembersCount<T> = StorageMap<_, Twox64Concat, PublisherId<T>, u32, ValueQuery>;
+
+    /// Storage for the ownership transfers waiting to be accepted. Is a map of PublisherId ->
+    /// AccountId.
+    #[pallet::storage]
+    pub type PendingTransfers<T> =
+        StorageMap<_, Twox64Concat, PublisherId<T>, AccountIdOf<T>, OptionQuery>;
+
+    /// Storage for the previous accounts of the publishers, from the oldest to the most recent.
+    /// Is a map of PublisherId -> [PublisherId].
+    #[pallet::storage]
+    pub type PublisherLineage<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        PublisherId<T>,
+        BoundedVec<PublisherId<T>, T::MaxTransfers>,
+        ValueQuery,
+    >;
+
+    /// Storage for the current account of the publishers which have been transferred. Is a map of
+    /// PublisherId -> PublisherId.
+    #[pallet::storage]
+    pub type Successors<T> =
+        StorageMap<_, Twox64Concat, PublisherId<T>, PublisherId<T>, OptionQuery>;
+
+    /// Storage for the verified publishers. Is a map of PublisherId -> VerificationDetails.
+    #[pallet::storage]
+    pub type Verifications<T> =
+        StorageMap<_, Twox64Concat, PublisherId<T>, VerificationDetailsOf<T>, OptionQuery>;
+
     /// Events.
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -95,6 +241,93 @@ pub mod pallet {
             /// The account which was added.
             publisher: PublisherId<T>,
         },
+        /// A publisher has updated their details.
+        PublisherUpdated {
+            /// The account which was updated.
+            publisher: PublisherId<T>,
+        },
+        /// A publisher has been removed.
+        PublisherRemoved {
+            /// The account which was removed.
+            publisher: PublisherId<T>,
+        },
+        /// A publisher has topped up their deposit.
+        PublisherDepositToppedUp {
+            /// The publisher.
+            publisher: PublisherId<T>,
+            /// The amount which was added to the deposit.
+            amount: CurrencyOf<T>,
+        },
+        /// A publisher has reclaimed the excess of their deposit.
+        PublisherDepositReclaimed {
+            /// The publisher.
+            publisher: PublisherId<T>,
+            /// The amount which was released from the deposit.
+            amount: CurrencyOf<T>,
+        },
+        /// A publisher has been suspended.
+        PublisherSuspended {
+            /// The account which was suspended.
+            publisher: PublisherId<T>,
+        },
+        /// A publisher has been reinstated.
+        PublisherReinstated {
+            /// The account which was reinstated.
+            publisher: PublisherId<T>,
+        },
+        /// A publisher has granted a role to a member.
+        MemberRoleGranted {
+            /// The publisher.
+            publisher: PublisherId<T>,
+            /// The member.
+            member: AccountIdOf<T>,
+            /// The role of the member.
+            role: PublisherRole,
+        },
+        /// A publisher has revoked a member.
+        MemberRevoked {
+            /// The publisher.
+            publisher: PublisherId<T>,
+            /// The member.
+            member: AccountIdOf<T>,
+        },
+        /// A publisher has offered their ownership to another account.
+        PublisherTransferInitiated {
+            /// The publisher.
+            publisher: PublisherId<T>,
+            /// The account which may accept the ownership.
+            new_owner: AccountIdOf<T>,
+        },
+        /// The ownership of a publisher has been transferred to another account.
+        PublisherTransferred {
+            /// The previous account of the publisher.
+            publisher: PublisherId<T>,
+            /// The new account of the publisher.
+            new_owner: AccountIdOf<T>,
+        },
+        /// A publisher has been verified.
+        PublisherVerified {
+            /// The publisher.
+            publisher: PublisherId<T>,
+            /// The evidence the publisher has been verified with.
+            evidence: VerificationEvidence,
+        },
+        /// The verification of a publisher has been revoked.
+        PublisherVerificationRevoked {
+            /// The publisher.
+            publisher: PublisherId<T>,
+        },
+        /// The deposit of a publisher has been slashed.
+        PublisherSlashed {
+            /// The publisher.
+            publisher: PublisherId<T>,
+            /// The amount which was slashed from the deposit.
+            amount: CurrencyOf<T>,
+            /// The reason of the slash.
+            reason: ReasonMessage,
+            /// Whether the publisher has been suspended.
+            suspended: bool,
+        },
     }
 
     /// Errors.
@@ -104,6 +337,46 @@ pub mod pallet {
         PublisherAlreadyExists,
         /// The publisher details are invalid.
         PublisherDetailsInvalid,
+        /// The publisher is not found.
+        PublisherNotFound,
+        /// The publisher has more games than the given witness.
+        TooManyGames,
+        /// The publisher has pending orders.
+        PublisherHasPendingOrders,
+        /// The publisher has games which can be purchased.
+        PublisherHasActiveGames,
+        /// The publisher deposit already covers the required deposit.
+        DepositSufficient,
+        /// The publisher deposit does not exceed the required deposit.
+        NoExcessDeposit,
+        /// The publisher is suspended.
+        PublisherSuspended,
+        /// The publisher is not suspended.
+        PublisherNotSuspended,
+        /// The account is already a member of a publisher.
+        AccountIsMember,
+        /// The member is a publisher.
+        MemberIsPublisher,
+        /// The member is not found.
+        MemberNotFound,
+        /// The publisher has reached the maximum number of members.
+        TooManyMembers,
+        /// The member does not have the role required for the call.
+        NoPermission,
+        /// The account has been a publisher which was transferred.
+        PublisherTransferred,
+        /// The ownership transfer is not found.
+        TransferNotFound,
+        /// The publisher has reached the maximum number of ownership transfers.
+        TooManyTransfers,
+        /// The catalog of the publisher is being moved to or from another account.
+        CatalogTransferInProgress,
+        /// The verification evidence is invalid or does not apply to the publisher.
+        VerificationEvidenceInvalid,
+        /// The publisher is not verified.
+        PublisherNotVerified,
+        /// Too many increases of the publisher deposit are in their grace period.
+        TooManyDepositIncreases,
     }
 
     /// Dispatchable functions ([`Call`]s).
@@ -112,12 +385,29 @@ pub mod pallet {
         /// Sets the publisher deposit.
         ///
         /// This function allows the root account to set the publisher deposit. It updates the
-        /// `PublisherDeposit` storage value and emits an `PublisherDepositUpdated` event.
+        /// `PublisherDeposit` storage value and emits an `PublisherDepositUpdated` event. When the
+        /// deposit is increased, the registered publishers have `DepositGracePeriod` blocks to top
+        /// up their deposit before they are no longer valid. The grace periods of the earlier
+        /// increases keep running, and a decrease applies at once.
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::deposit_set())]
         pub fn deposit_set(origin: OriginFor<T>, deposit: CurrencyOf<T>) -> DispatchResult {
             ensure_root(origin)?;
 
+            let now = frame_system::Pallet::<T>::block_number();
+            let enforced = Self::required_deposit();
+            // The increases past their grace period are now enforced, and the increases above the
+            // new deposit are superseded by it
+            let mut increases = DepositDeadlines::<T>::get();
+            increases.retain(|(amount, deadline)| *deadline > now && *amount <= deposit);
+            if deposit > enforced && increases.last().is_none_or(|(amount, _)| *amount < deposit) {
+                let deadline = now.saturating_add(T::DepositGracePeriod::get());
+                increases
+                    .try_push((deposit, deadline))
+                    .map_err(|_| Error::<T>::TooManyDepositIncreases)?;
+            }
+            EnforcedDeposit::<T>::put(enforced.min(deposit));
+            DepositDeadlines::<T>::put(increases);
             PublisherDeposit::<T>::put(deposit);
 
             Self::deposit_event(Event::PublisherDepositUpdated { deposit });
@@ -127,26 +417,518 @@ pub mod pallet {
         /// Registers a new publisher to the system.
         ///
         /// This function adds a publisher by storing their details in the `Publishers` storage. It
-        /// checks that the publisher does not already exist in the system before adding
-        /// them. A `PublisherAdded` event is emitted once the publisher is successfully
-        /// added.
+        /// checks that the publisher does not already exist in the system, is not a member of
+        /// another publisher and has not transferred a publisher, before adding them. A
+        /// `PublisherAdded` event is emitted once the publisher is successfully added.
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::publisher_register(details.name.len() as u32, details.url.len() as u32))]
+        #[pallet::weight(T::WeightInfo::publisher_register(
+            details.name.len() as u32,
+            details.url.len() as u32,
+            details.optional_fields_len(),
+        ))]
         pub fn publisher_register(
             origin: OriginFor<T>,
             details: PublisherDetails,
         ) -> DispatchResult {
             let publisher = ensure_signed(origin)?;
             ensure!(!Publishers::<T>::contains_key(&publisher), Error::<T>::PublisherAlreadyExists);
+            ensure!(!MemberOf::<T>::contains_key(&publisher), Error::<T>::AccountIsMember);
+            ensure!(!Successors::<T>::contains_key(&publisher), Error::<T>::PublisherTransferred);
             ensure!(details.is_valid(), Error::<T>::PublisherDetailsInvalid);
 
             let deposit = PublisherDeposit::<T>::get();
             T::Currency::hold(&HoldReason::PublisherDeposit.into(), &publisher, deposit)?;
+            PublisherDeposits::<T>::insert(&publisher, deposit);
 
             Publishers::<T>::insert(&publisher, &details);
             Self::deposit_event(Event::PublisherAdded { publisher });
             Ok(())
         }
+
+        /// Deregisters a publisher from the system.
+        ///
+        /// This function removes the publisher from the `Publishers` storage and releases their
+        /// deposit, and revokes all of their members. It fails while the publisher is suspended or
+        /// has pending orders. The publisher's games which can be purchased are delisted if
+        /// `delist_games` is set, otherwise it fails while there are any. The rest of the catalog,
+        /// such as the releases and the refund policies, is intentionally kept, so the buyers can
+        /// still download the games they own. `games` is a witness of the number of games of the
+        /// publisher, which bounds the weight of the call. A `PublisherRemoved` event is emitted
+        /// once the publisher is successfully removed.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::publisher_deregister(*games))]
+        pub fn publisher_deregister(
+            origin: OriginFor<T>,
+            delist_games: bool,
+            games: u32,
+        ) -> DispatchResultWithPostInfo {
+            let publisher = ensure_signed(origin)?;
+            ensure!(Publishers::<T>::contains_key(&publisher), Error::<T>::PublisherNotFound);
+            ensure!(
+                PublisherStatuses::<T>::get(&publisher).is_active(),
+                Error::<T>::PublisherSuspended
+            );
+
+            ensure!(
+                !T::Catalog::is_transferring(&publisher),
+                Error::<T>::CatalogTransferInProgress
+            );
+
+            let count = T::Catalog::games_count(&publisher);
+            ensure!(count <= games, Error::<T>::TooManyGames);
+            ensure!(
+                !T::Catalog::has_pending_orders(&publisher),
+                Error::<T>::PublisherHasPendingOrders
+            );
+            if delist_games {
+                T::Catalog::delist_games(&publisher);
+            } else {
+                ensure!(
+                    !T::Catalog::has_active_games(&publisher),
+                    Error::<T>::PublisherHasActiveGames
+                );
+            }
+
+            T::Currency::release_all(&HoldReason::PublisherDeposit.into(), &publisher, BestEffort)?;
+
+            for member in Members::<T>::drain_prefix(&publisher).map(|(member, _)| member) {
+                MemberOf::<T>::remove(member);
+            }
+            MembersCount::<T>::remove(&publisher);
+            PendingTransfers::<T>::remove(&publisher);
+            Verifications::<T>::remove(&publisher);
+            PublisherDeposits::<T>::remove(&publisher);
+            Publishers::<T>::remove(&publisher);
+            Self::deposit_event(Event::PublisherRemoved { publisher });
+            Ok(Some(T::WeightInfo::publisher_deregister(count)).into())
+        }
+
+        /// Tops up the deposit of a publisher to the required deposit.
+        ///
+        /// This function holds the difference between the required deposit and the deposit held
+        /// from the publisher. It may be called by the members with the `Finance` role. A
+        /// `PublisherDepositToppedUp` event is emitted once the deposit is
+        /// successfully topped up.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::deposit_top_up())]
+        pub fn deposit_top_up(origin: OriginFor<T>) -> DispatchResult {
+            let publisher = Self::ensure_member(origin, PublisherRole::Finance)?;
+            ensure!(Publishers::<T>::contains_key(&publisher), Error::<T>::PublisherNotFound);
+
+            let deposited = PublisherDeposits::<T>::get(&publisher);
+            let amount = PublisherDeposit::<T>::get().saturating_sub(deposited);
+            ensure!(!amount.is_zero(), Error::<T>::DepositSufficient);
+
+            T::Currency::hold(&HoldReason::PublisherDeposit.into(), &publisher, amount)?;
+            PublisherDeposits::<T>::insert(&publisher, deposited.saturating_add(amount));
+
+            Self::deposit_event(Event::PublisherDepositToppedUp { publisher, amount });
+            Ok(())
+        }
+
+        /// Reclaims the excess of the deposit of a publisher.
+        ///
+        /// This function releases the part of the deposit held from the publisher which exceeds
+        /// the required deposit, e.g. after the required deposit was decreased. It fails while the
+        /// publisher is suspended, and may be called by the members with the `Finance` role. A
+        /// `PublisherDepositReclaimed` event is emitted once the excess is successfully
+        /// released.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::deposit_reclaim_excess())]
+        pub fn deposit_reclaim_excess(origin: OriginFor<T>) -> DispatchResult {
+            let publisher = Self::ensure_member(origin, PublisherRole::Finance)?;
+            ensure!(Publishers::<T>::contains_key(&publisher), Error::<T>::PublisherNotFound);
+            ensure!(
+                PublisherStatuses::<T>::get(&publisher).is_active(),
+                Error::<T>::PublisherSuspended
+            );
+
+            let deposited = PublisherDeposits::<T>::get(&publisher);
+            let excess = deposited.saturating_sub(PublisherDeposit::<T>::get());
+            ensure!(!excess.is_zero(), Error::<T>::NoExcessDeposit);
+
+            let amount = T::Currency::release(
+                &HoldReason::PublisherDeposit.into(),
+                &publisher,
+                excess,
+                BestEffort,
+            )?;
+            PublisherDeposits::<T>::insert(&publisher, deposited.saturating_sub(amount));
+
+            Self::deposit_event(Event::PublisherDepositReclaimed { publisher, amount });
+            Ok(())
+        }
+
+        /// Updates the details of a publisher.
+        ///
+        /// This function replaces the details of the publisher in the `Publishers` storage. It
+        /// checks that the publisher exists and that the new details are valid. It may be called
+        /// by the members with the `Admin` role. The verification of the publisher is revoked when
+        /// its evidence no longer applies to the new details, e.g. when the URL moves to another
+        /// domain. A `PublisherUpdated` event is emitted once the details are successfully updated.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::publisher_update(
+            details.name.len() as u32,
+            details.url.len() as u32,
+            details.optional_fields_len(),
+        ))]
+        pub fn publisher_update(origin: OriginFor<T>, details: PublisherDetails) -> DispatchResult {
+            let publisher = Self::ensure_member(origin, PublisherRole::Admin)?;
+            ensure!(Publishers::<T>::contains_key(&publisher), Error::<T>::PublisherNotFound);
+            ensure!(details.is_valid(), Error::<T>::PublisherDetailsInvalid);
+
+            if Verifications::<T>::get(&publisher)
+                .is_some_and(|verification| !verification.evidence.applies_to(&details))
+            {
+                Verifications::<T>::remove(&publisher);
+                Self::deposit_event(Event::PublisherVerificationRevoked {
+                    publisher: publisher.clone(),
+                });
+            }
+
+            Publishers::<T>::insert(&publisher, &details);
+            Self::deposit_event(Event::PublisherUpdated { publisher });
+            Ok(())
+        }
+
+        /// Slashes the deposit of a publisher.
+        ///
+        /// This function allows the `SlashOrigin` to take up to `amount` from the deposit held from
+        /// the publisher, which is moved to the `Treasury` account or burned when there is none.
+        /// The publisher is suspended if `suspend` is set, so that they are no longer valid. A
+        /// `PublisherSlashed` event is emitted once the deposit is successfully slashed.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::publisher_slash())]
+        pub fn publisher_slash(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            amount: CurrencyOf<T>,
+            reason: ReasonMessage,
+            suspend: bool,
+        ) -> DispatchResult {
+            T::SlashOrigin::ensure_origin(origin)?;
+            ensure!(Publishers::<T>::contains_key(&publisher), Error::<T>::PublisherNotFound);
+
+            let hold_reason = HoldReason::PublisherDeposit.into();
+            let amount = match T::Treasury::get() {
+                Some(treasury) => T::Currency::transfer_on_hold(
+                    &hold_reason,
+                    &publisher,
+                    &treasury,
+                    amount,
+                    BestEffort,
+                    Free,
+                    Force,
+                )?,
+                None => {
+                    T::Currency::burn_held(&hold_reason, &publisher, amount, BestEffort, Force)?
+                },
+            };
+            PublisherDeposits::<T>::mutate(&publisher, |deposit| {
+                *deposit = deposit.saturating_sub(amount)
+            });
+            if suspend {
+                PublisherStatuses::<T>::insert(&publisher, PublisherStatus::Suspended);
+            }
+
+            Self::deposit_event(Event::PublisherSlashed {
+                publisher,
+                amount,
+                reason,
+                suspended: suspend,
+            });
+            Ok(())
+        }
+
+        /// Suspends a publisher.
+        ///
+        /// This function allows the `SuspendOrigin` to freeze a publisher without taking their
+        /// deposit, e.g. during a dispute. The suspended publisher is no longer valid, so they can
+        /// neither add nor sell games, and they cannot leave until they are reinstated. A
+        /// `PublisherSuspended` event is emitted once the publisher is successfully suspended.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::publisher_suspend())]
+        pub fn publisher_suspend(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+        ) -> DispatchResult {
+            T::SuspendOrigin::ensure_origin(origin)?;
+            ensure!(Publishers::<T>::contains_key(&publisher), Error::<T>::PublisherNotFound);
+            ensure!(
+                PublisherStatuses::<T>::get(&publisher).is_active(),
+                Error::<T>::PublisherSuspended
+            );
+
+            PublisherStatuses::<T>::insert(&publisher, PublisherStatus::Suspended);
+            Self::deposit_event(Event::PublisherSuspended { publisher });
+            Ok(())
+        }
+
+        /// Reinstates a suspended publisher.
+        ///
+        /// This function allows the `SuspendOrigin` to lift the suspension of a publisher. A
+        /// `PublisherReinstated` event is emitted once the publisher is successfully reinstated.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::publisher_reinstate())]
+        pub fn publisher_reinstate(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+        ) -> DispatchResult {
+            T::SuspendOrigin::ensure_origin(origin)?;
+            ensure!(
+                !PublisherStatuses::<T>::get(&publisher).is_active(),
+                Error::<T>::PublisherNotSuspended
+            );
+
+            PublisherStatuses::<T>::remove(&publisher);
+            Self::deposit_event(Event::PublisherReinstated { publisher });
+            Ok(())
+        }
+
+        /// Grants a role to a member of a publisher.
+        ///
+        /// This function allows a publisher to delegate some of their calls to another account,
+        /// which then acts on their behalf with the granted role. An account can be a member of
+        /// a single publisher, and the role of an existing member is replaced. A
+        /// `MemberRoleGranted` event is emitted once the role is successfully granted.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::member_grant())]
+        pub fn member_grant(
+            origin: OriginFor<T>,
+            member: AccountIdOf<T>,
+            role: PublisherRole,
+        ) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            ensure!(Publishers::<T>::contains_key(&publisher), Error::<T>::PublisherNotFound);
+            ensure!(!Publishers::<T>::contains_key(&member), Error::<T>::MemberIsPublisher);
+
+            match MemberOf::<T>::get(&member) {
+                Some(other) => ensure!(other == publisher, Error::<T>::AccountIsMember),
+                None => {
+                    let count = MembersCount::<T>::get(&publisher);
+                    ensure!(count < T::MaxMembers::get(), Error::<T>::TooManyMembers);
+                    MembersCount::<T>::insert(&publisher, count.saturating_add(1));
+                    MemberOf::<T>::insert(&member, &publisher);
+                },
+            }
+            Members::<T>::insert(&publisher, &member, role);
+
+            Self::deposit_event(Event::MemberRoleGranted { publisher, member, role });
+            Ok(())
+        }
+
+        /// Revokes a member of a publisher.
+        ///
+        /// This function removes the member from the publisher, so that they can no longer act on
+        /// their behalf. A `MemberRevoked` event is emitted once the member is successfully
+        /// revoked.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::member_revoke())]
+        pub fn member_revoke(origin: OriginFor<T>, member: AccountIdOf<T>) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            Members::<T>::take(&publisher, &member).ok_or(Error::<T>::MemberNotFound)?;
+
+            MemberOf::<T>::remove(&member);
+            MembersCount::<T>::mutate(&publisher, |count| *count = count.saturating_sub(1));
+
+            Self::deposit_event(Event::MemberRevoked { publisher, member });
+            Ok(())
+        }
+
+        /// Offers the ownership of a publisher to another account.
+        ///
+        /// This function is the first step of an ownership transfer, e.g. when a studio is acquired
+        /// or rotates its keys. The new owner must not be a publisher nor a member, and becomes the
+        /// owner once they accept the transfer. Initiating another transfer replaces the pending
+        /// one. A `PublisherTransferInitiated` event is emitted once the transfer is initiated.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::publisher_transfer_initiate())]
+        pub fn publisher_transfer_initiate(
+            origin: OriginFor<T>,
+            new_owner: AccountIdOf<T>,
+        ) -> DispatchResult {
+            let publisher = ensure_signed(origin)?;
+            ensure!(Publishers::<T>::contains_key(&publisher), Error::<T>::PublisherNotFound);
+            ensure!(
+                PublisherStatuses::<T>::get(&publisher).is_active(),
+                Error::<T>::PublisherSuspended
+            );
+            ensure!(
+                !T::Catalog::is_transferring(&publisher),
+                Error::<T>::CatalogTransferInProgress
+            );
+            Self::ensure_new_owner(&new_owner)?;
+
+            PendingTransfers::<T>::insert(&publisher, &new_owner);
+            Self::deposit_event(Event::PublisherTransferInitiated { publisher, new_owner });
+            Ok(())
+        }
+
+        /// Accepts the ownership of a publisher.
+        ///
+        /// This function is the second step of an ownership transfer, and is triggered by the new
+        /// owner. The details, the deposit, the members and the catalog of the publisher are moved
+        /// to the new owner, and the previous account is recorded in the lineage of the publisher
+        /// so that the games bought from it keep working. It fails while the publisher has pending
+        /// orders. The catalog may be moved over several blocks, during which it cannot be
+        /// changed. A `PublisherTransferred` event is emitted once the transfer is accepted.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::publisher_transfer_accept())]
+        pub fn publisher_transfer_accept(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+        ) -> DispatchResult {
+            let new_owner = ensure_signed(origin)?;
+            ensure!(
+                PendingTransfers::<T>::get(&publisher).as_ref() == Some(&new_owner),
+                Error::<T>::TransferNotFound
+            );
+            let details = Publishers::<T>::get(&publisher).ok_or(Error::<T>::PublisherNotFound)?;
+            ensure!(
+                PublisherStatuses::<T>::get(&publisher).is_active(),
+                Error::<T>::PublisherSuspended
+            );
+            ensure!(
+                !T::Catalog::has_pending_orders(&publisher),
+                Error::<T>::PublisherHasPendingOrders
+            );
+            ensure!(
+                !T::Catalog::is_transferring(&publisher),
+                Error::<T>::CatalogTransferInProgress
+            );
+            Self::ensure_new_owner(&new_owner)?;
+
+            let mut lineage = PublisherLineage::<T>::take(&publisher);
+            lineage.try_push(publisher.clone()).map_err(|_| Error::<T>::TooManyTransfers)?;
+
+            let deposit = PublisherDeposits::<T>::take(&publisher);
+            if !deposit.is_zero() {
+                T::Currency::transfer_on_hold(
+                    &HoldReason::PublisherDeposit.into(),
+                    &publisher,
+                    &new_owner,
+                    deposit,
+                    Exact,
+                    OnHold,
+                    Force,
+                )?;
+            }
+            PublisherDeposits::<T>::insert(&new_owner, deposit);
+
+            for (member, role) in Members::<T>::drain_prefix(&publisher) {
+                Members::<T>::insert(&new_owner, &member, role);
+                MemberOf::<T>::insert(&member, &new_owner);
+            }
+            MembersCount::<T>::insert(&new_owner, MembersCount::<T>::take(&publisher));
+
+            for previous in lineage.iter() {
+                Successors::<T>::insert(previous, &new_owner);
+            }
+            PublisherLineage::<T>::insert(&new_owner, lineage);
+
+            if let Some(verification) = Verifications::<T>::take(&publisher) {
+                Verifications::<T>::insert(&new_owner, verification);
+            }
+
+            T::Catalog::transfer_catalog(&publisher, &new_owner)?;
+
+            PendingTransfers::<T>::remove(&publisher);
+            Publishers::<T>::remove(&publisher);
+            Publishers::<T>::insert(&new_owner, details);
+
+            Self::deposit_event(Event::PublisherTransferred { publisher, new_owner });
+            Ok(())
+        }
+
+        /// Verifies a publisher.
+        ///
+        /// This function allows the `VerifierOrigin` to attest that a publisher is who they claim
+        /// to be, so that buyers can tell them apart from impersonators. It checks that the
+        /// evidence is valid, and that a domain proof is for the host of the publisher's URL. The
+        /// existing verification of the publisher is replaced. A `PublisherVerified` event is
+        /// emitted once the publisher is successfully verified.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::publisher_verify())]
+        pub fn publisher_verify(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+            evidence: VerificationEvidence,
+        ) -> DispatchResult {
+            T::VerifierOrigin::ensure_origin(origin)?;
+            let details = Publishers::<T>::get(&publisher).ok_or(Error::<T>::PublisherNotFound)?;
+            ensure!(
+                evidence.is_valid() && evidence.applies_to(&details),
+                Error::<T>::VerificationEvidenceInvalid
+            );
+
+            let verified_at = frame_system::Pallet::<T>::block_number();
+            Verifications::<T>::insert(
+                &publisher,
+                VerificationDetails { evidence: evidence.clone(), verified_at },
+            );
+            Self::deposit_event(Event::PublisherVerified { publisher, evidence });
+            Ok(())
+        }
+
+        /// Revokes the verification of a publisher.
+        ///
+        /// This function allows the `VerifierOrigin` to withdraw the verification of a publisher,
+        /// e.g. when the evidence turns out to be forged. A `PublisherVerificationRevoked` event is
+        /// emitted once the verification is successfully revoked.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::publisher_unverify())]
+        pub fn publisher_unverify(
+            origin: OriginFor<T>,
+            publisher: PublisherId<T>,
+        ) -> DispatchResult {
+            T::VerifierOrigin::ensure_origin(origin)?;
+            ensure!(Verifications::<T>::contains_key(&publisher), Error::<T>::PublisherNotVerified);
+
+            Verifications::<T>::remove(&publisher);
+            Self::deposit_event(Event::PublisherVerificationRevoked { publisher });
+            Ok(())
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Returns the publisher on whose behalf the signer of the origin acts, checking that the
+    /// signer holds the given role.
+    fn ensure_member(
+        origin: OriginFor<T>,
+        role: PublisherRole,
+    ) -> Result<PublisherId<T>, DispatchError> {
+        let account = ensure_signed(origin)?;
+        let publisher = Self::publisher_of(&account);
+        ensure!(Self::has_permission(&publisher, &account, role), Error::<T>::NoPermission);
+        Ok(publisher)
+    }
+
+    /// Checks that an account can become the owner of a publisher.
+    fn ensure_new_owner(account: &AccountIdOf<T>) -> DispatchResult {
+        ensure!(!Publishers::<T>::contains_key(account), Error::<T>::PublisherAlreadyExists);
+        ensure!(!MemberOf::<T>::contains_key(account), Error::<T>::AccountIsMember);
+        ensure!(!Successors::<T>::contains_key(account), Error::<T>::PublisherTransferred);
+        Ok(())
+    }
+
+    /// Returns the deposit the publishers must hold at the current block: the enforced deposit,
+    /// raised by the increases whose grace period is over.
+    pub fn required_deposit() -> CurrencyOf<T> {
+        let now = frame_system::Pallet::<T>::block_number();
+        let enforced = EnforcedDeposit::<T>::get();
+        DepositDeadlines::<T>::get()
+            .into_iter()
+            .rev()
+            .find(|(_, deadline)| *deadline <= now)
+            .map_or(enforced, |(amount, _)| amount.max(enforced))
+    }
+
+    /// Returns whether the deposit held from a publisher covers the required deposit. An increase
+    /// of the deposit is only required once its grace period is over, but a publisher whose
+    /// deposit was slashed below the required deposit is no longer covered.
+    pub fn has_sufficient_deposit(publisher_id: &PublisherId<T>) -> bool {
+        PublisherDeposits::<T>::get(publisher_id) >= Self::required_deposit()
     }
 }
 
@@ -154,10 +936,39 @@ impl<T: Config> PublisherManager for Pallet<T> {
     type PublisherId = PublisherId<T>;
 
     fn is_valid_publisher(publisher_id: &PublisherId<T>) -> bool {
-        Publishers::<T>::contains_key(publisher_id)
+        Publishers::<T>::contains_key(publisher_id) &&
+            PublisherStatuses::<T>::get(publisher_id).is_active() &&
+            Self::has_sufficient_deposit(publisher_id)
+    }
+
+    fn is_suspended_publisher(publisher_id: &PublisherId<T>) -> bool {
+        !PublisherStatuses::<T>::get(publisher_id).is_active()
+    }
+
+    fn publisher_of(account: &PublisherId<T>) -> PublisherId<T> {
+        MemberOf::<T>::get(account).unwrap_or_else(|| account.clone())
+    }
+
+    fn has_permission(
+        publisher_id: &PublisherId<T>,
+        account: &PublisherId<T>,
+        role: PublisherRole,
+    ) -> bool {
+        account == publisher_id ||
+            Members::<T>::get(publisher_id, account).is_some_and(|member| member.allows(&role))
+    }
+
+    fn current_publisher(publisher_id: &PublisherId<T>) -> PublisherId<T> {
+        Successors::<T>::get(publisher_id).unwrap_or_else(|| publisher_id.clone())
+    }
+
+    fn previous_publishers(publisher_id: &PublisherId<T>) -> Vec<PublisherId<T>> {
+        PublisherLineage::<T>::get(publisher_id).into_inner()
     }
 
+    #[cfg(feature = "runtime-benchmarks")]
     fn insert_publisher(publisher_id: &PublisherId<T>, details: &PublisherDetails) {
+        PublisherDeposits::<T>::insert(publisher_id, PublisherDeposit::<T>::get());
         Publishers::<T>::insert(publisher_id, details);
     }
 }
@@ -0,0 +1,117 @@
+//! Storage migrations for the publish pallet.
+
+use super::*;
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+#[cfg(feature = "try-runtime")]
+use scale_info::prelude::vec::Vec;
+
+pub mod v1 {
+    use super::*;
+
+    /// Records the deposit held from every publisher, and enforces the current publisher deposit.
+    ///
+    /// The deposit was held at registration without being recorded, so it is read back from the
+    /// balance on hold.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut migrated = 0u64;
+            for publisher in Publishers::<T>::iter_keys() {
+                let deposit =
+                    T::Currency::balance_on_hold(&HoldReason::PublisherDeposit.into(), &publisher);
+                PublisherDeposits::<T>::insert(&publisher, deposit);
+                migrated += 1;
+            }
+            EnforcedDeposit::<T>::put(PublisherDeposit::<T>::get());
+
+            T::DbWeight::get().reads_writes(migrated * 2 + 1, migrated + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((Publishers::<T>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let publishers = u32::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("Failed to decode the publisher count"))?;
+            let recorded = PublisherDeposits::<T>::iter_keys().count() as u32;
+
+            ensure!(publishers == recorded, "Every publisher deposit must be recorded");
+            ensure!(
+                EnforcedDeposit::<T>::get() == PublisherDeposit::<T>::get(),
+                "The publisher deposit must be enforced"
+            );
+            Ok(())
+        }
+    }
+
+    /// Migrates the publish pallet from storage version 0 to 1.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v2 {
+    use super::*;
+
+    pub(crate) mod v1 {
+        use super::*;
+        use liganite_primitives::types::{Name, Url};
+
+        /// The publisher details before v2: no logo, description or contact email.
+        #[derive(Encode, Decode)]
+        pub struct PublisherDetails {
+            pub name: Name,
+            pub url: Url,
+        }
+    }
+
+    /// Extends the details of every publisher with the optional logo, description and contact
+    /// email, which are left empty.
+    pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut migrated = 0u64;
+            Publishers::<T>::translate::<v1::PublisherDetails, _>(|_, old| {
+                migrated += 1;
+                Some(PublisherDetails { name: old.name, url: old.url, ..Default::default() })
+            });
+
+            T::DbWeight::get().reads_writes(migrated, migrated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((Publishers::<T>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let publishers = u32::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("Failed to decode the publisher count"))?;
+            let migrated = Publishers::<T>::iter().count() as u32;
+
+            ensure!(publishers == migrated, "Every publisher must be migrated");
+            Ok(())
+        }
+    }
+
+    /// Migrates the publish pallet from storage version 1 to 2.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
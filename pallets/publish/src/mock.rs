@@ -1,10 +1,14 @@
 use crate as liganite_publish;
 use frame_support::{
-    derive_impl,
-    traits::{ConstU64, VariantCountOf},
+    derive_impl, parameter_types,
+    traits::{ConstU32, ConstU64, VariantCountOf},
 };
-use liganite_primitives::types::PublisherId;
-use sp_runtime::BuildStorage;
+use frame_system::EnsureRoot;
+use liganite_primitives::{
+    publisher::PublisherCatalog,
+    types::{PublisherDetails, PublisherId},
+};
+use sp_runtime::{BuildStorage, DispatchResult};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 type Balance = u64;
@@ -66,26 +70,106 @@ impl liganite_publish::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
+    type Catalog = MockCatalog;
+    type DepositGracePeriod = ConstU64<DEPOSIT_GRACE_PERIOD>;
+    type SlashOrigin = EnsureRoot<u64>;
+    type Treasury = Treasury;
+    type SuspendOrigin = EnsureRoot<u64>;
+    type MaxMembers = ConstU32<MAX_MEMBERS>;
+    type MaxTransfers = ConstU32<MAX_TRANSFERS>;
+    type MaxDepositIncreases = ConstU32<MAX_DEPOSIT_INCREASES>;
+    type VerifierOrigin = EnsureRoot<u64>;
+}
+
+parameter_types! {
+    pub static Treasury: Option<u64> = None;
+    pub static GamesCount: u32 = 0;
+    pub static HasPendingOrders: bool = false;
+    pub static HasActiveGames: bool = false;
+    pub static IsTransferring: bool = false;
+    pub static TransferredCatalogs: Vec<(u64, u64)> = vec![];
+}
+
+/// A catalog shared by every publisher, driven by the parameters above.
+pub struct MockCatalog;
+
+impl PublisherCatalog<PublisherId<Test>> for MockCatalog {
+    fn games_count(_publisher_id: &PublisherId<Test>) -> u32 {
+        GamesCount::get()
+    }
+
+    fn has_pending_orders(_publisher_id: &PublisherId<Test>) -> bool {
+        HasPendingOrders::get()
+    }
+
+    fn has_active_games(_publisher_id: &PublisherId<Test>) -> bool {
+        HasActiveGames::get()
+    }
+
+    fn delist_games(_publisher_id: &PublisherId<Test>) {
+        HasActiveGames::set(false);
+    }
+
+    fn transfer_catalog(
+        publisher_id: &PublisherId<Test>,
+        new_publisher_id: &PublisherId<Test>,
+    ) -> DispatchResult {
+        TransferredCatalogs::mutate(|transfers| transfers.push((*publisher_id, *new_publisher_id)));
+        Ok(())
+    }
+
+    fn is_transferring(_publisher_id: &PublisherId<Test>) -> bool {
+        IsTransferring::get()
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add_active_games(_publisher_id: &PublisherId<Test>, n: u32) {
+        GamesCount::set(n);
+        HasActiveGames::set(n > 0);
+    }
 }
 
 pub const PUBLISHER_DEPOSIT: Balance = 1_000_000;
 pub const INITIAL_BALANCE: Balance = 1_000_000_000;
+pub const DEPOSIT_GRACE_PERIOD: u64 = 10;
+pub const MAX_MEMBERS: u32 = 2;
+pub const MAX_TRANSFERS: u32 = 2;
+pub const MAX_DEPOSIT_INCREASES: u32 = 2;
 
 pub const NON_FUNDED_PUBLISHER: PublisherId<Test> = 0;
 pub const FUNDED_PUBLISHER: PublisherId<Test> = 1;
+pub const TREASURY: u64 = 2;
+pub const MEMBER: u64 = 3;
+pub const MEMBER_2: u64 = 4;
+pub const MEMBER_3: u64 = 5;
+pub const NEW_OWNER: u64 = 6;
+pub const NEW_OWNER_2: u64 = 7;
+pub const NEW_OWNER_3: u64 = 8;
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
+    new_test_ext_with_publishers(vec![])
+}
+
+// Build genesis storage according to the mock runtime, with the given genesis publishers.
+pub fn new_test_ext_with_publishers(
+    publishers: Vec<(PublisherId<Test>, PublisherDetails)>,
+) -> sp_io::TestExternalities {
     let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
     pallet_balances::GenesisConfig::<Test> {
-        balances: vec![(FUNDED_PUBLISHER, INITIAL_BALANCE)],
+        balances: vec![
+            (FUNDED_PUBLISHER, INITIAL_BALANCE),
+            (NEW_OWNER, INITIAL_BALANCE),
+            (NEW_OWNER_2, INITIAL_BALANCE),
+            (NEW_OWNER_3, INITIAL_BALANCE),
+        ],
         ..Default::default()
     }
     .assimilate_storage(&mut storage)
     .unwrap();
 
-    liganite_publish::GenesisConfig::<Test> { publisher_deposit: PUBLISHER_DEPOSIT }
+    liganite_publish::GenesisConfig::<Test> { publisher_deposit: PUBLISHER_DEPOSIT, publishers }
         .assimilate_storage(&mut storage)
         .unwrap();
 
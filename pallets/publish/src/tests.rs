@@ -1,9 +1,22 @@
-use crate::{mock::*, Error, Event, PublisherDeposit, Publishers};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+    migrations, mock::*, DepositDeadlines, EnforcedDeposit, Error, Event, HoldReason, MemberOf,
+    Members, MembersCount, PendingTransfers, PublisherDeposit, PublisherDeposits, PublisherLineage,
+    PublisherStatuses, Publishers, Successors, Verifications,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    storage::unhashed,
+    traits::{fungible, fungible::MutateHold, OnRuntimeUpgrade, StorageVersion},
+};
 use liganite_primitives::{
-    publisher::PublisherManager, testing::bounded_vec, types::PublisherDetails,
+    publisher::PublisherManager,
+    testing::bounded_vec,
+    types::{
+        Name, PublisherDetails, PublisherRole, PublisherStatus, Url, VerificationDetails,
+        VerificationEvidence,
+    },
 };
-use sp_runtime::TokenError;
+use sp_runtime::{DispatchError, TokenError};
 
 #[test]
 fn test_deposit_set() {
@@ -14,6 +27,205 @@ fn test_deposit_set() {
         assert_ok!(Publish::deposit_set(RuntimeOrigin::root(), new_deposit));
 
         assert_eq!(PublisherDeposit::<Test>::get(), new_deposit);
+        assert_eq!(EnforcedDeposit::<Test>::get(), PUBLISHER_DEPOSIT);
+        assert_eq!(
+            DepositDeadlines::<Test>::get().into_inner(),
+            vec![(new_deposit, 1 + DEPOSIT_GRACE_PERIOD)]
+        );
+    });
+}
+
+#[test]
+fn test_deposit_set_decrease_no_grace_period() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Publish::deposit_set(RuntimeOrigin::root(), PUBLISHER_DEPOSIT * 3));
+        assert_ok!(Publish::deposit_set(RuntimeOrigin::root(), PUBLISHER_DEPOSIT / 2));
+
+        assert_eq!(EnforcedDeposit::<Test>::get(), PUBLISHER_DEPOSIT / 2);
+        assert!(DepositDeadlines::<Test>::get().is_empty());
+        assert_eq!(Publish::required_deposit(), PUBLISHER_DEPOSIT / 2);
+    });
+}
+
+#[test]
+fn test_deposit_set_keeps_running_grace_period() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert_ok!(Publish::deposit_set(RuntimeOrigin::root(), PUBLISHER_DEPOSIT * 2));
+
+        // A second increase does not restart the grace period of the first one
+        System::set_block_number(5);
+        assert_ok!(Publish::deposit_set(RuntimeOrigin::root(), PUBLISHER_DEPOSIT * 3));
+        assert_eq!(
+            DepositDeadlines::<Test>::get().into_inner(),
+            vec![
+                (PUBLISHER_DEPOSIT * 2, 1 + DEPOSIT_GRACE_PERIOD),
+                (PUBLISHER_DEPOSIT * 3, 5 + DEPOSIT_GRACE_PERIOD),
+            ]
+        );
+
+        System::set_block_number(DEPOSIT_GRACE_PERIOD);
+        assert!(Publish::is_valid_publisher(&FUNDED_PUBLISHER));
+        System::set_block_number(1 + DEPOSIT_GRACE_PERIOD);
+        assert_eq!(Publish::required_deposit(), PUBLISHER_DEPOSIT * 2);
+        assert!(!Publish::is_valid_publisher(&FUNDED_PUBLISHER));
+        System::set_block_number(5 + DEPOSIT_GRACE_PERIOD);
+        assert_eq!(Publish::required_deposit(), PUBLISHER_DEPOSIT * 3);
+
+        assert_ok!(Publish::deposit_top_up(RuntimeOrigin::signed(FUNDED_PUBLISHER)));
+        assert!(Publish::is_valid_publisher(&FUNDED_PUBLISHER));
+    });
+}
+
+#[test]
+fn test_deposit_set_too_many_increases() {
+    new_test_ext().execute_with(|| {
+        for i in 0..MAX_DEPOSIT_INCREASES as u64 {
+            assert_ok!(Publish::deposit_set(RuntimeOrigin::root(), PUBLISHER_DEPOSIT * (i + 2)));
+        }
+        assert_noop!(
+            Publish::deposit_set(RuntimeOrigin::root(), PUBLISHER_DEPOSIT * 10),
+            Error::<Test>::TooManyDepositIncreases
+        );
+
+        // Once a grace period is over, its increase is enforced and makes room for another one
+        System::set_block_number(1 + DEPOSIT_GRACE_PERIOD);
+        assert_ok!(Publish::deposit_set(RuntimeOrigin::root(), PUBLISHER_DEPOSIT * 10));
+    });
+}
+
+#[test]
+fn test_deposit_grace_period() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert_ok!(Publish::deposit_set(RuntimeOrigin::root(), PUBLISHER_DEPOSIT * 3));
+
+        // The publisher is still valid within the grace period
+        System::set_block_number(DEPOSIT_GRACE_PERIOD);
+        assert!(Publish::is_valid_publisher(&FUNDED_PUBLISHER));
+
+        System::set_block_number(1 + DEPOSIT_GRACE_PERIOD);
+        assert!(!Publish::is_valid_publisher(&FUNDED_PUBLISHER));
+
+        assert_ok!(Publish::deposit_top_up(RuntimeOrigin::signed(FUNDED_PUBLISHER)));
+        assert!(Publish::is_valid_publisher(&FUNDED_PUBLISHER));
+    });
+}
+
+#[test]
+fn test_deposit_top_up() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        let new_deposit = PUBLISHER_DEPOSIT * 3;
+        assert_ok!(Publish::deposit_set(RuntimeOrigin::root(), new_deposit));
+
+        assert_ok!(Publish::deposit_top_up(RuntimeOrigin::signed(FUNDED_PUBLISHER)));
+
+        assert_eq!(PublisherDeposits::<Test>::get(FUNDED_PUBLISHER), new_deposit);
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::PublisherDeposit.into(),
+                &FUNDED_PUBLISHER
+            ),
+            new_deposit
+        );
+        System::assert_last_event(
+            Event::PublisherDepositToppedUp {
+                publisher: FUNDED_PUBLISHER,
+                amount: new_deposit - PUBLISHER_DEPOSIT,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Publish::deposit_top_up(RuntimeOrigin::signed(FUNDED_PUBLISHER)),
+            Error::<Test>::DepositSufficient
+        );
+    });
+}
+
+#[test]
+fn test_deposit_top_up_not_found() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Publish::deposit_top_up(RuntimeOrigin::signed(FUNDED_PUBLISHER)),
+            Error::<Test>::PublisherNotFound
+        );
+    });
+}
+
+#[test]
+fn test_deposit_reclaim_excess() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        let new_deposit = PUBLISHER_DEPOSIT / 4;
+        assert_ok!(Publish::deposit_set(RuntimeOrigin::root(), new_deposit));
+
+        assert_ok!(Publish::deposit_reclaim_excess(RuntimeOrigin::signed(FUNDED_PUBLISHER)));
+
+        assert_eq!(PublisherDeposits::<Test>::get(FUNDED_PUBLISHER), new_deposit);
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&FUNDED_PUBLISHER),
+            INITIAL_BALANCE - new_deposit
+        );
+        System::assert_last_event(
+            Event::PublisherDepositReclaimed {
+                publisher: FUNDED_PUBLISHER,
+                amount: PUBLISHER_DEPOSIT - new_deposit,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Publish::deposit_reclaim_excess(RuntimeOrigin::signed(FUNDED_PUBLISHER)),
+            Error::<Test>::NoExcessDeposit
+        );
+    });
+}
+
+#[test]
+fn test_migrate_v0_to_v1() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<Publish>();
+        let details = PublisherDetails {
+            name: bounded_vec(b"Example Publisher"),
+            url: bounded_vec(b"https://example.com"),
+            ..Default::default()
+        };
+        Publishers::<Test>::insert(FUNDED_PUBLISHER, details);
+        assert_ok!(Balances::hold(
+            &HoldReason::PublisherDeposit.into(),
+            &FUNDED_PUBLISHER,
+            PUBLISHER_DEPOSIT
+        ));
+
+        EnforcedDeposit::<Test>::kill();
+
+        migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<Publish>(), 1);
+        assert_eq!(PublisherDeposits::<Test>::get(FUNDED_PUBLISHER), PUBLISHER_DEPOSIT);
+        assert_eq!(EnforcedDeposit::<Test>::get(), PUBLISHER_DEPOSIT);
+    });
+}
+
+#[test]
+fn test_migrate_v1_to_v2() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(1).put::<Publish>();
+        let name: Name = bounded_vec(b"Example Publisher");
+        let url: Url = bounded_vec(b"https://example.com");
+
+        // The old publisher details only held the name and the URL
+        unhashed::put(&Publishers::<Test>::hashed_key_for(FUNDED_PUBLISHER), &(&name, &url));
+
+        migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<Publish>(), 2);
+        assert_eq!(
+            Publishers::<Test>::get(FUNDED_PUBLISHER),
+            Some(PublisherDetails { name, url, logo: None, description: None, email: None })
+        );
     });
 }
 
@@ -23,6 +235,7 @@ fn test_publisher_register() {
         let details = PublisherDetails {
             name: bounded_vec(b"Example Publisher"),
             url: bounded_vec(b"https://example.com"),
+            ..Default::default()
         };
         assert_ok!(Publish::publisher_register(
             RuntimeOrigin::signed(FUNDED_PUBLISHER),
@@ -40,6 +253,7 @@ fn test_publisher_register_already_exists() {
         let details = PublisherDetails {
             name: bounded_vec(b"Example Publisher"),
             url: bounded_vec(b"https://example.com"),
+            ..Default::default()
         };
         Publishers::<Test>::insert(FUNDED_PUBLISHER, details.clone());
 
@@ -53,8 +267,11 @@ fn test_publisher_register_already_exists() {
 #[test]
 fn test_publisher_register_empty_name() {
     new_test_ext().execute_with(|| {
-        let details =
-            PublisherDetails { name: bounded_vec(b""), url: bounded_vec(b"https://example.com") };
+        let details = PublisherDetails {
+            name: bounded_vec(b""),
+            url: bounded_vec(b"https://example.com"),
+            ..Default::default()
+        };
 
         assert_noop!(
             Publish::publisher_register(RuntimeOrigin::signed(FUNDED_PUBLISHER), details),
@@ -69,6 +286,7 @@ fn test_publisher_register_no_funds() {
         let details = PublisherDetails {
             name: bounded_vec(b"Example Publisher"),
             url: bounded_vec(b"https://example.com"),
+            ..Default::default()
         };
         assert_noop!(
             Publish::publisher_register(RuntimeOrigin::signed(NON_FUNDED_PUBLISHER), details),
@@ -78,15 +296,973 @@ fn test_publisher_register_no_funds() {
 }
 
 #[test]
-fn test_publisher_manager_is_valid_publisher() {
+fn test_genesis_publishers() {
+    let details = PublisherDetails {
+        name: bounded_vec(b"Example Publisher"),
+        url: bounded_vec(b"https://example.com"),
+        ..Default::default()
+    };
+    new_test_ext_with_publishers(vec![(FUNDED_PUBLISHER, details.clone())]).execute_with(|| {
+        assert_eq!(Publishers::<Test>::get(FUNDED_PUBLISHER), Some(details));
+        assert_eq!(PublisherDeposits::<Test>::get(FUNDED_PUBLISHER), PUBLISHER_DEPOSIT);
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::PublisherDeposit.into(),
+                &FUNDED_PUBLISHER
+            ),
+            PUBLISHER_DEPOSIT
+        );
+        assert!(Publish::is_valid_publisher(&FUNDED_PUBLISHER));
+    });
+}
+
+#[test]
+#[should_panic(expected = "A genesis publisher cannot cover the publisher deposit")]
+fn test_genesis_publishers_no_funds() {
+    let details = PublisherDetails {
+        name: bounded_vec(b"Example Publisher"),
+        url: bounded_vec(b"https://example.com"),
+        ..Default::default()
+    };
+    new_test_ext_with_publishers(vec![(NON_FUNDED_PUBLISHER, details)]);
+}
+
+fn register_publisher() {
+    let details = PublisherDetails {
+        name: bounded_vec(b"Example Publisher"),
+        url: bounded_vec(b"https://example.com"),
+        ..Default::default()
+    };
+    assert_ok!(Publish::publisher_register(RuntimeOrigin::signed(FUNDED_PUBLISHER), details));
+}
+
+#[test]
+fn test_publisher_update() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        let details = PublisherDetails {
+            name: bounded_vec(b"Renamed Publisher"),
+            url: bounded_vec(b"https://renamed.example.com"),
+            logo: Some(bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX")),
+            description: Some(bounded_vec(b"QmRJzSVrU5kMkXzDCrePyx3TX7gGu8cXsogX5xLyfMuNPG")),
+            email: Some(bounded_vec(b"contact@example.com")),
+        };
+
+        assert_ok!(Publish::publisher_update(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            details.clone()
+        ));
+
+        assert_eq!(Publishers::<Test>::get(FUNDED_PUBLISHER), Some(details));
+        System::assert_last_event(Event::PublisherUpdated { publisher: FUNDED_PUBLISHER }.into());
+    });
+}
+
+#[test]
+fn test_publisher_update_not_found() {
     new_test_ext().execute_with(|| {
         let details = PublisherDetails {
             name: bounded_vec(b"Example Publisher"),
             url: bounded_vec(b"https://example.com"),
+            ..Default::default()
         };
-        Publishers::<Test>::insert(FUNDED_PUBLISHER, details);
 
-        assert!(Publish::is_valid_publisher(&FUNDED_PUBLISHER));
-        assert!(!Publish::is_valid_publisher(&2));
-    })
+        assert_noop!(
+            Publish::publisher_update(RuntimeOrigin::signed(FUNDED_PUBLISHER), details),
+            Error::<Test>::PublisherNotFound
+        );
+    });
+}
+
+#[test]
+fn test_publisher_update_invalid_details() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        let details = PublisherDetails {
+            name: bounded_vec(b"Example Publisher"),
+            url: bounded_vec(b"https://example.com"),
+            email: Some(bounded_vec(b"not an email")),
+            ..Default::default()
+        };
+
+        assert_noop!(
+            Publish::publisher_update(RuntimeOrigin::signed(FUNDED_PUBLISHER), details),
+            Error::<Test>::PublisherDetailsInvalid
+        );
+    });
+}
+
+#[test]
+fn test_publisher_deregister() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&FUNDED_PUBLISHER),
+            INITIAL_BALANCE - PUBLISHER_DEPOSIT
+        );
+
+        assert_ok!(Publish::publisher_deregister(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            false,
+            0
+        ));
+
+        assert_eq!(Publishers::<Test>::get(FUNDED_PUBLISHER), None);
+        assert!(!Publish::is_valid_publisher(&FUNDED_PUBLISHER));
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&FUNDED_PUBLISHER), INITIAL_BALANCE);
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::PublisherDeposit.into(),
+                &FUNDED_PUBLISHER
+            ),
+            0
+        );
+        System::assert_last_event(Event::PublisherRemoved { publisher: FUNDED_PUBLISHER }.into());
+    });
+}
+
+#[test]
+fn test_publisher_deregister_releases_original_deposit() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert_ok!(Publish::deposit_set(RuntimeOrigin::root(), PUBLISHER_DEPOSIT * 3));
+
+        assert_ok!(Publish::publisher_deregister(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            false,
+            0
+        ));
+
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&FUNDED_PUBLISHER), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn test_publisher_deregister_not_found() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Publish::publisher_deregister(RuntimeOrigin::signed(FUNDED_PUBLISHER), false, 0),
+            Error::<Test>::PublisherNotFound
+        );
+    });
+}
+
+#[test]
+fn test_publisher_deregister_too_many_games() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        GamesCount::set(2);
+
+        assert_noop!(
+            Publish::publisher_deregister(RuntimeOrigin::signed(FUNDED_PUBLISHER), true, 1),
+            Error::<Test>::TooManyGames
+        );
+    });
+}
+
+#[test]
+fn test_publisher_deregister_pending_orders() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        HasPendingOrders::set(true);
+
+        // Pending orders cannot be delisted, so the publisher has to settle them first
+        assert_noop!(
+            Publish::publisher_deregister(RuntimeOrigin::signed(FUNDED_PUBLISHER), true, 0),
+            Error::<Test>::PublisherHasPendingOrders
+        );
+    });
+}
+
+#[test]
+fn test_publisher_deregister_active_games() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        GamesCount::set(1);
+        HasActiveGames::set(true);
+
+        assert_noop!(
+            Publish::publisher_deregister(RuntimeOrigin::signed(FUNDED_PUBLISHER), false, 1),
+            Error::<Test>::PublisherHasActiveGames
+        );
+
+        assert_ok!(Publish::publisher_deregister(RuntimeOrigin::signed(FUNDED_PUBLISHER), true, 1));
+
+        assert!(!HasActiveGames::get());
+        assert_eq!(Publishers::<Test>::get(FUNDED_PUBLISHER), None);
+    });
+}
+
+#[test]
+fn test_publisher_slash_burns_deposit() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        let issuance = <Balances as fungible::Inspect<_>>::total_issuance();
+        let amount = PUBLISHER_DEPOSIT / 4;
+        let reason = bounded_vec(b"Fraudulent listing");
+
+        assert_ok!(Publish::publisher_slash(
+            RuntimeOrigin::root(),
+            FUNDED_PUBLISHER,
+            amount,
+            reason.clone(),
+            false
+        ));
+
+        assert_eq!(PublisherDeposits::<Test>::get(FUNDED_PUBLISHER), PUBLISHER_DEPOSIT - amount);
+        assert_eq!(
+            <Balances as fungible::hold::Inspect<_>>::balance_on_hold(
+                &HoldReason::PublisherDeposit.into(),
+                &FUNDED_PUBLISHER
+            ),
+            PUBLISHER_DEPOSIT - amount
+        );
+        assert_eq!(<Balances as fungible::Inspect<_>>::total_issuance(), issuance - amount);
+        assert!(!Publish::is_valid_publisher(&FUNDED_PUBLISHER));
+        System::assert_last_event(
+            Event::PublisherSlashed {
+                publisher: FUNDED_PUBLISHER,
+                amount,
+                reason,
+                suspended: false,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_publisher_slash_below_deposit() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert!(DepositDeadlines::<Test>::get().is_empty());
+
+        assert_ok!(Publish::publisher_slash(
+            RuntimeOrigin::root(),
+            FUNDED_PUBLISHER,
+            PUBLISHER_DEPOSIT / 4,
+            bounded_vec(b"Fraudulent listing"),
+            false
+        ));
+
+        // No grace period applies to a slashed deposit
+        assert!(!Publish::has_sufficient_deposit(&FUNDED_PUBLISHER));
+        assert_ok!(Publish::deposit_top_up(RuntimeOrigin::signed(FUNDED_PUBLISHER)));
+        assert!(Publish::has_sufficient_deposit(&FUNDED_PUBLISHER));
+    });
+}
+
+#[test]
+fn test_publisher_slash_to_treasury() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        Treasury::set(Some(TREASURY));
+
+        // More than the deposit is requested, so only the deposit is slashed
+        assert_ok!(Publish::publisher_slash(
+            RuntimeOrigin::root(),
+            FUNDED_PUBLISHER,
+            PUBLISHER_DEPOSIT * 2,
+            bounded_vec(b"Fraudulent listing"),
+            false
+        ));
+
+        assert_eq!(PublisherDeposits::<Test>::get(FUNDED_PUBLISHER), 0);
+        assert_eq!(<Balances as fungible::Inspect<_>>::balance(&TREASURY), PUBLISHER_DEPOSIT);
+        assert_eq!(
+            <Balances as fungible::Inspect<_>>::balance(&FUNDED_PUBLISHER),
+            INITIAL_BALANCE - PUBLISHER_DEPOSIT
+        );
+    });
+}
+
+#[test]
+fn test_publisher_slash_and_suspend() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+
+        assert_ok!(Publish::publisher_slash(
+            RuntimeOrigin::root(),
+            FUNDED_PUBLISHER,
+            PUBLISHER_DEPOSIT / 4,
+            bounded_vec(b"Fraudulent listing"),
+            true
+        ));
+
+        assert_eq!(PublisherStatuses::<Test>::get(FUNDED_PUBLISHER), PublisherStatus::Suspended);
+        assert!(!Publish::is_valid_publisher(&FUNDED_PUBLISHER));
+
+        // The suspended publisher cannot walk away with the rest of their deposit
+        assert_noop!(
+            Publish::publisher_deregister(RuntimeOrigin::signed(FUNDED_PUBLISHER), false, 0),
+            Error::<Test>::PublisherSuspended
+        );
+        assert_ok!(Publish::deposit_set(RuntimeOrigin::root(), PUBLISHER_DEPOSIT / 2));
+        assert_noop!(
+            Publish::deposit_reclaim_excess(RuntimeOrigin::signed(FUNDED_PUBLISHER)),
+            Error::<Test>::PublisherSuspended
+        );
+    });
+}
+
+#[test]
+fn test_publisher_slash_bad_origin() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+
+        assert_noop!(
+            Publish::publisher_slash(
+                RuntimeOrigin::signed(FUNDED_PUBLISHER),
+                FUNDED_PUBLISHER,
+                PUBLISHER_DEPOSIT,
+                bounded_vec(b"Fraudulent listing"),
+                true
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_publisher_slash_not_found() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Publish::publisher_slash(
+                RuntimeOrigin::root(),
+                FUNDED_PUBLISHER,
+                PUBLISHER_DEPOSIT,
+                bounded_vec(b"Fraudulent listing"),
+                true
+            ),
+            Error::<Test>::PublisherNotFound
+        );
+    });
+}
+
+#[test]
+fn test_publisher_suspend_and_reinstate() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+
+        assert_ok!(Publish::publisher_suspend(RuntimeOrigin::root(), FUNDED_PUBLISHER));
+
+        assert!(!Publish::is_valid_publisher(&FUNDED_PUBLISHER));
+        assert!(Publish::is_suspended_publisher(&FUNDED_PUBLISHER));
+        // The deposit is not touched by the suspension
+        assert_eq!(PublisherDeposits::<Test>::get(FUNDED_PUBLISHER), PUBLISHER_DEPOSIT);
+        System::assert_last_event(Event::PublisherSuspended { publisher: FUNDED_PUBLISHER }.into());
+        assert_noop!(
+            Publish::publisher_suspend(RuntimeOrigin::root(), FUNDED_PUBLISHER),
+            Error::<Test>::PublisherSuspended
+        );
+
+        assert_ok!(Publish::publisher_reinstate(RuntimeOrigin::root(), FUNDED_PUBLISHER));
+
+        assert!(Publish::is_valid_publisher(&FUNDED_PUBLISHER));
+        assert!(!Publish::is_suspended_publisher(&FUNDED_PUBLISHER));
+        assert_eq!(PublisherStatuses::<Test>::get(FUNDED_PUBLISHER), PublisherStatus::Active);
+        System::assert_last_event(
+            Event::PublisherReinstated { publisher: FUNDED_PUBLISHER }.into(),
+        );
+    });
+}
+
+#[test]
+fn test_publisher_suspend_not_found() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Publish::publisher_suspend(RuntimeOrigin::root(), FUNDED_PUBLISHER),
+            Error::<Test>::PublisherNotFound
+        );
+    });
+}
+
+#[test]
+fn test_publisher_suspend_bad_origin() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+
+        assert_noop!(
+            Publish::publisher_suspend(RuntimeOrigin::signed(FUNDED_PUBLISHER), FUNDED_PUBLISHER),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Publish::publisher_reinstate(RuntimeOrigin::signed(FUNDED_PUBLISHER), FUNDED_PUBLISHER),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_publisher_reinstate_not_suspended() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+
+        assert_noop!(
+            Publish::publisher_reinstate(RuntimeOrigin::root(), FUNDED_PUBLISHER),
+            Error::<Test>::PublisherNotSuspended
+        );
+    });
+}
+
+#[test]
+fn test_member_grant() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+
+        assert_ok!(Publish::member_grant(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            MEMBER,
+            PublisherRole::CatalogManager
+        ));
+
+        assert_eq!(
+            Members::<Test>::get(FUNDED_PUBLISHER, MEMBER),
+            Some(PublisherRole::CatalogManager)
+        );
+        assert_eq!(MemberOf::<Test>::get(MEMBER), Some(FUNDED_PUBLISHER));
+        assert_eq!(MembersCount::<Test>::get(FUNDED_PUBLISHER), 1);
+        assert_eq!(Publish::publisher_of(&MEMBER), FUNDED_PUBLISHER);
+        assert!(Publish::has_permission(&FUNDED_PUBLISHER, &MEMBER, PublisherRole::CatalogManager));
+        assert!(!Publish::has_permission(&FUNDED_PUBLISHER, &MEMBER, PublisherRole::Finance));
+        System::assert_last_event(
+            Event::MemberRoleGranted {
+                publisher: FUNDED_PUBLISHER,
+                member: MEMBER,
+                role: PublisherRole::CatalogManager,
+            }
+            .into(),
+        );
+
+        // Granting another role replaces the existing one
+        assert_ok!(Publish::member_grant(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            MEMBER,
+            PublisherRole::Admin
+        ));
+
+        assert_eq!(Members::<Test>::get(FUNDED_PUBLISHER, MEMBER), Some(PublisherRole::Admin));
+        assert_eq!(MembersCount::<Test>::get(FUNDED_PUBLISHER), 1);
+        assert!(Publish::has_permission(&FUNDED_PUBLISHER, &MEMBER, PublisherRole::Finance));
+    });
+}
+
+#[test]
+fn test_member_grant_publisher_not_found() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Publish::member_grant(
+                RuntimeOrigin::signed(FUNDED_PUBLISHER),
+                MEMBER,
+                PublisherRole::Admin
+            ),
+            Error::<Test>::PublisherNotFound
+        );
+    });
+}
+
+#[test]
+fn test_member_grant_invalid_member() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        let details = PublisherDetails {
+            name: bounded_vec(b"Other Publisher"),
+            url: bounded_vec(b"https://other.example.com"),
+            ..Default::default()
+        };
+        Publishers::<Test>::insert(NON_FUNDED_PUBLISHER, details);
+        assert_ok!(Publish::member_grant(
+            RuntimeOrigin::signed(NON_FUNDED_PUBLISHER),
+            MEMBER,
+            PublisherRole::Admin
+        ));
+
+        assert_noop!(
+            Publish::member_grant(
+                RuntimeOrigin::signed(FUNDED_PUBLISHER),
+                NON_FUNDED_PUBLISHER,
+                PublisherRole::Admin
+            ),
+            Error::<Test>::MemberIsPublisher
+        );
+        assert_noop!(
+            Publish::member_grant(
+                RuntimeOrigin::signed(FUNDED_PUBLISHER),
+                MEMBER,
+                PublisherRole::Admin
+            ),
+            Error::<Test>::AccountIsMember
+        );
+    });
+}
+
+#[test]
+fn test_member_grant_too_many_members() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        for member in [MEMBER, MEMBER_2] {
+            assert_ok!(Publish::member_grant(
+                RuntimeOrigin::signed(FUNDED_PUBLISHER),
+                member,
+                PublisherRole::Finance
+            ));
+        }
+
+        assert_noop!(
+            Publish::member_grant(
+                RuntimeOrigin::signed(FUNDED_PUBLISHER),
+                MEMBER_3,
+                PublisherRole::Finance
+            ),
+            Error::<Test>::TooManyMembers
+        );
+    });
+}
+
+#[test]
+fn test_member_revoke() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert_ok!(Publish::member_grant(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            MEMBER,
+            PublisherRole::Admin
+        ));
+
+        assert_ok!(Publish::member_revoke(RuntimeOrigin::signed(FUNDED_PUBLISHER), MEMBER));
+
+        assert_eq!(Members::<Test>::get(FUNDED_PUBLISHER, MEMBER), None);
+        assert_eq!(MemberOf::<Test>::get(MEMBER), None);
+        assert_eq!(MembersCount::<Test>::get(FUNDED_PUBLISHER), 0);
+        assert_eq!(Publish::publisher_of(&MEMBER), MEMBER);
+        System::assert_last_event(
+            Event::MemberRevoked { publisher: FUNDED_PUBLISHER, member: MEMBER }.into(),
+        );
+        assert_noop!(
+            Publish::member_revoke(RuntimeOrigin::signed(FUNDED_PUBLISHER), MEMBER),
+            Error::<Test>::MemberNotFound
+        );
+    });
+}
+
+#[test]
+fn test_member_acts_on_behalf_of_publisher() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert_ok!(Publish::member_grant(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            MEMBER,
+            PublisherRole::Finance
+        ));
+        assert_ok!(Publish::deposit_set(RuntimeOrigin::root(), PUBLISHER_DEPOSIT * 2));
+
+        // The deposit is held from the publisher, not from the member
+        assert_ok!(Publish::deposit_top_up(RuntimeOrigin::signed(MEMBER)));
+        assert_eq!(PublisherDeposits::<Test>::get(FUNDED_PUBLISHER), PUBLISHER_DEPOSIT * 2);
+
+        let details = PublisherDetails {
+            name: bounded_vec(b"Renamed Publisher"),
+            url: bounded_vec(b"https://example.com"),
+            ..Default::default()
+        };
+        assert_noop!(
+            Publish::publisher_update(RuntimeOrigin::signed(MEMBER), details.clone()),
+            Error::<Test>::NoPermission
+        );
+
+        assert_ok!(Publish::member_grant(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            MEMBER,
+            PublisherRole::Admin
+        ));
+        assert_ok!(Publish::publisher_update(RuntimeOrigin::signed(MEMBER), details.clone()));
+        assert_eq!(Publishers::<Test>::get(FUNDED_PUBLISHER), Some(details));
+    });
+}
+
+#[test]
+fn test_publisher_register_member() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert_ok!(Publish::member_grant(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            MEMBER,
+            PublisherRole::Admin
+        ));
+        let details = PublisherDetails {
+            name: bounded_vec(b"Example Publisher"),
+            url: bounded_vec(b"https://example.com"),
+            ..Default::default()
+        };
+
+        assert_noop!(
+            Publish::publisher_register(RuntimeOrigin::signed(MEMBER), details),
+            Error::<Test>::AccountIsMember
+        );
+    });
+}
+
+#[test]
+fn test_publisher_deregister_revokes_members() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert_ok!(Publish::member_grant(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            MEMBER,
+            PublisherRole::Admin
+        ));
+
+        assert_ok!(Publish::publisher_deregister(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            false,
+            0
+        ));
+
+        assert_eq!(Members::<Test>::get(FUNDED_PUBLISHER, MEMBER), None);
+        assert_eq!(MemberOf::<Test>::get(MEMBER), None);
+        assert_eq!(MembersCount::<Test>::get(FUNDED_PUBLISHER), 0);
+    });
+}
+
+#[test]
+fn test_publisher_manager_is_valid_publisher() {
+    new_test_ext().execute_with(|| {
+        let details = PublisherDetails {
+            name: bounded_vec(b"Example Publisher"),
+            url: bounded_vec(b"https://example.com"),
+            ..Default::default()
+        };
+        Publishers::<Test>::insert(FUNDED_PUBLISHER, details);
+
+        assert!(Publish::is_valid_publisher(&FUNDED_PUBLISHER));
+        assert!(!Publish::is_valid_publisher(&2));
+    })
+}
+
+#[test]
+fn test_publisher_transfer() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert_ok!(Publish::member_grant(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            MEMBER,
+            PublisherRole::CatalogManager
+        ));
+        let details = Publishers::<Test>::get(FUNDED_PUBLISHER).unwrap();
+
+        assert_ok!(Publish::publisher_transfer_initiate(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            NEW_OWNER
+        ));
+        assert_eq!(PendingTransfers::<Test>::get(FUNDED_PUBLISHER), Some(NEW_OWNER));
+        System::assert_last_event(
+            Event::PublisherTransferInitiated { publisher: FUNDED_PUBLISHER, new_owner: NEW_OWNER }
+                .into(),
+        );
+
+        assert_ok!(Publish::publisher_transfer_accept(
+            RuntimeOrigin::signed(NEW_OWNER),
+            FUNDED_PUBLISHER
+        ));
+
+        assert_eq!(PendingTransfers::<Test>::get(FUNDED_PUBLISHER), None);
+        assert_eq!(Publishers::<Test>::get(FUNDED_PUBLISHER), None);
+        assert_eq!(Publishers::<Test>::get(NEW_OWNER), Some(details));
+        assert_eq!(PublisherDeposits::<Test>::get(FUNDED_PUBLISHER), 0);
+        assert_eq!(PublisherDeposits::<Test>::get(NEW_OWNER), PUBLISHER_DEPOSIT);
+        assert_eq!(
+            <Balances as fungible::InspectHold<_>>::balance_on_hold(
+                &HoldReason::PublisherDeposit.into(),
+                &NEW_OWNER
+            ),
+            PUBLISHER_DEPOSIT
+        );
+        assert_eq!(Members::<Test>::get(NEW_OWNER, MEMBER), Some(PublisherRole::CatalogManager));
+        assert_eq!(MemberOf::<Test>::get(MEMBER), Some(NEW_OWNER));
+        assert_eq!(MembersCount::<Test>::get(NEW_OWNER), 1);
+        assert_eq!(MembersCount::<Test>::get(FUNDED_PUBLISHER), 0);
+        assert_eq!(Successors::<Test>::get(FUNDED_PUBLISHER), Some(NEW_OWNER));
+        assert_eq!(Publish::current_publisher(&FUNDED_PUBLISHER), NEW_OWNER);
+        assert_eq!(Publish::previous_publishers(&NEW_OWNER), vec![FUNDED_PUBLISHER]);
+        assert_eq!(TransferredCatalogs::get(), vec![(FUNDED_PUBLISHER, NEW_OWNER)]);
+        assert!(Publish::is_valid_publisher(&NEW_OWNER));
+        System::assert_last_event(
+            Event::PublisherTransferred { publisher: FUNDED_PUBLISHER, new_owner: NEW_OWNER }
+                .into(),
+        );
+    });
+}
+
+#[test]
+fn test_publisher_transfer_lineage() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        for (publisher, new_owner) in [(FUNDED_PUBLISHER, NEW_OWNER), (NEW_OWNER, NEW_OWNER_2)] {
+            assert_ok!(Publish::publisher_transfer_initiate(
+                RuntimeOrigin::signed(publisher),
+                new_owner
+            ));
+            assert_ok!(Publish::publisher_transfer_accept(
+                RuntimeOrigin::signed(new_owner),
+                publisher
+            ));
+        }
+
+        assert_eq!(
+            PublisherLineage::<Test>::get(NEW_OWNER_2).into_inner(),
+            vec![FUNDED_PUBLISHER, NEW_OWNER]
+        );
+        assert_eq!(Publish::current_publisher(&FUNDED_PUBLISHER), NEW_OWNER_2);
+        assert_eq!(Publish::current_publisher(&NEW_OWNER), NEW_OWNER_2);
+
+        // The lineage is bounded
+        assert_ok!(Publish::publisher_transfer_initiate(
+            RuntimeOrigin::signed(NEW_OWNER_2),
+            NEW_OWNER_3
+        ));
+        assert_noop!(
+            Publish::publisher_transfer_accept(RuntimeOrigin::signed(NEW_OWNER_3), NEW_OWNER_2),
+            Error::<Test>::TooManyTransfers
+        );
+
+        // The previous accounts cannot register again
+        assert_noop!(
+            Publish::publisher_register(
+                RuntimeOrigin::signed(NEW_OWNER),
+                Publishers::<Test>::get(NEW_OWNER_2).unwrap()
+            ),
+            Error::<Test>::PublisherTransferred
+        );
+    });
+}
+
+#[test]
+fn test_publisher_transfer_invalid_new_owner() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert_ok!(Publish::member_grant(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            MEMBER,
+            PublisherRole::Admin
+        ));
+
+        assert_noop!(
+            Publish::publisher_transfer_initiate(
+                RuntimeOrigin::signed(FUNDED_PUBLISHER),
+                FUNDED_PUBLISHER
+            ),
+            Error::<Test>::PublisherAlreadyExists
+        );
+        assert_noop!(
+            Publish::publisher_transfer_initiate(RuntimeOrigin::signed(FUNDED_PUBLISHER), MEMBER),
+            Error::<Test>::AccountIsMember
+        );
+        assert_noop!(
+            Publish::publisher_transfer_initiate(RuntimeOrigin::signed(NEW_OWNER), NEW_OWNER_2),
+            Error::<Test>::PublisherNotFound
+        );
+    });
+}
+
+#[test]
+fn test_publisher_transfer_accept_not_found() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert_ok!(Publish::publisher_transfer_initiate(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            NEW_OWNER
+        ));
+
+        assert_noop!(
+            Publish::publisher_transfer_accept(
+                RuntimeOrigin::signed(NEW_OWNER_2),
+                FUNDED_PUBLISHER
+            ),
+            Error::<Test>::TransferNotFound
+        );
+        assert_noop!(
+            Publish::publisher_transfer_accept(RuntimeOrigin::signed(NEW_OWNER), NEW_OWNER_2),
+            Error::<Test>::TransferNotFound
+        );
+    });
+}
+
+#[test]
+fn test_publisher_transfer_accept_pending_orders() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert_ok!(Publish::publisher_transfer_initiate(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            NEW_OWNER
+        ));
+
+        HasPendingOrders::set(true);
+        assert_noop!(
+            Publish::publisher_transfer_accept(RuntimeOrigin::signed(NEW_OWNER), FUNDED_PUBLISHER),
+            Error::<Test>::PublisherHasPendingOrders
+        );
+    });
+}
+
+#[test]
+fn test_publisher_transfer_catalog_in_progress() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+
+        IsTransferring::set(true);
+        assert_noop!(
+            Publish::publisher_transfer_initiate(
+                RuntimeOrigin::signed(FUNDED_PUBLISHER),
+                NEW_OWNER
+            ),
+            Error::<Test>::CatalogTransferInProgress
+        );
+        assert_noop!(
+            Publish::publisher_deregister(RuntimeOrigin::signed(FUNDED_PUBLISHER), false, 0),
+            Error::<Test>::CatalogTransferInProgress
+        );
+    });
+}
+
+fn domain_evidence(url: &[u8]) -> VerificationEvidence {
+    VerificationEvidence::Domain {
+        url: bounded_vec(url),
+        proof: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+    }
+}
+
+#[test]
+fn test_publisher_verify() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        let evidence = domain_evidence(b"https://example.com");
+
+        assert_ok!(Publish::publisher_verify(
+            RuntimeOrigin::root(),
+            FUNDED_PUBLISHER,
+            evidence.clone()
+        ));
+
+        assert_eq!(
+            Verifications::<Test>::get(FUNDED_PUBLISHER),
+            Some(VerificationDetails { evidence: evidence.clone(), verified_at: 1 })
+        );
+        System::assert_last_event(
+            Event::PublisherVerified { publisher: FUNDED_PUBLISHER, evidence }.into(),
+        );
+    });
+}
+
+#[test]
+fn test_publisher_verify_invalid() {
+    new_test_ext().execute_with(|| {
+        let evidence = domain_evidence(b"https://example.com");
+        assert_noop!(
+            Publish::publisher_verify(RuntimeOrigin::root(), FUNDED_PUBLISHER, evidence.clone()),
+            Error::<Test>::PublisherNotFound
+        );
+
+        register_publisher();
+        assert_noop!(
+            Publish::publisher_verify(
+                RuntimeOrigin::signed(FUNDED_PUBLISHER),
+                FUNDED_PUBLISHER,
+                evidence
+            ),
+            DispatchError::BadOrigin
+        );
+        // The domain proof must be for the host of the publisher's URL
+        assert_noop!(
+            Publish::publisher_verify(
+                RuntimeOrigin::root(),
+                FUNDED_PUBLISHER,
+                domain_evidence(b"https://impersonator.com")
+            ),
+            Error::<Test>::VerificationEvidenceInvalid
+        );
+        assert_noop!(
+            Publish::publisher_verify(
+                RuntimeOrigin::root(),
+                FUNDED_PUBLISHER,
+                VerificationEvidence::Document(bounded_vec(b"NotARealCID123"))
+            ),
+            Error::<Test>::VerificationEvidenceInvalid
+        );
+    });
+}
+
+#[test]
+fn test_publisher_unverify() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert_noop!(
+            Publish::publisher_unverify(RuntimeOrigin::root(), FUNDED_PUBLISHER),
+            Error::<Test>::PublisherNotVerified
+        );
+        assert_ok!(Publish::publisher_verify(
+            RuntimeOrigin::root(),
+            FUNDED_PUBLISHER,
+            domain_evidence(b"https://example.com")
+        ));
+
+        assert_ok!(Publish::publisher_unverify(RuntimeOrigin::root(), FUNDED_PUBLISHER));
+
+        assert_eq!(Verifications::<Test>::get(FUNDED_PUBLISHER), None);
+        System::assert_last_event(
+            Event::PublisherVerificationRevoked { publisher: FUNDED_PUBLISHER }.into(),
+        );
+    });
+}
+
+#[test]
+fn test_publisher_update_revokes_verification() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert_ok!(Publish::publisher_verify(
+            RuntimeOrigin::root(),
+            FUNDED_PUBLISHER,
+            domain_evidence(b"https://example.com")
+        ));
+        let details = Publishers::<Test>::get(FUNDED_PUBLISHER).unwrap();
+
+        // The verification holds as long as the URL stays on the same domain
+        assert_ok!(Publish::publisher_update(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            PublisherDetails { url: bounded_vec(b"https://example.com/studio"), ..details.clone() }
+        ));
+        assert!(Verifications::<Test>::contains_key(FUNDED_PUBLISHER));
+
+        assert_ok!(Publish::publisher_update(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            PublisherDetails { url: bounded_vec(b"https://other.com"), ..details }
+        ));
+        assert_eq!(Verifications::<Test>::get(FUNDED_PUBLISHER), None);
+        System::assert_has_event(
+            Event::PublisherVerificationRevoked { publisher: FUNDED_PUBLISHER }.into(),
+        );
+    });
+}
+
+#[test]
+fn test_publisher_transfer_keeps_verification() {
+    new_test_ext().execute_with(|| {
+        register_publisher();
+        assert_ok!(Publish::publisher_verify(
+            RuntimeOrigin::root(),
+            FUNDED_PUBLISHER,
+            domain_evidence(b"https://example.com")
+        ));
+
+        assert_ok!(Publish::publisher_transfer_initiate(
+            RuntimeOrigin::signed(FUNDED_PUBLISHER),
+            NEW_OWNER
+        ));
+        assert_ok!(Publish::publisher_transfer_accept(
+            RuntimeOrigin::signed(NEW_OWNER),
+            FUNDED_PUBLISHER
+        ));
+
+        assert_eq!(Verifications::<Test>::get(FUNDED_PUBLISHER), None);
+        assert!(Verifications::<Test>::contains_key(NEW_OWNER));
+    });
 }
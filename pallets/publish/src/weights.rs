@@ -10,7 +10,20 @@ use frame_support::{
 /// Weight functions needed for liganite_publisher.
 pub trait WeightInfo {
     fn deposit_set() -> Weight;
-    fn publisher_register(a: u32, b: u32) -> Weight;
+    fn publisher_register(a: u32, b: u32, c: u32) -> Weight;
+    fn publisher_deregister(n: u32) -> Weight;
+    fn deposit_top_up() -> Weight;
+    fn deposit_reclaim_excess() -> Weight;
+    fn publisher_update(a: u32, b: u32, c: u32) -> Weight;
+    fn publisher_slash() -> Weight;
+    fn publisher_suspend() -> Weight;
+    fn publisher_reinstate() -> Weight;
+    fn member_grant() -> Weight;
+    fn member_revoke() -> Weight;
+    fn publisher_transfer_initiate() -> Weight;
+    fn publisher_transfer_accept() -> Weight;
+    fn publisher_verify() -> Weight;
+    fn publisher_unverify() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -19,7 +32,59 @@ impl WeightInfo for () {
         Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
     }
 
-    fn publisher_register(_a: u32, _b: u32) -> Weight {
+    fn publisher_register(_a: u32, _b: u32, _c: u32) -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn publisher_deregister(_n: u32) -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn deposit_top_up() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn deposit_reclaim_excess() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn publisher_update(_a: u32, _b: u32, _c: u32) -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn publisher_slash() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn publisher_suspend() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn publisher_reinstate() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn member_grant() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn member_revoke() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn publisher_transfer_initiate() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn publisher_transfer_accept() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn publisher_verify() -> Weight {
+        Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+
+    fn publisher_unverify() -> Weight {
         Weight::from_parts(9_000_000, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
     }
 }
@@ -1,9 +1,89 @@
-use crate::types::PublisherDetails;
+use crate::types::PublisherRole;
+use frame_support::pallet_prelude::*;
+use scale_info::prelude::vec::Vec;
 
 pub trait PublisherManager {
     type PublisherId;
 
     fn is_valid_publisher(publisher_id: &Self::PublisherId) -> bool;
 
-    fn insert_publisher(publisher_id: &Self::PublisherId, details: &PublisherDetails);
+    fn is_suspended_publisher(publisher_id: &Self::PublisherId) -> bool;
+
+    /// Returns the publisher on whose behalf the account acts: the publisher the account is a
+    /// member of, or the account itself.
+    fn publisher_of(account: &Self::PublisherId) -> Self::PublisherId;
+
+    /// Returns whether the account may act with the given role on behalf of the publisher.
+    fn has_permission(
+        publisher_id: &Self::PublisherId,
+        account: &Self::PublisherId,
+        role: PublisherRole,
+    ) -> bool;
+
+    /// Returns the current account of a publisher, following its ownership transfers.
+    fn current_publisher(publisher_id: &Self::PublisherId) -> Self::PublisherId;
+
+    /// Returns the previous accounts of a publisher, from the oldest to the most recent.
+    fn previous_publishers(publisher_id: &Self::PublisherId) -> Vec<Self::PublisherId>;
+
+    /// Inserts a publisher whose deposit is recorded as covering the required deposit, without
+    /// holding it. Only available to set up the benchmarks.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn insert_publisher(publisher_id: &Self::PublisherId, details: &crate::types::PublisherDetails);
+}
+
+/// The catalog of a publisher, which has to be wound down before the publisher can leave.
+pub trait PublisherCatalog<PublisherId> {
+    /// Returns the number of games of the publisher.
+    fn games_count(publisher_id: &PublisherId) -> u32;
+
+    /// Returns whether the publisher has pending orders.
+    fn has_pending_orders(publisher_id: &PublisherId) -> bool;
+
+    /// Returns whether the publisher has games which can be purchased.
+    fn has_active_games(publisher_id: &PublisherId) -> bool;
+
+    /// Delists every game of the publisher which can be purchased. The rest of the catalog is
+    /// kept for the buyers who own the games.
+    fn delist_games(publisher_id: &PublisherId);
+
+    /// Moves the catalog of the publisher to a new account, possibly over several blocks.
+    fn transfer_catalog(
+        publisher_id: &PublisherId,
+        new_publisher_id: &PublisherId,
+    ) -> DispatchResult;
+
+    /// Returns whether the catalog of the publisher is being moved to or from another account.
+    fn is_transferring(publisher_id: &PublisherId) -> bool;
+
+    /// Adds the given number of games which can be purchased to the publisher's catalog.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn add_active_games(_publisher_id: &PublisherId, _n: u32) {}
+}
+
+impl<PublisherId> PublisherCatalog<PublisherId> for () {
+    fn games_count(_publisher_id: &PublisherId) -> u32 {
+        0
+    }
+
+    fn has_pending_orders(_publisher_id: &PublisherId) -> bool {
+        false
+    }
+
+    fn has_active_games(_publisher_id: &PublisherId) -> bool {
+        false
+    }
+
+    fn delist_games(_publisher_id: &PublisherId) {}
+
+    fn transfer_catalog(
+        _publisher_id: &PublisherId,
+        _new_publisher_id: &PublisherId,
+    ) -> DispatchResult {
+        Ok(())
+    }
+
+    fn is_transferring(_publisher_id: &PublisherId) -> bool {
+        false
+    }
 }
@@ -1,420 +1,537 @@
-/// List of predefined game tags.
-pub const TAGS: &[&str; 406] = &[
+//! The predefined game tags, added to the `Tags` storage at genesis.
+//!
+//! Every tag has an explicit id, which the games store, so the list can be reordered freely. The
+//! ids must never be changed nor reused. The list is checked at compile time: the ids and the
+//! names must be unique, and every name must be a non-empty string of at most `MAX_TAG_SIZE`
+//! bytes. The names are `&str`, so they are always valid UTF-8.
+
+use crate::{
+    types::{
+        TagCategory::{self, *},
+        TagId,
+    },
+    MAX_TAG_SIZE,
+};
+
+/// List of predefined game tags, along with their id and category.
+pub const TAGS: &[(TagId, &str, TagCategory)] = &[
     // Top-Level Genres
-    "Action",
-    "Adventure",
-    "Casual",
-    "Experimental",
-    "Puzzle",
-    "Racing",
-    "RPG",
-    "Simulation",
-    "Sports",
-    "Strategy",
-    "Tabletop",
+    (0, "Action", TopLevelGenre),
+    (1, "Adventure", TopLevelGenre),
+    (2, "Casual", TopLevelGenre),
+    (3, "Experimental", TopLevelGenre),
+    (4, "Puzzle", TopLevelGenre),
+    (5, "Racing", TopLevelGenre),
+    (6, "RPG", TopLevelGenre),
+    (7, "Simulation", TopLevelGenre),
+    (8, "Sports", TopLevelGenre),
+    (9, "Strategy", TopLevelGenre),
+    (10, "Tabletop", TopLevelGenre),
     // Genres
-    "Action RPG",
-    "Action-Adventure",
-    "Arcade",
-    "Auto Battler",
-    "Automobile Sim",
-    "Base Building",
-    "Baseball",
-    "Basketball",
-    "Battle Royale",
-    "BMX",
-    "Board Game",
-    "Bowling",
-    "Building",
-    "Card Game",
-    "Character Action Game",
-    "Chess",
-    "Clicker",
-    "Cycling",
-    "Diplomacy",
-    "eSports",
-    "Experimental",
-    "Exploration",
-    "Farming Sim",
-    "Fighting",
-    "Football",
-    "God Game",
-    "Golf",
-    "Hacking",
-    "Hidden Object",
-    "Hockey",
-    "Idler",
-    "Interactive Fiction",
-    "Management",
-    "Match 3",
-    "Medical Sim",
-    "Mini Golf",
-    "Mining",
-    "MMORPG",
-    "MOBA",
-    "Motocross",
-    "Open World",
-    "Outbreak Sim",
-    "Party-Based RPG",
-    "Pinball",
-    "Platformer",
-    "Point & Click",
-    "Rhythm",
-    "Roguelike",
-    "RTS",
-    "Sandbox",
-    "Shooter",
-    "Skateboarding",
-    "Skating",
-    "Skiing",
-    "Snowboarding",
-    "Soccer",
-    "Space Sim",
-    "Stealth",
-    "Strategy RPG",
-    "Survival",
-    "Tennis",
-    "Tower Defense",
-    "Trivia",
-    "Turn-Based Strategy",
-    "Visual Novel",
-    "Walking Simulator",
-    "Word Game",
-    "Wrestling",
+    (11, "Action RPG", Genre),
+    (12, "Action-Adventure", Genre),
+    (13, "Arcade", Genre),
+    (14, "Auto Battler", Genre),
+    (15, "Automobile Sim", Genre),
+    (16, "Base Building", Genre),
+    (17, "Baseball", Genre),
+    (18, "Basketball", Genre),
+    (19, "Battle Royale", Genre),
+    (20, "BMX", Genre),
+    (21, "Board Game", Genre),
+    (22, "Bowling", Genre),
+    (23, "Building", Genre),
+    (24, "Card Game", Genre),
+    (25, "Character Action Game", Genre),
+    (26, "Chess", Genre),
+    (27, "Clicker", Genre),
+    (28, "Cycling", Genre),
+    (29, "Diplomacy", Genre),
+    (30, "eSports", Genre),
+    (32, "Exploration", Genre),
+    (33, "Farming Sim", Genre),
+    (34, "Fighting", Genre),
+    (35, "Football", Genre),
+    (36, "God Game", Genre),
+    (37, "Golf", Genre),
+    (38, "Hacking", Genre),
+    (39, "Hidden Object", Genre),
+    (40, "Hockey", Genre),
+    (41, "Idler", Genre),
+    (42, "Interactive Fiction", Genre),
+    (43, "Management", Genre),
+    (44, "Match 3", Genre),
+    (45, "Medical Sim", Genre),
+    (46, "Mini Golf", Genre),
+    (47, "Mining", Genre),
+    (48, "MMORPG", Genre),
+    (49, "MOBA", Genre),
+    (50, "Motocross", Genre),
+    (51, "Open World", Genre),
+    (52, "Outbreak Sim", Genre),
+    (53, "Party-Based RPG", Genre),
+    (54, "Pinball", Genre),
+    (55, "Platformer", Genre),
+    (56, "Point & Click", Genre),
+    (57, "Rhythm", Genre),
+    (58, "Roguelike", Genre),
+    (59, "RTS", Genre),
+    (60, "Sandbox", Genre),
+    (61, "Shooter", Genre),
+    (62, "Skateboarding", Genre),
+    (63, "Skating", Genre),
+    (64, "Skiing", Genre),
+    (65, "Snowboarding", Genre),
+    (66, "Soccer", Genre),
+    (67, "Space Sim", Genre),
+    (68, "Stealth", Genre),
+    (69, "Strategy RPG", Genre),
+    (70, "Survival", Genre),
+    (71, "Tennis", Genre),
+    (72, "Tower Defense", Genre),
+    (73, "Trivia", Genre),
+    (74, "Turn-Based Strategy", Genre),
+    (75, "Visual Novel", Genre),
+    (76, "Walking Simulator", Genre),
+    (77, "Word Game", Genre),
+    (78, "Wrestling", Genre),
     // Sub-Genres
-    "2D Fighter",
-    "2D Platformer",
-    "3D Fighter",
-    "3D Platformer",
-    "4X",
-    "Action Roguelike",
-    "Arena Shooter",
-    "Beat 'em up",
-    "Bullet Hell",
-    "Card Battler",
-    "Choose Your Own Adventure",
-    "City Builder",
-    "Collectathon",
-    "Colony Sim",
-    "Combat Racing",
-    "CRPG",
-    "Dating Sim",
-    "Dungeon Crawler",
-    "Education",
-    "Flight",
-    "FPS",
-    "Grand Strategy",
-    "Hack and Slash",
-    "Heist",
-    "Hero Shooter",
-    "Horror",
-    "Immersive Sim",
-    "Investigation",
-    "JRPG",
-    "Life Sim",
-    "Looter Shooter",
-    "Metroidvania",
-    "Mystery Dungeon",
-    "On-Rails Shooter",
-    "Open World Survival Craft",
-    "Political Sim",
-    "Precision Platformer",
-    "Programming",
-    "Real Time Tactics",
-    "Roguelite",
-    "Roguevania",
-    "Runner",
-    "Shoot 'Em Up",
-    "Side Scroller",
-    "Sokoban",
-    "Solitaire",
-    "Souls-like",
-    "Spectacle fighter",
-    "Spelling",
-    "Survival Horror",
-    "Tactical RPG",
-    "Third-Person Shooter",
-    "Time Management",
-    "Top-Down Shooter",
-    "Trading",
-    "Trading Card Game",
-    "Traditional Roguelike",
-    "Turn-Based Tactics",
-    "Twin Stick Shooter",
-    "Typing",
-    "Wargame",
+    (79, "2D Fighter", SubGenre),
+    (80, "2D Platformer", SubGenre),
+    (81, "3D Fighter", SubGenre),
+    (82, "3D Platformer", SubGenre),
+    (83, "4X", SubGenre),
+    (84, "Action Roguelike", SubGenre),
+    (85, "Arena Shooter", SubGenre),
+    (86, "Beat 'em up", SubGenre),
+    (87, "Bullet Hell", SubGenre),
+    (88, "Card Battler", SubGenre),
+    (89, "Choose Your Own Adventure", SubGenre),
+    (90, "City Builder", SubGenre),
+    (91, "Collectathon", SubGenre),
+    (92, "Colony Sim", SubGenre),
+    (93, "Combat Racing", SubGenre),
+    (94, "CRPG", SubGenre),
+    (95, "Dating Sim", SubGenre),
+    (96, "Dungeon Crawler", SubGenre),
+    (97, "Education", SubGenre),
+    (98, "Flight", SubGenre),
+    (99, "FPS", SubGenre),
+    (100, "Grand Strategy", SubGenre),
+    (101, "Hack and Slash", SubGenre),
+    (102, "Heist", SubGenre),
+    (103, "Hero Shooter", SubGenre),
+    (104, "Horror", SubGenre),
+    (105, "Immersive Sim", SubGenre),
+    (106, "Investigation", SubGenre),
+    (107, "JRPG", SubGenre),
+    (108, "Life Sim", SubGenre),
+    (109, "Looter Shooter", SubGenre),
+    (110, "Metroidvania", SubGenre),
+    (111, "Mystery Dungeon", SubGenre),
+    (112, "On-Rails Shooter", SubGenre),
+    (113, "Open World Survival Craft", SubGenre),
+    (114, "Political Sim", SubGenre),
+    (115, "Precision Platformer", SubGenre),
+    (116, "Programming", SubGenre),
+    (117, "Real Time Tactics", SubGenre),
+    (118, "Roguelite", SubGenre),
+    (119, "Roguevania", SubGenre),
+    (120, "Runner", SubGenre),
+    (121, "Shoot 'Em Up", SubGenre),
+    (122, "Side Scroller", SubGenre),
+    (123, "Sokoban", SubGenre),
+    (124, "Solitaire", SubGenre),
+    (125, "Souls-like", SubGenre),
+    (126, "Spectacle fighter", SubGenre),
+    (127, "Spelling", SubGenre),
+    (128, "Survival Horror", SubGenre),
+    (129, "Tactical RPG", SubGenre),
+    (130, "Third-Person Shooter", SubGenre),
+    (131, "Time Management", SubGenre),
+    (132, "Top-Down Shooter", SubGenre),
+    (133, "Trading", SubGenre),
+    (134, "Trading Card Game", SubGenre),
+    (135, "Traditional Roguelike", SubGenre),
+    (136, "Turn-Based Tactics", SubGenre),
+    (137, "Twin Stick Shooter", SubGenre),
+    (138, "Typing", SubGenre),
+    (139, "Wargame", SubGenre),
     // Visuals & Viewpoint
-    "2.5D",
-    "2D",
-    "360 Video",
-    "3D",
-    "3D Vision",
-    "Abstract",
-    "Anime",
-    "Cartoon",
-    "Cartoony",
-    "Cinematic",
-    "Colorful",
-    "Comic Book",
-    "Cute",
-    "First-Person",
-    "FMV",
-    "Hand-drawn",
-    "Isometric",
-    "Minimalist",
-    "Noir",
-    "Pixel Graphics",
-    "Psychedelic",
-    "Realistic",
-    "Split Screen",
-    "Stylized",
-    "Text-Based",
-    "Third Person",
-    "Top-Down",
-    "Voxel",
-    "VR",
+    (140, "2.5D", Visuals),
+    (141, "2D", Visuals),
+    (142, "360 Video", Visuals),
+    (143, "3D", Visuals),
+    (144, "3D Vision", Visuals),
+    (145, "Abstract", Visuals),
+    (146, "Anime", Visuals),
+    (147, "Cartoon", Visuals),
+    (148, "Cartoony", Visuals),
+    (149, "Cinematic", Visuals),
+    (150, "Colorful", Visuals),
+    (151, "Comic Book", Visuals),
+    (152, "Cute", Visuals),
+    (153, "First-Person", Visuals),
+    (154, "FMV", Visuals),
+    (155, "Hand-drawn", Visuals),
+    (156, "Isometric", Visuals),
+    (157, "Minimalist", Visuals),
+    (158, "Noir", Visuals),
+    (159, "Pixel Graphics", Visuals),
+    (160, "Psychedelic", Visuals),
+    (161, "Realistic", Visuals),
+    (162, "Split Screen", Visuals),
+    (163, "Stylized", Visuals),
+    (164, "Text-Based", Visuals),
+    (165, "Third Person", Visuals),
+    (166, "Top-Down", Visuals),
+    (167, "Voxel", Visuals),
+    (168, "VR", Visuals),
     // Themes & Moods
-    "1980s",
-    "1990's",
-    "Agriculture",
-    "Aliens",
-    "Alternate History",
-    "America",
-    "Atmospheric",
-    "Assassin",
-    "Bikes",
-    "Capitalism",
-    "Cats",
-    "Cold War",
-    "Comic Book",
-    "Conspiracy",
-    "Crime",
-    "Cyberpunk",
-    "Dark",
-    "Dark Fantasy",
-    "Demons",
-    "Destruction",
-    "Detective",
-    "Dinosaurs",
-    "Diplomacy",
-    "Dog",
-    "Dragons",
-    "Dynamic Narration",
-    "Economy",
-    "Education",
-    "Faith",
-    "Family Friendly",
-    "Fantasy",
-    "Foreign",
-    "Futuristic",
-    "Gambling",
-    "Game Development",
-    "Gothic",
-    "Heist",
-    "Historical",
-    "Horses",
-    "Illuminati",
-    "Investigation",
-    "Jet",
-    "Lemmings",
-    "LGBTQ+",
-    "Logic",
-    "Loot",
-    "Lovecraftian",
-    "Magic",
-    "Management",
-    "Mars",
-    "Mechs",
-    "Medieval",
-    "Memes",
-    "Military",
-    "Modern",
-    "Motorbike",
-    "Mystery",
-    "Mythology",
-    "Nature",
-    "Naval",
-    "Ninja",
-    "Offroad",
-    "Old School",
-    "Otome",
-    "Parkour",
-    "Philosophical",
-    "Pirates",
-    "Political",
-    "Politics",
-    "Pool",
-    "Post-apocalyptic",
-    "Programming",
-    "Retro",
-    "Robots",
-    "Romance",
-    "Rome",
-    "Satire",
-    "Science",
-    "Sci-fi",
-    "Sniper",
-    "Snow",
-    "Space",
-    "Stealth",
-    "Steampunk",
-    "Submarine",
-    "Superhero",
-    "Supernatural",
-    "Surreal",
-    "Survival",
-    "Swordplay",
-    "Tactical",
-    "Tanks",
-    "Thriller",
-    "Time Travel",
-    "Trains",
-    "Transhumanism",
-    "Transportation",
-    "Underground",
-    "Underwater",
-    "Vampire",
-    "War",
-    "Werewolves",
-    "Western",
-    "World War I",
-    "World War II",
+    (169, "1980s", Theme),
+    (170, "1990's", Theme),
+    (171, "Agriculture", Theme),
+    (172, "Aliens", Theme),
+    (173, "Alternate History", Theme),
+    (174, "America", Theme),
+    (175, "Atmospheric", Theme),
+    (176, "Assassin", Theme),
+    (177, "Bikes", Theme),
+    (178, "Capitalism", Theme),
+    (179, "Cats", Theme),
+    (180, "Cold War", Theme),
+    (182, "Conspiracy", Theme),
+    (183, "Crime", Theme),
+    (184, "Cyberpunk", Theme),
+    (185, "Dark", Theme),
+    (186, "Dark Fantasy", Theme),
+    (187, "Demons", Theme),
+    (188, "Destruction", Theme),
+    (189, "Detective", Theme),
+    (190, "Dinosaurs", Theme),
+    (192, "Dog", Theme),
+    (193, "Dragons", Theme),
+    (194, "Dynamic Narration", Theme),
+    (195, "Economy", Theme),
+    (197, "Faith", Theme),
+    (198, "Family Friendly", Theme),
+    (199, "Fantasy", Theme),
+    (200, "Foreign", Theme),
+    (201, "Futuristic", Theme),
+    (202, "Gambling", Theme),
+    (203, "Game Development", Theme),
+    (204, "Gothic", Theme),
+    (206, "Historical", Theme),
+    (207, "Horses", Theme),
+    (208, "Illuminati", Theme),
+    (210, "Jet", Theme),
+    (211, "Lemmings", Theme),
+    (212, "LGBTQ+", Theme),
+    (213, "Logic", Theme),
+    (214, "Loot", Theme),
+    (215, "Lovecraftian", Theme),
+    (216, "Magic", Theme),
+    (218, "Mars", Theme),
+    (219, "Mechs", Theme),
+    (220, "Medieval", Theme),
+    (221, "Memes", Theme),
+    (222, "Military", Theme),
+    (223, "Modern", Theme),
+    (224, "Motorbike", Theme),
+    (225, "Mystery", Theme),
+    (226, "Mythology", Theme),
+    (227, "Nature", Theme),
+    (228, "Naval", Theme),
+    (229, "Ninja", Theme),
+    (230, "Offroad", Theme),
+    (231, "Old School", Theme),
+    (232, "Otome", Theme),
+    (233, "Parkour", Theme),
+    (234, "Philosophical", Theme),
+    (235, "Pirates", Theme),
+    (236, "Political", Theme),
+    (237, "Politics", Theme),
+    (238, "Pool", Theme),
+    (239, "Post-apocalyptic", Theme),
+    (241, "Retro", Theme),
+    (242, "Robots", Theme),
+    (243, "Romance", Theme),
+    (244, "Rome", Theme),
+    (245, "Satire", Theme),
+    (246, "Science", Theme),
+    (247, "Sci-fi", Theme),
+    (248, "Sniper", Theme),
+    (249, "Snow", Theme),
+    (250, "Space", Theme),
+    (252, "Steampunk", Theme),
+    (253, "Submarine", Theme),
+    (254, "Superhero", Theme),
+    (255, "Supernatural", Theme),
+    (256, "Surreal", Theme),
+    (258, "Swordplay", Theme),
+    (259, "Tactical", Theme),
+    (260, "Tanks", Theme),
+    (261, "Thriller", Theme),
+    (262, "Time Travel", Theme),
+    (263, "Trains", Theme),
+    (264, "Transhumanism", Theme),
+    (265, "Transportation", Theme),
+    (266, "Underground", Theme),
+    (267, "Underwater", Theme),
+    (268, "Vampire", Theme),
+    (269, "War", Theme),
+    (270, "Werewolves", Theme),
+    (271, "Western", Theme),
+    (272, "World War I", Theme),
+    (273, "World War II", Theme),
     // Features
-    "6DOF",
-    "Archery",
-    "Artificial Intelligence",
-    "Asymmetric VR",
-    "ATV",
-    "Automation",
-    "Base Building",
-    "Boxing",
-    "Building",
-    "Bullet Time",
-    "Character Customization",
-    "Choices Matter",
-    "Class-Based",
-    "Combat",
-    "Conversation",
-    "Crafting",
-    "Deckbuilding",
-    "Driving",
-    "Fishing",
-    "Flight",
-    "FMV",
-    "Grid-Based Movement",
-    "Gun Customization",
-    "Hack and Slash",
-    "Hacking",
-    "Hex Grid",
-    "Hunting",
-    "Inventory Management",
-    "Level Editor",
-    "Linear",
-    "Martial Arts",
-    "Mining",
-    "Moddable",
-    "Multiple Endings",
-    "Music-Based Procedural Generation",
-    "Narration",
-    "Naval Combat",
-    "Nonlinear",
-    "Open World",
-    "Perma Death",
-    "Physics",
-    "Procedural Generation",
-    "PvE",
-    "PvP",
-    "Quick-Time Events",
-    "Resource Management",
-    "Sailing",
-    "Score Attack",
-    "Stealth",
-    "Story Rich",
-    "Tabletop",
-    "Team-Based",
-    "Text-Based",
-    "Time Manipulation",
-    "Trading",
-    "Turn-Based Combat",
-    "Turn-Based Tactics",
-    "Tutorial",
-    "Vehicular Combat",
-    "Female Protagonist",
-    "Silent Protagonist",
-    "Villain Protagonist",
-    "Minigames",
-    "Intentionally Awkward Controls",
+    (274, "6DOF", Feature),
+    (275, "Archery", Feature),
+    (276, "Artificial Intelligence", Feature),
+    (277, "Asymmetric VR", Feature),
+    (278, "ATV", Feature),
+    (279, "Automation", Feature),
+    (281, "Boxing", Feature),
+    (283, "Bullet Time", Feature),
+    (284, "Character Customization", Feature),
+    (285, "Choices Matter", Feature),
+    (286, "Class-Based", Feature),
+    (287, "Combat", Feature),
+    (288, "Conversation", Feature),
+    (289, "Crafting", Feature),
+    (290, "Deckbuilding", Feature),
+    (291, "Driving", Feature),
+    (292, "Fishing", Feature),
+    (295, "Grid-Based Movement", Feature),
+    (296, "Gun Customization", Feature),
+    (299, "Hex Grid", Feature),
+    (300, "Hunting", Feature),
+    (301, "Inventory Management", Feature),
+    (302, "Level Editor", Feature),
+    (303, "Linear", Feature),
+    (304, "Martial Arts", Feature),
+    (306, "Moddable", Feature),
+    (307, "Multiple Endings", Feature),
+    (308, "Music-Based Procedural Generation", Feature),
+    (309, "Narration", Feature),
+    (310, "Naval Combat", Feature),
+    (311, "Nonlinear", Feature),
+    (313, "Perma Death", Feature),
+    (314, "Physics", Feature),
+    (315, "Procedural Generation", Feature),
+    (316, "PvE", Feature),
+    (317, "PvP", Feature),
+    (318, "Quick-Time Events", Feature),
+    (319, "Resource Management", Feature),
+    (320, "Sailing", Feature),
+    (321, "Score Attack", Feature),
+    (323, "Story Rich", Feature),
+    (325, "Team-Based", Feature),
+    (327, "Time Manipulation", Feature),
+    (329, "Turn-Based Combat", Feature),
+    (331, "Tutorial", Feature),
+    (332, "Vehicular Combat", Feature),
+    (333, "Female Protagonist", Feature),
+    (334, "Silent Protagonist", Feature),
+    (335, "Villain Protagonist", Feature),
+    (336, "Minigames", Feature),
+    (337, "Intentionally Awkward Controls", Feature),
     // Players
-    "4 Player Local",
-    "Asynchronous Multiplayer",
-    "Co-op",
-    "Co-op Campaign",
-    "Local Co-Op",
-    "Local Multiplayer",
-    "Massively Multiplayer",
-    "Multiplayer",
-    "Online Co-Op",
-    "Singleplayer",
+    (338, "4 Player Local", Players),
+    (339, "Asynchronous Multiplayer", Players),
+    (340, "Co-op", Players),
+    (341, "Co-op Campaign", Players),
+    (342, "Local Co-Op", Players),
+    (343, "Local Multiplayer", Players),
+    (344, "Massively Multiplayer", Players),
+    (345, "Multiplayer", Players),
+    (346, "Online Co-Op", Players),
+    (347, "Singleplayer", Players),
     // Other Tags
-    "Based on a Novel",
-    "Batman",
-    "Documentary",
-    "Drama",
-    "Dungeons & Dragons",
-    "Episodic",
-    "Experience",
-    "Feature Film",
-    "Games Workshop",
-    "Indie",
-    "Lara Croft",
-    "LEGO",
-    "Mod",
-    "Movie",
-    "Music",
-    "Real-Time",
-    "Real-Time with Pause",
-    "Remake",
-    "Sequel",
-    "Soundtrack",
-    "Star Wars",
-    "Time Attack",
-    "Turn-Based",
-    "Warhammer 40K",
+    (348, "Based on a Novel", Other),
+    (349, "Batman", Other),
+    (350, "Documentary", Other),
+    (351, "Drama", Other),
+    (352, "Dungeons & Dragons", Other),
+    (353, "Episodic", Other),
+    (354, "Experience", Other),
+    (355, "Feature Film", Other),
+    (356, "Games Workshop", Other),
+    (357, "Indie", Other),
+    (358, "Lara Croft", Other),
+    (359, "LEGO", Other),
+    (360, "Mod", Other),
+    (361, "Movie", Other),
+    (362, "Music", Other),
+    (363, "Real-Time", Other),
+    (364, "Real-Time with Pause", Other),
+    (365, "Remake", Other),
+    (366, "Sequel", Other),
+    (367, "Soundtrack", Other),
+    (368, "Star Wars", Other),
+    (369, "Time Attack", Other),
+    (370, "Turn-Based", Other),
+    (371, "Warhammer 40K", Other),
     // Assessments
-    "Addictive",
-    "Beautiful",
-    "Classic",
-    "Competitive",
-    "Cult Classic",
-    "Difficult",
-    "Emotional",
-    "Epic",
-    "Fast-Paced",
-    "Funny",
-    "Great Soundtrack",
-    "Lore-Rich",
-    "Masterpiece",
-    "Psychological",
-    "Relaxing",
-    "Replay Value",
-    "Short",
-    "Unforgiving",
-    "Comedy",
-    "Dark Comedy",
+    (372, "Addictive", Assessment),
+    (373, "Beautiful", Assessment),
+    (374, "Classic", Assessment),
+    (375, "Competitive", Assessment),
+    (376, "Cult Classic", Assessment),
+    (377, "Difficult", Assessment),
+    (378, "Emotional", Assessment),
+    (379, "Epic", Assessment),
+    (380, "Fast-Paced", Assessment),
+    (381, "Funny", Assessment),
+    (382, "Great Soundtrack", Assessment),
+    (383, "Lore-Rich", Assessment),
+    (384, "Masterpiece", Assessment),
+    (385, "Psychological", Assessment),
+    (386, "Relaxing", Assessment),
+    (387, "Replay Value", Assessment),
+    (388, "Short", Assessment),
+    (389, "Unforgiving", Assessment),
+    (390, "Comedy", Assessment),
+    (391, "Dark Comedy", Assessment),
     // Ratings etc
-    "Blood",
-    "Gore",
-    "Mature",
-    "NSFW",
-    "Nudity",
-    "Sexual Content",
-    "Violent",
+    (392, "Blood", Rating),
+    (393, "Gore", Rating),
+    (394, "Mature", Rating),
+    (395, "NSFW", Rating),
+    (396, "Nudity", Rating),
+    (397, "Sexual Content", Rating),
+    (398, "Violent", Rating),
     // Hardware / Input
-    "Controller",
-    "Hardware",
-    "Mouse only",
-    "Steam Machine",
-    "Touch-Friendly",
-    "TrackIR",
-    "Voice Control",
+    (399, "Controller", Hardware),
+    (400, "Hardware", Hardware),
+    (401, "Mouse only", Hardware),
+    (402, "Steam Machine", Hardware),
+    (403, "Touch-Friendly", Hardware),
+    (404, "TrackIR", Hardware),
+    (405, "Voice Control", Hardware),
 ];
+
+/// The ids of the tags which have been removed from `TAGS` as duplicates, along with the id of
+/// the tag they duplicated. These ids are never reused, as they may still be stored by chains
+/// which were started with the duplicates.
+pub const RETIRED_TAGS: &[(TagId, TagId)] = &[
+    (31, 3),    // Experimental
+    (181, 151), // Comic Book
+    (191, 29),  // Diplomacy
+    (196, 97),  // Education
+    (205, 102), // Heist
+    (209, 106), // Investigation
+    (217, 43),  // Management
+    (240, 116), // Programming
+    (251, 68),  // Stealth
+    (257, 70),  // Survival
+    (280, 16),  // Base Building
+    (282, 23),  // Building
+    (293, 98),  // Flight
+    (294, 154), // FMV
+    (297, 101), // Hack and Slash
+    (298, 38),  // Hacking
+    (305, 47),  // Mining
+    (312, 51),  // Open World
+    (322, 68),  // Stealth
+    (324, 10),  // Tabletop
+    (326, 164), // Text-Based
+    (328, 133), // Trading
+    (330, 136), // Turn-Based Tactics
+];
+
+/// Returns whether two byte strings are equal, in a const context.
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns whether a tag id is used by one of the given tags.
+const fn contains_id(tags: &[(TagId, &str, TagCategory)], id: TagId) -> bool {
+    let mut i = 0;
+    while i < tags.len() {
+        if tags[i].0 == id {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Checks the predefined tags, panicking on the first invalid entry.
+const fn check_tags(tags: &[(TagId, &str, TagCategory)], retired: &[(TagId, TagId)]) {
+    let mut i = 0;
+    while i < tags.len() {
+        let (id, name, _) = tags[i];
+        assert!(!name.is_empty(), "A tag name is empty");
+        assert!(name.len() <= MAX_TAG_SIZE as usize, "A tag name exceeds MAX_TAG_SIZE");
+        let mut j = i + 1;
+        while j < tags.len() {
+            assert!(tags[j].0 != id, "Two tags have the same id");
+            assert!(
+                !bytes_eq(name.as_bytes(), tags[j].1.as_bytes()),
+                "Two tags have the same name"
+            );
+            j += 1;
+        }
+        i += 1;
+    }
+
+    let mut i = 0;
+    while i < retired.len() {
+        assert!(!contains_id(tags, retired[i].0), "A retired tag id is reused");
+        assert!(contains_id(tags, retired[i].1), "A retired tag duplicates an unknown tag");
+        i += 1;
+    }
+}
+
+const _: () = check_tags(TAGS, RETIRED_TAGS);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_tags() {
+        check_tags(&[(0, "Action", TopLevelGenre), (2, "Adventure", TopLevelGenre)], &[(1, 0)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "A tag name is empty")]
+    fn test_check_tags_empty_name() {
+        check_tags(&[(0, "", Other)], &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "A tag name exceeds MAX_TAG_SIZE")]
+    fn test_check_tags_long_name() {
+        check_tags(&[(0, "A tag name which is much too long to fit in the storage", Other)], &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Two tags have the same id")]
+    fn test_check_tags_duplicate_id() {
+        check_tags(&[(0, "Action", TopLevelGenre), (0, "Adventure", TopLevelGenre)], &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Two tags have the same name")]
+    fn test_check_tags_duplicate_name() {
+        check_tags(&[(0, "Stealth", Genre), (1, "Stealth", Theme)], &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "A retired tag id is reused")]
+    fn test_check_tags_reused_id() {
+        check_tags(&[(0, "Action", TopLevelGenre), (1, "Adventure", TopLevelGenre)], &[(1, 0)]);
+    }
+}
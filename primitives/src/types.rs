@@ -1,11 +1,18 @@
-use crate::{validate, MAX_CID_SIZE, MAX_NAME_SIZE, MAX_TAGS_PER_GAME, MAX_TAG_SIZE, MAX_URL_SIZE};
+use crate::{
+    validate, MAX_CID_SIZE, MAX_EMAIL_SIZE, MAX_ENCRYPTED_CID_SIZE, MAX_NAME_SIZE, MAX_REASON_SIZE,
+    MAX_TAGS_PER_GAME, MAX_TAG_SIZE, MAX_URL_SIZE,
+};
 use frame_support::pallet_prelude::*;
+use serde::{Deserialize, Serialize};
 
 pub type Name = BoundedVec<u8, ConstU32<MAX_NAME_SIZE>>;
 pub type Url = BoundedVec<u8, ConstU32<MAX_URL_SIZE>>;
+pub type Email = BoundedVec<u8, ConstU32<MAX_EMAIL_SIZE>>;
 pub type Tag = BoundedVec<u8, ConstU32<MAX_TAG_SIZE>>;
 pub type GameTags = BoundedVec<TagId, ConstU32<MAX_TAGS_PER_GAME>>;
 pub type Cid = BoundedVec<u8, ConstU32<MAX_CID_SIZE>>;
+pub type EncryptedCid = BoundedVec<u8, ConstU32<MAX_ENCRYPTED_CID_SIZE>>;
+pub type ReasonMessage = BoundedVec<u8, ConstU32<MAX_REASON_SIZE>>;
 
 pub type GameId = u16;
 pub type GlobalGameId<T> = (PublisherId<T>, GameId);
@@ -16,24 +23,163 @@ pub type PublisherId<T> = AccountIdOf<T>;
 pub type BuyerId<T> = AccountIdOf<T>;
 
 #[derive(
-    Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
+    Default,
+    Clone,
+    Eq,
+    PartialEq,
+    Debug,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    Serialize,
+    Deserialize,
 )]
 pub struct PublisherDetails {
     /// The name of the publisher
     pub name: Name,
     /// The URL of the publisher
     pub url: Url,
+    /// The CID of the logo of the publisher
+    pub logo: Option<Cid>,
+    /// The CID of the description of the publisher
+    pub description: Option<Cid>,
+    /// The contact email of the publisher
+    pub email: Option<Email>,
 }
 
 impl PublisherDetails {
     pub fn is_valid(&self) -> bool {
-        validate::is_non_empty_string(&self.name) && validate::is_url(&self.url)
+        validate::is_non_empty_string(&self.name) &&
+            validate::is_url(&self.url) &&
+            self.logo.as_ref().is_none_or(|cid| validate::is_cid(cid)) &&
+            self.description.as_ref().is_none_or(|cid| validate::is_cid(cid)) &&
+            self.email.as_ref().is_none_or(|email| validate::is_email(email))
+    }
+
+    /// Returns the total length of the optional fields, which the weight of storing the details
+    /// depends on.
+    pub fn optional_fields_len(&self) -> u32 {
+        let len = |field: Option<usize>| field.unwrap_or_default() as u32;
+        len(self.logo.as_ref().map(|cid| cid.len())) +
+            len(self.description.as_ref().map(|cid| cid.len())) +
+            len(self.email.as_ref().map(|email| email.len()))
+    }
+}
+
+#[derive(
+    Default,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Debug,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub enum PublisherStatus {
+    /// The publisher can add and sell games
+    #[default]
+    Active,
+    /// The publisher is frozen, e.g. after being slashed or during a dispute
+    Suspended,
+}
+
+impl PublisherStatus {
+    pub fn is_active(&self) -> bool {
+        *self == PublisherStatus::Active
     }
 }
 
 #[derive(
     Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
 )]
+pub enum VerificationEvidence {
+    /// The publisher proved the ownership of the domain of their URL, e.g. with a DNS record. The
+    /// CID points to the proof which has been checked.
+    Domain { url: Url, proof: Cid },
+    /// The CID of any other document attesting the identity of the publisher
+    Document(Cid),
+}
+
+impl VerificationEvidence {
+    pub fn is_valid(&self) -> bool {
+        match self {
+            VerificationEvidence::Domain { url, proof } => {
+                validate::is_url(url) && validate::is_cid(proof)
+            },
+            VerificationEvidence::Document(cid) => validate::is_cid(cid),
+        }
+    }
+
+    /// Returns whether the evidence still holds for the given publisher details. A domain proof
+    /// only holds as long as the URL of the publisher is on the same host.
+    pub fn applies_to(&self, details: &PublisherDetails) -> bool {
+        match self {
+            VerificationEvidence::Domain { url, .. } => validate::is_same_host(url, &details.url),
+            VerificationEvidence::Document(_) => true,
+        }
+    }
+}
+
+#[derive(
+    Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
+)]
+#[scale_info(skip_type_params(BlockNumber))]
+pub struct VerificationDetails<BlockNumber> {
+    /// The evidence the publisher has been verified with
+    pub evidence: VerificationEvidence,
+    /// The block at which the publisher has been verified
+    pub verified_at: BlockNumber,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Debug,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub enum PublisherRole {
+    /// The member may act on behalf of the publisher in every role
+    Admin,
+    /// The member manages the games and the releases of the publisher
+    CatalogManager,
+    /// The member fulfills and rejects the orders of the publisher
+    OrderFulfiller,
+    /// The member manages the deposit and the refund policies of the publisher
+    Finance,
+}
+
+impl PublisherRole {
+    /// Returns whether a member with this role may act with the given role.
+    pub fn allows(&self, role: &PublisherRole) -> bool {
+        *self == PublisherRole::Admin || self == role
+    }
+}
+
+#[derive(
+    Clone,
+    Eq,
+    PartialEq,
+    Debug,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
 #[scale_info(skip_type_params(Currency))]
 pub enum Distribution<Currency> {
     /// The game is distributed free of charge
@@ -69,7 +215,17 @@ impl<Currency: Zero> Distribution<Currency> {
 }
 
 #[derive(
-    Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
+    Clone,
+    Eq,
+    PartialEq,
+    Debug,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    Serialize,
+    Deserialize,
 )]
 #[scale_info(skip_type_params(Currency))]
 pub struct GameDetails<Currency> {
@@ -89,11 +245,338 @@ impl<Currency: Zero> GameDetails<Currency> {
     }
 }
 
-#[derive(Default, Clone, Eq, PartialEq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+impl<Currency> GameDetails<Currency> {
+    /// Replaces the `from` tag with the `into` tag, keeping a single `into` tag when the game
+    /// already has both. Returns whether the tags changed.
+    pub fn merge_tag(&mut self, from: TagId, into: TagId) -> bool {
+        if !self.tags.contains(&from) {
+            return false;
+        }
+        if self.tags.contains(&into) {
+            self.tags.retain(|tag| *tag != from);
+        } else {
+            self.tags.iter_mut().filter(|tag| **tag == from).for_each(|tag| *tag = into);
+        }
+        true
+    }
+}
+
+#[derive(
+    Default,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Debug,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub enum TagStatus {
+    /// The tag can be given to games
+    #[default]
+    Active,
+    /// The tag cannot be given to games anymore, but the games which have it keep it
+    Deprecated,
+}
+
+#[derive(
+    Default,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Debug,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub enum TagCategory {
+    /// A broad genre, such as action or puzzle
+    TopLevelGenre,
+    /// A genre, such as platformer or roguelike
+    Genre,
+    /// A narrower genre, such as 2D platformer
+    SubGenre,
+    /// The visual style and the viewpoint
+    Visuals,
+    /// The themes and the moods
+    Theme,
+    /// The gameplay features
+    Feature,
+    /// The player modes
+    Players,
+    /// The tags which fit no other category
+    #[default]
+    Other,
+    /// The assessments, such as great soundtrack
+    Assessment,
+    /// The content ratings
+    Rating,
+    /// The hardware and the input devices
+    Hardware,
+}
+
+impl TagCategory {
+    /// Returns whether the tags of the category describe the genre of a game.
+    pub fn is_genre(&self) -> bool {
+        matches!(self, TagCategory::TopLevelGenre | TagCategory::Genre | TagCategory::SubGenre)
+    }
+}
+
+#[derive(
+    Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
+)]
+pub struct TagDetails {
+    /// The name of the tag
+    pub name: Tag,
+    /// Whether the tag can be given to games
+    pub status: TagStatus,
+    /// The category of the tag
+    pub category: TagCategory,
+    /// The broader tag this tag refines, if any
+    pub parent: Option<TagId>,
+}
+
+impl TagDetails {
+    pub fn new(name: Tag, category: TagCategory, parent: Option<TagId>) -> Self {
+        Self { name, status: TagStatus::Active, category, parent }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status == TagStatus::Active
+    }
+
+    pub fn is_valid(&self) -> bool {
+        validate::is_non_empty_string(&self.name)
+    }
+}
+
+#[derive(
+    Default,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Debug,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub enum GameStatus {
+    /// The game is listed and can be purchased
+    #[default]
+    Active,
+    /// The game is not listed and cannot be purchased, e.g. before its release
+    Hidden,
+    /// The game has been withdrawn from sale, but can be listed again
+    Delisted,
+    /// The game has been permanently withdrawn from sale
+    Retired,
+}
+
+impl GameStatus {
+    pub fn is_active(&self) -> bool {
+        *self == GameStatus::Active
+    }
+
+    /// A game can move between any two statuses, except that a retired game stays retired.
+    pub fn can_transition_to(&self, next: &GameStatus) -> bool {
+        self != next && *self != GameStatus::Retired
+    }
+}
+
+#[derive(
+    Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
+)]
 #[scale_info(skip_type_params(Currency))]
-pub struct OrderDetails<Currency> {
+pub struct GameDetailsUpdate<Currency> {
+    /// The new name of the game, if it changes
+    pub name: Option<Name>,
+    /// The new tags of the game, if they change
+    pub tags: Option<GameTags>,
+    /// The new way the game is distributed, if it changes
+    pub distribution: Option<Distribution<Currency>>,
+}
+
+impl<Currency: Clone + PartialEq> GameDetailsUpdate<Currency> {
+    /// Applies the update to the given details. Returns the fields which actually changed.
+    pub fn apply(self, details: &mut GameDetails<Currency>) -> GameDetailsUpdate<Currency> {
+        let name = self.name.filter(|name| *name != details.name);
+        let tags = self.tags.filter(|tags| *tags != details.tags);
+        let distribution = self.distribution.filter(|dist| *dist != details.distribution);
+
+        if let Some(name) = &name {
+            details.name = name.clone();
+        }
+        if let Some(tags) = &tags {
+            details.tags = tags.clone();
+        }
+        if let Some(distribution) = &distribution {
+            details.distribution = distribution.clone();
+        }
+
+        GameDetailsUpdate { name, tags, distribution }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none() && self.tags.is_none() && self.distribution.is_none()
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Debug,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub struct SemVer {
+    /// The major version, increased on incompatible changes
+    pub major: u16,
+    /// The minor version, increased on backwards compatible features
+    pub minor: u16,
+    /// The patch version, increased on backwards compatible fixes
+    pub patch: u16,
+}
+
+impl SemVer {
+    pub fn new(major: u16, minor: u16, patch: u16) -> Self {
+        SemVer { major, minor, patch }
+    }
+}
+
+#[derive(
+    Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
+)]
+#[scale_info(skip_type_params(BlockNumber))]
+pub struct ReleaseDetails<BlockNumber> {
+    /// The CID of the game build
+    pub cid: Cid,
+    /// The CID of the changelog
+    pub changelog: Cid,
+    /// The block at which the release was published
+    pub released_at: BlockNumber,
+    /// Whether the release has been yanked
+    pub yanked: bool,
+    /// The previous release which is not yanked
+    pub previous: Option<SemVer>,
+    /// The next release which is not yanked
+    pub next: Option<SemVer>,
+}
+
+impl<BlockNumber> ReleaseDetails<BlockNumber> {
+    pub fn is_valid(&self) -> bool {
+        validate::is_cid(&self.cid) && validate::is_cid(&self.changelog)
+    }
+}
+
+#[derive(
+    Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
+)]
+pub enum Delivery {
+    /// The CID of the game in plain form
+    Plain(Cid),
+    /// The CID of the game encrypted to the buyer's public key. Only the buyer can decrypt it.
+    Encrypted(EncryptedCid),
+}
+
+impl Delivery {
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Delivery::Plain(cid) => validate::is_cid(cid),
+            Delivery::Encrypted(encrypted) => !encrypted.is_empty(),
+        }
+    }
+}
+
+#[derive(
+    Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
+)]
+pub enum RejectionReason {
+    /// The game is not available in the buyer's region
+    RegionUnsupported,
+    /// The game is out of stock, e.g. there are no keys left
+    OutOfStock,
+    /// Any other reason, explained by a message
+    Other(ReasonMessage),
+}
+
+impl RejectionReason {
+    pub fn is_valid(&self) -> bool {
+        match self {
+            RejectionReason::Other(message) => validate::is_non_empty_string(message),
+            _ => true,
+        }
+    }
+}
+
+#[derive(Default, Clone, Eq, PartialEq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(Currency, BlockNumber))]
+pub struct OrderDetails<Currency, BlockNumber> {
     /// The deposit held from the buyer
     pub deposit: Currency,
+    /// The block at which the order expires and the deposit can be released
+    pub expires_at: BlockNumber,
+}
+
+impl<Currency, BlockNumber: PartialOrd> OrderDetails<Currency, BlockNumber> {
+    /// Returns whether the order has expired at the given block.
+    pub fn is_expired(&self, now: &BlockNumber) -> bool {
+        *now >= self.expires_at
+    }
+}
+
+#[derive(
+    Clone, Eq, PartialEq, Debug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo,
+)]
+#[scale_info(skip_type_params(BlockNumber))]
+pub struct RefundPolicy<BlockNumber> {
+    /// The number of blocks after a purchase during which the buyer can ask for a refund
+    pub window: BlockNumber,
+    /// The maximum number of downloads reported by the buyer for a refund to be granted
+    pub max_downloads: u32,
+}
+
+impl<BlockNumber: Zero> RefundPolicy<BlockNumber> {
+    pub fn is_valid(&self) -> bool {
+        !self.window.is_zero()
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(Currency, BlockNumber))]
+pub struct PurchaseDetails<Currency, BlockNumber> {
+    /// The payment held for the publisher until the purchase is settled
+    pub payment: Currency,
+    /// The block from which the purchase can no longer be refunded
+    pub refundable_until: BlockNumber,
+    /// The maximum number of downloads for a refund, as set by the policy at purchase time
+    pub max_downloads: u32,
+}
+
+impl<Currency, BlockNumber: PartialOrd> PurchaseDetails<Currency, BlockNumber> {
+    /// Returns whether the purchase can still be refunded at the given block.
+    pub fn is_refundable(&self, now: &BlockNumber) -> bool {
+        *now < self.refundable_until
+    }
 }
 
 #[cfg(test)]
@@ -106,23 +589,44 @@ mod tests {
         let details = PublisherDetails {
             name: bounded_vec(b"Example Publisher"),
             url: bounded_vec(b"https://example.com"),
+            ..Default::default()
         };
 
         assert!(details.is_valid());
     }
 
+    #[test]
+    fn test_publisher_details_optional_fields_len() {
+        let mut details = PublisherDetails {
+            name: bounded_vec(b"Example Publisher"),
+            url: bounded_vec(b"https://example.com"),
+            ..Default::default()
+        };
+        assert_eq!(details.optional_fields_len(), 0);
+
+        details.logo = Some(bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"));
+        details.email = Some(bounded_vec(b"contact@example.com"));
+        assert_eq!(details.optional_fields_len(), 46 + 19);
+    }
+
     #[test]
     fn test_publisher_details_name_is_empty() {
-        let details =
-            PublisherDetails { name: bounded_vec(b""), url: bounded_vec(b"https://example.com") };
+        let details = PublisherDetails {
+            name: bounded_vec(b""),
+            url: bounded_vec(b"https://example.com"),
+            ..Default::default()
+        };
 
         assert!(!details.is_valid());
     }
 
     #[test]
     fn test_publisher_details_url_is_empty() {
-        let details =
-            PublisherDetails { name: bounded_vec(b"Example Publisher"), url: bounded_vec(b"") };
+        let details = PublisherDetails {
+            name: bounded_vec(b"Example Publisher"),
+            url: bounded_vec(b""),
+            ..Default::default()
+        };
 
         assert!(!details.is_valid());
     }
@@ -132,11 +636,77 @@ mod tests {
         let details = PublisherDetails {
             name: bounded_vec(b"Example Publisher"),
             url: bounded_vec(b"wrong url"),
+            ..Default::default()
         };
 
         assert!(!details.is_valid());
     }
 
+    #[test]
+    fn test_publisher_details_optional_fields() {
+        let details = PublisherDetails {
+            name: bounded_vec(b"Example Publisher"),
+            url: bounded_vec(b"https://example.com"),
+            logo: Some(bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX")),
+            description: Some(bounded_vec(b"QmRJzSVrU5kMkXzDCrePyx3TX7gGu8cXsogX5xLyfMuNPG")),
+            email: Some(bounded_vec(b"contact@example.com")),
+        };
+        assert!(details.is_valid());
+
+        let invalid_logo =
+            PublisherDetails { logo: Some(bounded_vec(b"NotARealCID123")), ..details.clone() };
+        assert!(!invalid_logo.is_valid());
+
+        let invalid_description =
+            PublisherDetails { description: Some(bounded_vec(b"")), ..details.clone() };
+        assert!(!invalid_description.is_valid());
+
+        let invalid_email = PublisherDetails { email: Some(bounded_vec(b"contact")), ..details };
+        assert!(!invalid_email.is_valid());
+    }
+
+    #[test]
+    fn test_verification_evidence_is_valid() {
+        let domain = VerificationEvidence::Domain {
+            url: bounded_vec(b"https://example.com"),
+            proof: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+        };
+        assert!(domain.is_valid());
+        assert!(VerificationEvidence::Document(bounded_vec(
+            b"QmRJzSVrU5kMkXzDCrePyx3TX7gGu8cXsogX5xLyfMuNPG"
+        ))
+        .is_valid());
+
+        let invalid_url = VerificationEvidence::Domain {
+            url: bounded_vec(b"example.com"),
+            proof: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+        };
+        assert!(!invalid_url.is_valid());
+        assert!(!VerificationEvidence::Document(bounded_vec(b"NotARealCID123")).is_valid());
+    }
+
+    #[test]
+    fn test_verification_evidence_applies_to() {
+        let details = PublisherDetails {
+            name: bounded_vec(b"Example Publisher"),
+            url: bounded_vec(b"https://example.com/studio"),
+            ..Default::default()
+        };
+        let proof = bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX");
+
+        let domain =
+            VerificationEvidence::Domain { url: bounded_vec(b"https://example.com"), proof };
+        assert!(domain.applies_to(&details));
+        assert!(!domain.applies_to(&PublisherDetails {
+            url: bounded_vec(b"https://impersonator.com"),
+            ..details.clone()
+        }));
+        assert!(VerificationEvidence::Document(bounded_vec(
+            b"QmRJzSVrU5kMkXzDCrePyx3TX7gGu8cXsogX5xLyfMuNPG"
+        ))
+        .applies_to(&details));
+    }
+
     #[test]
     fn test_game_details_is_valid() {
         let details = GameDetails {
@@ -207,4 +777,187 @@ mod tests {
 
         assert!(!details.is_valid(|_| true));
     }
+
+    #[test]
+    fn test_game_details_merge_tag() {
+        let mut details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: bounded_vec(&[1, 2, 3]),
+            distribution: Distribution::Individual { price: 1234 },
+        };
+
+        assert!(details.merge_tag(2, 4));
+        assert_eq!(details.tags.to_vec(), vec![1, 4, 3]);
+        assert!(details.merge_tag(1, 3));
+        assert_eq!(details.tags.to_vec(), vec![4, 3]);
+        assert!(!details.merge_tag(1, 3));
+        assert_eq!(details.tags.to_vec(), vec![4, 3]);
+    }
+
+    #[test]
+    fn test_tag_details() {
+        let mut tag = TagDetails::new(bounded_vec(b"Action"), TagCategory::TopLevelGenre, None);
+        assert!(tag.is_valid());
+        assert!(tag.is_active());
+
+        tag.status = TagStatus::Deprecated;
+        assert!(!tag.is_active());
+
+        assert!(!TagDetails::new(bounded_vec(b""), TagCategory::Genre, None).is_valid());
+        assert!(!TagDetails::new(bounded_vec(&[0xff, 0xfe]), TagCategory::Genre, None).is_valid());
+    }
+
+    #[test]
+    fn test_tag_category_is_genre() {
+        assert!(TagCategory::TopLevelGenre.is_genre());
+        assert!(TagCategory::Genre.is_genre());
+        assert!(TagCategory::SubGenre.is_genre());
+        assert!(!TagCategory::Theme.is_genre());
+        assert!(!TagCategory::Other.is_genre());
+    }
+
+    #[test]
+    fn test_publisher_role_allows() {
+        assert!(PublisherRole::Admin.allows(&PublisherRole::CatalogManager));
+        assert!(PublisherRole::Admin.allows(&PublisherRole::Admin));
+        assert!(PublisherRole::Finance.allows(&PublisherRole::Finance));
+
+        assert!(!PublisherRole::CatalogManager.allows(&PublisherRole::OrderFulfiller));
+        assert!(!PublisherRole::OrderFulfiller.allows(&PublisherRole::Admin));
+    }
+
+    #[test]
+    fn test_game_status_transitions() {
+        assert!(GameStatus::Active.can_transition_to(&GameStatus::Hidden));
+        assert!(GameStatus::Hidden.can_transition_to(&GameStatus::Delisted));
+        assert!(GameStatus::Delisted.can_transition_to(&GameStatus::Active));
+        assert!(GameStatus::Delisted.can_transition_to(&GameStatus::Retired));
+
+        assert!(!GameStatus::Active.can_transition_to(&GameStatus::Active));
+        assert!(!GameStatus::Retired.can_transition_to(&GameStatus::Active));
+        assert!(!GameStatus::Retired.can_transition_to(&GameStatus::Retired));
+    }
+
+    #[test]
+    fn test_game_details_update_apply() {
+        let mut details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: bounded_vec(&[1, 2, 3]),
+            distribution: Distribution::Individual { price: 1234 },
+        };
+        let update = GameDetailsUpdate {
+            name: Some(bounded_vec(b"Example Game")),
+            tags: Some(bounded_vec(&[4])),
+            distribution: Some(Distribution::Individual { price: 4321 }),
+        };
+
+        let diff = update.apply(&mut details);
+
+        // the name did not change, so it is not part of the diff
+        assert_eq!(
+            diff,
+            GameDetailsUpdate {
+                name: None,
+                tags: Some(bounded_vec(&[4])),
+                distribution: Some(Distribution::Individual { price: 4321 }),
+            }
+        );
+        assert_eq!(details.name.as_slice(), b"Example Game");
+        assert_eq!(details.tags.as_slice(), &[4]);
+        assert_eq!(details.distribution, Distribution::Individual { price: 4321 });
+    }
+
+    #[test]
+    fn test_game_details_update_is_empty() {
+        let mut details = GameDetails {
+            name: bounded_vec(b"Example Game"),
+            tags: bounded_vec(&[1, 2, 3]),
+            distribution: Distribution::Individual { price: 1234 },
+        };
+        let update = GameDetailsUpdate {
+            name: Some(bounded_vec(b"Example Game")),
+            tags: None,
+            distribution: None,
+        };
+
+        assert!(!update.is_empty());
+        assert!(update.apply(&mut details).is_empty());
+    }
+
+    #[test]
+    fn test_semver_ordering() {
+        assert!(SemVer::new(1, 0, 0) > SemVer::new(0, 9, 9));
+        assert!(SemVer::new(1, 2, 0) > SemVer::new(1, 1, 9));
+        assert!(SemVer::new(1, 2, 3) > SemVer::new(1, 2, 2));
+        assert_eq!(SemVer::new(1, 2, 3), SemVer { major: 1, minor: 2, patch: 3 });
+    }
+
+    #[test]
+    fn test_release_details_is_valid() {
+        let release = ReleaseDetails {
+            cid: bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+            changelog: bounded_vec(b"QmRJzSVrU5kMkXzDCrePyx3TX7gGu8cXsogX5xLyfMuNPG"),
+            released_at: 1u32,
+            yanked: false,
+            previous: None,
+            next: None,
+        };
+        assert!(release.is_valid());
+
+        let release = ReleaseDetails { changelog: bounded_vec(b"NotARealCID123"), ..release };
+        assert!(!release.is_valid());
+    }
+
+    #[test]
+    fn test_delivery_is_valid() {
+        let plain = Delivery::Plain(bounded_vec(b"QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"));
+        let encrypted = Delivery::Encrypted(bounded_vec(&[0xde, 0xad, 0xbe, 0xef]));
+
+        assert!(plain.is_valid());
+        assert!(encrypted.is_valid());
+    }
+
+    #[test]
+    fn test_delivery_is_invalid() {
+        let plain = Delivery::Plain(bounded_vec(b"NotARealCID123"));
+        let encrypted = Delivery::Encrypted(bounded_vec(&[]));
+
+        assert!(!plain.is_valid());
+        assert!(!encrypted.is_valid());
+    }
+
+    #[test]
+    fn test_order_details_is_expired() {
+        let order = OrderDetails { deposit: 100u64, expires_at: 10u32 };
+
+        assert!(!order.is_expired(&9));
+        assert!(order.is_expired(&10));
+        assert!(order.is_expired(&11));
+    }
+
+    #[test]
+    fn test_rejection_reason_is_valid() {
+        assert!(RejectionReason::RegionUnsupported.is_valid());
+        assert!(RejectionReason::OutOfStock.is_valid());
+        assert!(RejectionReason::Other(bounded_vec(b"Keys are being restocked")).is_valid());
+
+        assert!(!RejectionReason::Other(bounded_vec(b"")).is_valid());
+        assert!(!RejectionReason::Other(bounded_vec(&[0xff, 0xfe])).is_valid());
+    }
+
+    #[test]
+    fn test_refund_policy_is_valid() {
+        assert!(RefundPolicy { window: 100u32, max_downloads: 0 }.is_valid());
+        assert!(!RefundPolicy { window: 0u32, max_downloads: 3 }.is_valid());
+    }
+
+    #[test]
+    fn test_purchase_details_is_refundable() {
+        let purchase =
+            PurchaseDetails { payment: 100u64, refundable_until: 10u32, max_downloads: 1 };
+
+        assert!(purchase.is_refundable(&9));
+        assert!(!purchase.is_refundable(&10));
+        assert!(!purchase.is_refundable(&11));
+    }
 }
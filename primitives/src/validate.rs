@@ -24,6 +24,34 @@ pub fn is_url(url: &[u8]) -> bool {
     }
 }
 
+pub fn is_same_host(url: &[u8], other: &[u8]) -> bool {
+    let host = |url: &[u8]| {
+        core::str::from_utf8(url)
+            .ok()
+            .and_then(|s| Url::parse(s).ok())
+            .and_then(|url| url.host_str().map(|host| host.to_ascii_lowercase()))
+    };
+    match (host(url), host(other)) {
+        (Some(host), Some(other)) => host == other,
+        _ => false,
+    }
+}
+
+pub fn is_email(email: &[u8]) -> bool {
+    match core::str::from_utf8(email) {
+        Ok(s) => match s.split_once('@') {
+            Some((local, domain)) => {
+                !local.is_empty() &&
+                    !domain.contains('@') &&
+                    domain.split('.').count() > 1 &&
+                    domain.split('.').all(|label| !label.is_empty())
+            },
+            None => false,
+        },
+        Err(_) => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,4 +98,26 @@ mod tests {
         assert!(!is_url(b"example.com"));
         assert!(!is_url(b""));
     }
+
+    #[test]
+    fn test_is_same_host() {
+        assert!(is_same_host(b"https://example.com", b"https://example.com/games"));
+        assert!(is_same_host(b"https://example.com", b"http://EXAMPLE.com"));
+        assert!(!is_same_host(b"https://example.com", b"https://www.example.com"));
+        assert!(!is_same_host(b"https://example.com", b"https://example.org"));
+        assert!(!is_same_host(b"https://example.com", b"example.com"));
+        assert!(!is_same_host(b"", b""));
+    }
+
+    #[test]
+    fn test_is_email() {
+        assert!(is_email(b"contact@example.com"));
+        assert!(is_email(b"first.last@studio.example.org"));
+        assert!(!is_email(b"contact"));
+        assert!(!is_email(b"@example.com"));
+        assert!(!is_email(b"contact@example"));
+        assert!(!is_email(b"contact@@example.com"));
+        assert!(!is_email(b"contact@example..com"));
+        assert!(!is_email(b""));
+    }
 }
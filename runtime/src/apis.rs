@@ -1,9 +1,14 @@
 // External crates imports
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 use frame_support::{
     genesis_builder_helper::{build_state, get_preset},
     weights::Weight,
 };
+use liganite_games_runtime_api::{
+    GameDetails, GameId, GameInfo, OrderInfo, ReleaseInfo, TagCategory, TagGroup, TagId, TagInfo,
+    MAX_PAGE_SIZE,
+};
+use liganite_publish_runtime_api::{PublisherInfo, VerificationDetails};
 use pallet_grandpa::AuthorityId as GrandpaId;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -17,10 +22,39 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-    RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+    AccountId, Aura, Balance, Block, BlockNumber, Executive, Games, Grandpa, InherentDataExt,
+    Nonce, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment,
+    VERSION,
 };
 
+type GameInfoOf = GameInfo<AccountId, Balance, BlockNumber>;
+
+/// Gathers the store front view of a game from the storage of the games pallet.
+fn game_info(publisher: AccountId, game_id: GameId, details: GameDetails<Balance>) -> GameInfoOf {
+    let tags = details.tags.iter().filter_map(|&id| tag_info(id)).collect();
+    let latest_release =
+        Games::latest_release(&publisher, game_id).map(|(version, release)| ReleaseInfo {
+            version,
+            cid: release.cid,
+            changelog: release.changelog,
+            released_at: release.released_at,
+        });
+    GameInfo {
+        status: liganite_games::GameStatuses::<Runtime>::get(&publisher, game_id),
+        refund_policy: liganite_games::RefundPolicies::<Runtime>::get(&publisher, game_id),
+        publisher,
+        game_id,
+        name: details.name,
+        tags,
+        distribution: details.distribution,
+        latest_release,
+    }
+}
+
+fn tag_info(id: TagId) -> Option<TagInfo> {
+    liganite_games::Tags::<Runtime>::get(id).map(|tag| TagInfo::new(id, tag))
+}
+
 impl_runtime_apis! {
     impl sp_api::Core<Block> for Runtime {
         fn version() -> RuntimeVersion {
@@ -195,6 +229,96 @@ impl_runtime_apis! {
         }
     }
 
+    impl liganite_publish_runtime_api::PublishApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn publisher(publisher: AccountId) -> Option<PublisherInfo<AccountId, Balance, BlockNumber>> {
+            let publisher =
+                liganite_publish::Successors::<Runtime>::get(&publisher).unwrap_or(publisher);
+            let details = liganite_publish::Publishers::<Runtime>::get(&publisher)?;
+            Some(PublisherInfo {
+                details,
+                status: liganite_publish::PublisherStatuses::<Runtime>::get(&publisher),
+                deposit: liganite_publish::PublisherDeposits::<Runtime>::get(&publisher),
+                verification: liganite_publish::Verifications::<Runtime>::get(&publisher),
+                previous: liganite_publish::PublisherLineage::<Runtime>::get(&publisher)
+                    .into_inner(),
+                publisher,
+            })
+        }
+
+        fn verification(publisher: AccountId) -> Option<VerificationDetails<BlockNumber>> {
+            liganite_publish::Verifications::<Runtime>::get(publisher)
+        }
+    }
+
+    impl liganite_games_runtime_api::GamesApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn game(publisher: AccountId, game_id: GameId) -> Option<GameInfoOf> {
+            Games::game(&publisher, game_id)
+                .map(|((publisher, game_id), details)| game_info(publisher, game_id, details))
+        }
+
+        fn games_of(publisher: AccountId, cursor: Option<GameId>, limit: u32) -> Vec<GameInfoOf> {
+            Games::games_of(&publisher, cursor, limit.min(MAX_PAGE_SIZE))
+                .into_iter()
+                .map(|((publisher, game_id), details)| game_info(publisher, game_id, details))
+                .collect()
+        }
+
+        fn games_by_tag(
+            tag_id: TagId,
+            cursor: Option<(AccountId, GameId)>,
+            limit: u32,
+        ) -> Vec<GameInfoOf> {
+            Games::games_by_tag(tag_id, cursor, limit.min(MAX_PAGE_SIZE))
+                .into_iter()
+                .map(|((publisher, game_id), details)| game_info(publisher, game_id, details))
+                .collect()
+        }
+
+        fn owned_games(
+            buyer: AccountId,
+            cursor: Option<(AccountId, GameId)>,
+            limit: u32,
+        ) -> Vec<(AccountId, GameId)> {
+            Games::owned_games(buyer, cursor, limit.min(MAX_PAGE_SIZE))
+        }
+
+        fn pending_orders(
+            publisher: AccountId,
+            game_id: GameId,
+            cursor: Option<AccountId>,
+            limit: u32,
+        ) -> Vec<OrderInfo<AccountId, Balance, BlockNumber>> {
+            Games::publisher_orders(publisher.clone(), game_id, cursor, limit.min(MAX_PAGE_SIZE))
+                .into_iter()
+                .filter_map(|buyer| {
+                    let order = liganite_games::BuyerOrders::<Runtime>::get(
+                        &buyer,
+                        (&publisher, game_id),
+                    )?;
+                    Some(OrderInfo { buyer, deposit: order.deposit, expires_at: order.expires_at })
+                })
+                .collect()
+        }
+
+        fn tag(tag_id: TagId) -> Option<TagInfo> {
+            tag_info(tag_id)
+        }
+
+        fn tags_by_category() -> Vec<TagGroup> {
+            let mut groups = BTreeMap::<TagCategory, Vec<TagInfo>>::new();
+            for (id, tag) in liganite_games::Tags::<Runtime>::iter() {
+                groups.entry(tag.category).or_default().push(TagInfo::new(id, tag));
+            }
+            groups
+                .into_iter()
+                .map(|(category, mut tags)| {
+                    tags.sort_by_key(|tag| tag.id);
+                    TagGroup { category, tags }
+                })
+                .collect()
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (
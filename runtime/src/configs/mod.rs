@@ -9,7 +9,10 @@ use frame_support::{
         IdentityFee, Weight,
     },
 };
-use frame_system::limits::{BlockLength, BlockWeights};
+use frame_system::{
+    limits::{BlockLength, BlockWeights},
+    EnsureRoot,
+};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_runtime::{traits::One, Perbill};
@@ -19,9 +22,10 @@ use crate::weights::{block_weights::BlockExecutionWeight, extrinsic_weights::Ext
 
 // Local module imports
 use super::{
-    weights, AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo,
-    Publish, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason,
-    RuntimeOrigin, RuntimeTask, System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+    weights, AccountId, Aura, Balance, Balances, Block, BlockNumber, Games, Hash, Nonce,
+    PalletInfo, Publish, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason,
+    RuntimeHoldReason, RuntimeOrigin, RuntimeTask, System, DAYS, EXISTENTIAL_DEPOSIT,
+    SLOT_DURATION, VERSION,
 };
 
 /// We assume that ~10% of the block weight is consumed by `on_initialize` handlers.
@@ -156,11 +160,31 @@ impl pallet_sudo::Config for Runtime {
     type WeightInfo = weights::pallet_sudo::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+    pub const DepositGracePeriod: BlockNumber = 30 * DAYS;
+}
+
 impl liganite_publish::Config for Runtime {
     type WeightInfo = weights::liganite_publish::WeightInfo<Runtime>;
     type RuntimeEvent = RuntimeEvent;
     type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
+    type Catalog = Games;
+    type DepositGracePeriod = DepositGracePeriod;
+    type SlashOrigin = EnsureRoot<AccountId>;
+    // There is no treasury yet, so the slashed deposits are burned
+    type Treasury = ();
+    type SuspendOrigin = EnsureRoot<AccountId>;
+    type MaxMembers = ConstU32<32>;
+    type MaxTransfers = ConstU32<8>;
+    type MaxDepositIncreases = ConstU32<8>;
+    type VerifierOrigin = EnsureRoot<AccountId>;
+}
+
+parameter_types! {
+    pub const OrderTimeout: BlockNumber = 7 * DAYS;
+    pub const MaxTopLevelGenres: u32 = 3;
+    pub const MinGenres: u32 = 1;
 }
 
 impl liganite_games::Config for Runtime {
@@ -169,4 +193,8 @@ impl liganite_games::Config for Runtime {
     type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type PublisherManager = Publish;
+    type OrderTimeout = OrderTimeout;
+    type TagOrigin = EnsureRoot<AccountId>;
+    type MaxTopLevelGenres = MaxTopLevelGenres;
+    type MinGenres = MinGenres;
 }
@@ -1,6 +1,8 @@
-use crate::{AccountId, BalancesConfig, RuntimeGenesisConfig, SudoConfig};
+use crate::{AccountId, Balance, BalancesConfig, Runtime, RuntimeGenesisConfig, SudoConfig, UNIT};
 use alloc::{vec, vec::Vec};
-use frame_support::build_struct_json_patch;
+use frame_support::{build_struct_json_patch, traits::Get, BoundedVec};
+use liganite_games_runtime_api::{Distribution, GameDetails, TagCategory, TagId};
+use liganite_publish_runtime_api::PublisherDetails;
 use serde_json::Value;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
@@ -9,11 +11,16 @@ use sp_keyring::Sr25519Keyring;
 
 const DEFAULT_PUBLISHER_DEPOSIT: u128 = 1_000;
 
+/// The preset populating a store with publishers, games and players.
+pub const LIGANITE_DEMO_PRESET: &str = "liganite-demo";
+
 // Returns the genesis config presets populated with given parameters.
 fn testnet_genesis(
     initial_authorities: Vec<(AuraId, GrandpaId)>,
     endowed_accounts: Vec<AccountId>,
     root: AccountId,
+    publishers: Vec<(AccountId, PublisherDetails)>,
+    catalogue: liganite_games::GenesisConfig<Runtime>,
 ) -> Value {
     build_struct_json_patch!(RuntimeGenesisConfig {
         balances: BalancesConfig {
@@ -30,7 +37,11 @@ fn testnet_genesis(
             authorities: initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>(),
         },
         sudo: SudoConfig { key: Some(root) },
-        publish: liganite_publish::GenesisConfig { publisher_deposit: DEFAULT_PUBLISHER_DEPOSIT },
+        publish: liganite_publish::GenesisConfig {
+            publisher_deposit: DEFAULT_PUBLISHER_DEPOSIT,
+            publishers,
+        },
+        games: catalogue,
     })
 }
 
@@ -48,6 +59,8 @@ pub fn development_config_genesis() -> Value {
             Sr25519Keyring::BobStash.to_account_id(),
         ],
         sp_keyring::Sr25519Keyring::Alice.to_account_id(),
+        vec![],
+        Default::default(),
     )
 }
 
@@ -69,6 +82,128 @@ pub fn local_config_genesis() -> Value {
             .map(|v| v.to_account_id())
             .collect::<Vec<_>>(),
         Sr25519Keyring::Alice.to_account_id(),
+        vec![],
+        Default::default(),
+    )
+}
+
+// Converts a demo value into a bounded vector, all of them fitting their bounds.
+fn bounded<S: Get<u32>>(value: &str) -> BoundedVec<u8, S> {
+    value
+        .as_bytes()
+        .to_vec()
+        .try_into()
+        .expect("The demo values fit their bounds; qed")
+}
+
+// Returns the details of a demo publisher.
+fn publisher(name: &str, url: &str, email: &str) -> PublisherDetails {
+    PublisherDetails {
+        name: bounded(name),
+        url: bounded(url),
+        email: Some(bounded(email)),
+        ..Default::default()
+    }
+}
+
+// Returns the details of a demo game.
+fn game(name: &str, tags: &[TagId], distribution: Distribution<Balance>) -> GameDetails<Balance> {
+    GameDetails {
+        name: bounded(name),
+        tags: tags.to_vec().try_into().expect("The demo games have few tags; qed"),
+        distribution,
+    }
+}
+
+/// Return the demo genesis config preset: a local chain whose store is populated with a few
+/// publishers, their games and the collections of some players.
+pub fn liganite_demo_config_genesis() -> Value {
+    let chapiteau = Sr25519Keyring::Charlie.to_account_id();
+    let lantern = Sr25519Keyring::Dave.to_account_id();
+    let orbit = Sr25519Keyring::Eve.to_account_id();
+
+    let catalogue = liganite_games::GenesisConfig {
+        tags: vec![
+            (1000, bounded("On-chain Items"), TagCategory::Feature, None),
+            (1001, bounded("Roguelike Deckbuilder"), TagCategory::SubGenre, Some(58)),
+            (1002, bounded("Cozy Farming"), TagCategory::SubGenre, Some(33)),
+        ],
+        games: vec![
+            (
+                chapiteau.clone(),
+                1,
+                game(
+                    "Circus of Cards",
+                    &[6, 9, 58, 1001, 290, 159, 347],
+                    Distribution::Instant {
+                        price: 15 * UNIT,
+                        cid: bounded("QmYwAPJzv5CZsnAztbCxjRMoa6zFzFG8pGzLFZxojtL8MX"),
+                    },
+                ),
+            ),
+            (
+                chapiteau.clone(),
+                2,
+                game(
+                    "Big Top Tycoon",
+                    &[7, 43, 152, 347],
+                    Distribution::Individual { price: 25 * UNIT },
+                ),
+            ),
+            (
+                lantern.clone(),
+                1,
+                game(
+                    "Lantern Fields",
+                    &[7, 33, 1002, 386, 1000, 340],
+                    Distribution::Free {
+                        cid: bounded("QmRJzSVrU5kMkXzDCrePyx3TX7gGu8cXsogX5xLyfMuNPG"),
+                    },
+                ),
+            ),
+            (
+                orbit.clone(),
+                1,
+                game(
+                    "Star Courier",
+                    &[0, 67, 247, 250, 340, 399],
+                    Distribution::Instant {
+                        price: 20 * UNIT,
+                        cid: bounded("bafybeigdyrzt3whh4p5fy7uj5zd7qvmdtg7okjqcyawh5hj7sgl2xylz4u"),
+                    },
+                ),
+            ),
+        ],
+        owned_games: vec![
+            (Sr25519Keyring::Alice.to_account_id(), chapiteau.clone(), 1),
+            (Sr25519Keyring::Alice.to_account_id(), lantern.clone(), 1),
+            (Sr25519Keyring::Bob.to_account_id(), lantern.clone(), 1),
+            (Sr25519Keyring::Bob.to_account_id(), orbit.clone(), 1),
+        ],
+    };
+
+    testnet_genesis(
+        vec![(
+            sp_keyring::Sr25519Keyring::Alice.public().into(),
+            sp_keyring::Ed25519Keyring::Alice.public().into(),
+        )],
+        Sr25519Keyring::iter()
+            .filter(|v| v != &Sr25519Keyring::One && v != &Sr25519Keyring::Two)
+            .map(|v| v.to_account_id())
+            .collect::<Vec<_>>(),
+        Sr25519Keyring::Alice.to_account_id(),
+        vec![
+            (
+                chapiteau,
+                publisher("Chapiteau Games", "https://chapiteau.games", "hello@chapiteau.games"),
+            ),
+            (
+                lantern,
+                publisher("Lantern Studio", "https://lantern.studio", "contact@lantern.studio"),
+            ),
+            (orbit, publisher("Orbit Forge", "https://orbitforge.dev", "team@orbitforge.dev")),
+        ],
+        catalogue,
     )
 }
 
@@ -77,6 +212,7 @@ pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
     let patch = match id.as_ref() {
         sp_genesis_builder::DEV_RUNTIME_PRESET => development_config_genesis(),
         sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET => local_config_genesis(),
+        LIGANITE_DEMO_PRESET => liganite_demo_config_genesis(),
         _ => return None,
     };
     Some(
@@ -91,5 +227,6 @@ pub fn preset_names() -> Vec<PresetId> {
     vec![
         PresetId::from(sp_genesis_builder::DEV_RUNTIME_PRESET),
         PresetId::from(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET),
+        PresetId::from(LIGANITE_DEMO_PRESET),
     ]
 }
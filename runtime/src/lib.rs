@@ -70,10 +70,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 100,
+    spec_version: 112,
     impl_version: 1,
     apis: apis::RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 6,
     system_version: 1,
 };
 
@@ -172,7 +172,18 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    liganite_publish::migrations::v1::MigrateV0ToV1<Runtime>,
+    liganite_publish::migrations::v2::MigrateV1ToV2<Runtime>,
+    liganite_games::migrations::v1::MigrateV0ToV1<Runtime>,
+    liganite_games::migrations::v2::MigrateV1ToV2<Runtime>,
+    liganite_games::migrations::v3::MigrateV2ToV3<Runtime>,
+    liganite_games::migrations::v4::MigrateV3ToV4<Runtime>,
+    liganite_games::migrations::v5::MigrateV4ToV5<Runtime>,
+    liganite_games::migrations::v6::MigrateV5ToV6<Runtime>,
+    liganite_games::migrations::v7::MigrateV6ToV7<Runtime>,
+    liganite_games::migrations::v8::MigrateV7ToV8<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -33,28 +33,35 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> liganite_games::WeightInfo for WeightInfo<T> {
 	/// Storage: `Publish::Publishers` (r:1 w:0)
-	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(300), added: 2775, mode: `MaxEncodedLen`)
+	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherStatuses` (r:1 w:0)
+	/// Proof: `Publish::PublisherStatuses` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
 	/// Storage: `Games::PublishedGames` (r:1 w:1)
 	/// Proof: `Games::PublishedGames` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
 	/// Storage: `Games::Tags` (r:20 w:0)
-	/// Proof: `Games::Tags` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Proof: `Games::Tags` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Games::GamesCount` (r:1 w:1)
+	/// Proof: `Games::GamesCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Games::GamesByTag` (r:0 w:20)
+	/// Proof: `Games::GamesByTag` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	/// The range of component `a` is `[1, 128]`.
-	/// The range of component `b` is `[0, 20]`.
+	/// The range of component `b` is `[1, 20]`.
 	fn game_add(a: u32, b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `471 + b * (249 ±0)`
-		//  Estimated: `3841 + b * (2544 ±0)`
+		//  Estimated: `4158 + b * (2549 ±0)`
 		// Minimum execution time: 15_315_000 picoseconds.
 		Weight::from_parts(20_116_733, 0)
-			.saturating_add(Weight::from_parts(0, 3841))
+			.saturating_add(Weight::from_parts(0, 4158))
 			// Standard Error: 8_019
 			.saturating_add(Weight::from_parts(95_095, 0).saturating_mul(a.into()))
 			// Standard Error: 49_749
 			.saturating_add(Weight::from_parts(4_063_867, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
-			.saturating_add(T::DbWeight::get().writes(1))
-			.saturating_add(Weight::from_parts(0, 2544).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2549).saturating_mul(b.into()))
 	}
 	/// Storage: `Games::BuyerOrders` (r:1 w:0)
 	/// Proof: `Games::BuyerOrders` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
@@ -62,6 +69,8 @@ impl<T: frame_system::Config> liganite_games::WeightInfo for WeightInfo<T> {
 	/// Proof: `Games::OwnedGames` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
 	/// Storage: `Games::PublishedGames` (r:1 w:0)
 	/// Proof: `Games::PublishedGames` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherStatuses` (r:1 w:0)
+	/// Proof: `Publish::PublisherStatuses` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
 	fn buy_free() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `434`
@@ -69,7 +78,7 @@ impl<T: frame_system::Config> liganite_games::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 14_706_000 picoseconds.
 		Weight::from_parts(15_596_000, 0)
 			.saturating_add(Weight::from_parts(0, 3841))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Games::BuyerOrders` (r:1 w:0)
@@ -78,17 +87,25 @@ impl<T: frame_system::Config> liganite_games::WeightInfo for WeightInfo<T> {
 	/// Proof: `Games::OwnedGames` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
 	/// Storage: `Games::PublishedGames` (r:1 w:0)
 	/// Proof: `Games::PublishedGames` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Publish::PublisherStatuses` (r:1 w:0)
+	/// Proof: `Publish::PublisherStatuses` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `Games::RefundPolicies` (r:1 w:0)
+	/// Proof: `Games::RefundPolicies` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Games::Purchases` (r:0 w:1)
+	/// Proof: `Games::Purchases` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
 	fn buy_instant() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `450`
-		//  Estimated: `3841`
-		// Minimum execution time: 42_997_000 picoseconds.
-		Weight::from_parts(44_230_000, 0)
-			.saturating_add(Weight::from_parts(0, 3841))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Measured:  `612`
+		//  Estimated: `6196`
+		// Minimum execution time: 71_384_000 picoseconds.
+		Weight::from_parts(73_102_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `Games::BuyerOrders` (r:1 w:1)
 	/// Proof: `Games::BuyerOrders` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
@@ -96,6 +113,8 @@ impl<T: frame_system::Config> liganite_games::WeightInfo for WeightInfo<T> {
 	/// Proof: `Games::OwnedGames` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
 	/// Storage: `Games::PublishedGames` (r:1 w:0)
 	/// Proof: `Games::PublishedGames` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherStatuses` (r:1 w:0)
+	/// Proof: `Publish::PublisherStatuses` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `Games::PublisherOrders` (r:0 w:1)
@@ -107,7 +126,7 @@ impl<T: frame_system::Config> liganite_games::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 43_033_000 picoseconds.
 		Weight::from_parts(43_943_000, 0)
 			.saturating_add(Weight::from_parts(0, 3841))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Games::BuyerOrders` (r:1 w:1)
@@ -146,4 +165,302 @@ impl<T: frame_system::Config> liganite_games::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
+	/// Storage: `Publish::Publishers` (r:1 w:0)
+	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `Games::PublishedGames` (r:1 w:1)
+	/// Proof: `Games::PublishedGames` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// Storage: `Games::Tags` (r:20 w:0)
+	/// Proof: `Games::Tags` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Games::TagMerge` (r:1 w:0)
+	/// Proof: `Games::TagMerge` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Games::GamesByTag` (r:0 w:40)
+	/// Proof: `Games::GamesByTag` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn game_update() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5618`
+		//  Estimated: `54741`
+		// Minimum execution time: 111_274_000 picoseconds.
+		Weight::from_parts(115_036_000, 0)
+			.saturating_add(Weight::from_parts(0, 54741))
+			.saturating_add(T::DbWeight::get().reads(23))
+			.saturating_add(T::DbWeight::get().writes(41))
+	}
+	/// Storage: `Games::PublishedGames` (r:1 w:0)
+	/// Proof: `Games::PublishedGames` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// Storage: `Games::GameStatuses` (r:1 w:1)
+	/// Proof: `Games::GameStatuses` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Games::GamesByTag` (r:0 w:20)
+	/// Proof: `Games::GamesByTag` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn game_status_set() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `434`
+		//  Estimated: `3841`
+		// Minimum execution time: 13_104_000 picoseconds.
+		Weight::from_parts(13_672_000, 0)
+			.saturating_add(Weight::from_parts(0, 3841))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(21))
+	}
+	/// Storage: `Games::PublishedGames` (r:1 w:0)
+	/// Proof: `Games::PublishedGames` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// Storage: `Games::PendingOrdersCount` (r:1 w:1)
+	/// Proof: `Games::PendingOrdersCount` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Games::GameStatuses` (r:1 w:1)
+	/// Proof: `Games::GameStatuses` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Games::PublisherOrders` (r:501 w:500)
+	/// Proof: `Games::PublisherOrders` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Games::BuyerOrders` (r:500 w:500)
+	/// Proof: `Games::BuyerOrders` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:500 w:500)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Games::GamesByTag` (r:0 w:20)
+	/// Proof: `Games::GamesByTag` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 500]`.
+	fn game_retire(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `434 + n * (213 ±0)`
+		//  Estimated: `3841 + n * (2581 ±0)`
+		// Minimum execution time: 18_921_000 picoseconds.
+		Weight::from_parts(19_374_000, 0)
+			.saturating_add(Weight::from_parts(0, 3841))
+			// Standard Error: 14_273
+			.saturating_add(Weight::from_parts(27_918_442, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(22))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2581).saturating_mul(n.into()))
+	}
+	/// Storage: `Publish::Publishers` (r:1 w:0)
+	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `Games::PublishedGames` (r:1 w:0)
+	/// Proof: `Games::PublishedGames` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// Storage: `Games::Releases` (r:2 w:2)
+	/// Proof: `Games::Releases` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// Storage: `Games::LatestRelease` (r:1 w:1)
+	/// Proof: `Games::LatestRelease` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn release_publish() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1028`
+		//  Estimated: `6662`
+		// Minimum execution time: 36_417_000 picoseconds.
+		Weight::from_parts(37_502_000, 0)
+			.saturating_add(Weight::from_parts(0, 6662))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Games::Releases` (r:3 w:3)
+	/// Proof: `Games::Releases` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	fn release_yank() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1190`
+		//  Estimated: `9498`
+		// Minimum execution time: 24_136_000 picoseconds.
+		Weight::from_parts(25_011_000, 0)
+			.saturating_add(Weight::from_parts(0, 9498))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Games::BuyerOrders` (r:1 w:1)
+	/// Proof: `Games::BuyerOrders` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Games::PendingOrdersCount` (r:1 w:1)
+	/// Proof: `Games::PendingOrdersCount` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Games::PublisherOrders` (r:0 w:1)
+	/// Proof: `Games::PublisherOrders` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Games::OrderExpiries` (r:0 w:1)
+	/// Proof: `Games::OrderExpiries` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	fn order_expire() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3579`
+		// Minimum execution time: 45_203_000 picoseconds.
+		Weight::from_parts(46_518_000, 0)
+			.saturating_add(Weight::from_parts(0, 3579))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Games::BuyerOrders` (r:1 w:1)
+	/// Proof: `Games::BuyerOrders` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Games::PendingOrdersCount` (r:1 w:1)
+	/// Proof: `Games::PendingOrdersCount` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Games::PublisherOrders` (r:0 w:1)
+	/// Proof: `Games::PublisherOrders` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Games::OrderExpiries` (r:0 w:1)
+	/// Proof: `Games::OrderExpiries` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	fn order_reject() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3579`
+		// Minimum execution time: 46_087_000 picoseconds.
+		Weight::from_parts(47_215_000, 0)
+			.saturating_add(Weight::from_parts(0, 3579))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Games::PublishedGames` (r:1 w:0)
+	/// Proof: `Games::PublishedGames` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// Storage: `Games::RefundPolicies` (r:0 w:1)
+	/// Proof: `Games::RefundPolicies` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	fn refund_policy_set() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `582`
+		//  Estimated: `3841`
+		// Minimum execution time: 15_324_000 picoseconds.
+		Weight::from_parts(15_912_000, 0)
+			.saturating_add(Weight::from_parts(0, 3841))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Games::Purchases` (r:1 w:1)
+	/// Proof: `Games::Purchases` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Games::OwnedGames` (r:0 w:1)
+	/// Proof: `Games::OwnedGames` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
+	fn purchase_refund() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `524`
+		//  Estimated: `6196`
+		// Minimum execution time: 62_418_000 picoseconds.
+		Weight::from_parts(63_905_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Games::Purchases` (r:1 w:1)
+	/// Proof: `Games::Purchases` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn purchase_settle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `456`
+		//  Estimated: `3579`
+		// Minimum execution time: 38_761_000 picoseconds.
+		Weight::from_parts(39_840_000, 0)
+			.saturating_add(Weight::from_parts(0, 3579))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Games::PublishedGames` (r:2 w:2)
+	/// Proof: `Games::PublishedGames` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// Storage: `Games::Releases` (r:1 w:0)
+	/// Proof: `Games::Releases` (`max_values`: None, `max_size`: Some(361), added: 2836, mode: `MaxEncodedLen`)
+	/// Storage: `Games::GameStatuses` (r:1 w:2)
+	/// Proof: `Games::GameStatuses` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Games::RefundPolicies` (r:1 w:2)
+	/// Proof: `Games::RefundPolicies` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Games::LatestRelease` (r:1 w:2)
+	/// Proof: `Games::LatestRelease` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Games::TagMerge` (r:1 w:0)
+	/// Proof: `Games::TagMerge` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Games::GamesByTag` (r:0 w:40)
+	/// Proof: `Games::GamesByTag` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn catalog_transfer_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `888`
+		//  Estimated: `6692`
+		// Minimum execution time: 43_105_000 picoseconds.
+		Weight::from_parts(44_298_000, 0)
+			.saturating_add(Weight::from_parts(0, 6692))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(48))
+	}
+	/// Storage: `Games::Tags` (r:2 w:1)
+	/// Proof: `Games::Tags` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Games::TagNames` (r:1 w:1)
+	/// Proof: `Games::TagNames` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `Games::TagChildren` (r:2 w:1)
+	/// Proof: `Games::TagChildren` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `Games::CounterForTags` (r:1 w:1)
+	/// Proof: `Games::CounterForTags` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn tag_add() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `278`
+		//  Estimated: `6088`
+		// Minimum execution time: 24_517_000 picoseconds.
+		Weight::from_parts(25_302_000, 0)
+			.saturating_add(Weight::from_parts(0, 6088))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Games::Tags` (r:1 w:1)
+	/// Proof: `Games::Tags` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Games::TagNames` (r:1 w:2)
+	/// Proof: `Games::TagNames` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn tag_rename() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `285`
+		//  Estimated: `3539`
+		// Minimum execution time: 18_930_000 picoseconds.
+		Weight::from_parts(19_604_000, 0)
+			.saturating_add(Weight::from_parts(0, 3539))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Games::Tags` (r:1 w:1)
+	/// Proof: `Games::Tags` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	fn tag_deprecate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `211`
+		//  Estimated: `3539`
+		// Minimum execution time: 11_423_000 picoseconds.
+		Weight::from_parts(11_957_000, 0)
+			.saturating_add(Weight::from_parts(0, 3539))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Games::TagMerge` (r:1 w:1)
+	/// Proof: `Games::TagMerge` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Games::Tags` (r:2 w:1)
+	/// Proof: `Games::Tags` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Games::TagChildren` (r:1 w:0)
+	/// Proof: `Games::TagChildren` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	fn tag_merge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `338`
+		//  Estimated: `6088`
+		// Minimum execution time: 18_264_000 picoseconds.
+		Weight::from_parts(18_937_000, 0)
+			.saturating_add(Weight::from_parts(0, 6088))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Games::TagMergeCursor` (r:1 w:1)
+	/// Proof: `Games::TagMergeCursor` (`max_values`: Some(1), `max_size`: Some(34), added: 529, mode: `MaxEncodedLen`)
+	/// Storage: `Games::PublishedGames` (r:1 w:1)
+	/// Proof: `Games::PublishedGames` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// Storage: `Games::GameStatuses` (r:1 w:0)
+	/// Proof: `Games::GameStatuses` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Games::GamesByTag` (r:0 w:2)
+	/// Proof: `Games::GamesByTag` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn tag_merge_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `596`
+		//  Estimated: `3841`
+		// Minimum execution time: 22_613_000 picoseconds.
+		Weight::from_parts(23_408_000, 0)
+			.saturating_add(Weight::from_parts(0, 3841))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Games::Tags` (r:2 w:1)
+	/// Proof: `Games::Tags` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `Games::TagChildren` (r:3 w:2)
+	/// Proof: `Games::TagChildren` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	fn tag_classify() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `6088`
+		// Minimum execution time: 21_076_000 picoseconds.
+		Weight::from_parts(21_835_000, 0)
+			.saturating_add(Weight::from_parts(0, 6088))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
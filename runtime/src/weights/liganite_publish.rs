@@ -32,35 +32,300 @@ use core::marker::PhantomData;
 /// Weight functions for `liganite_publish`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> liganite_publish::WeightInfo for WeightInfo<T> {
-	/// Storage: `Publish::PublisherDeposit` (r:0 w:1)
+	/// Storage: `Publish::PublisherDeposit` (r:1 w:1)
 	/// Proof: `Publish::PublisherDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::DepositDeadline` (r:0 w:1)
+	/// Proof: `Publish::DepositDeadline` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn deposit_set() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 3_610_000 picoseconds.
-		Weight::from_parts(3_794_000, 0)
-			.saturating_add(Weight::from_parts(0, 0))
-			.saturating_add(T::DbWeight::get().writes(1))
+		//  Measured:  `6`
+		//  Estimated: `1501`
+		// Minimum execution time: 5_872_000 picoseconds.
+		Weight::from_parts(6_140_000, 0)
+			.saturating_add(Weight::from_parts(0, 1501))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Publish::Publishers` (r:1 w:1)
-	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(300), added: 2775, mode: `MaxEncodedLen`)
+	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
 	/// Storage: `Publish::PublisherDeposit` (r:1 w:0)
 	/// Proof: `Publish::PublisherDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherDeposits` (r:0 w:1)
+	/// Proof: `Publish::PublisherDeposits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// The range of component `a` is `[1, 128]`.
 	/// The range of component `b` is `[8, 128]`.
-	fn publisher_register(_a: u32, b: u32, ) -> Weight {
+	/// The range of component `c` is `[13, 128]`.
+	fn publisher_register(_a: u32, b: u32, _c: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `45`
-		//  Estimated: `3765`
+		//  Estimated: `4158`
 		// Minimum execution time: 36_963_000 picoseconds.
 		Weight::from_parts(40_480_429, 0)
-			.saturating_add(Weight::from_parts(0, 3765))
+			.saturating_add(Weight::from_parts(0, 4158))
 			// Standard Error: 796
 			.saturating_add(Weight::from_parts(785, 0).saturating_mul(b.into()))
 			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Publish::Publishers` (r:1 w:1)
+	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `Games::GamesCount` (r:1 w:0)
+	/// Proof: `Games::GamesCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Games::PendingOrdersCount` (r:1 w:0)
+	/// Proof: `Games::PendingOrdersCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Games::PublishedGames` (r:501 w:0)
+	/// Proof: `Games::PublishedGames` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// Storage: `Games::GameStatuses` (r:500 w:500)
+	/// Proof: `Games::GameStatuses` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherStatuses` (r:1 w:0)
+	/// Proof: `Publish::PublisherStatuses` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::Members` (r:33 w:32)
+	/// Proof: `Publish::Members` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::MemberOf` (r:0 w:32)
+	/// Proof: `Publish::MemberOf` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::MembersCount` (r:0 w:1)
+	/// Proof: `Publish::MembersCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 500]`.
+	fn publisher_deregister(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3126 + n * (118 ±0)`
+		//  Estimated: `88506 + n * (2851 ±0)`
+		// Minimum execution time: 203_587_000 picoseconds.
+		Weight::from_parts(207_944_000, 0)
+			.saturating_add(Weight::from_parts(0, 88506))
+			// Standard Error: 9_412
+			.saturating_add(Weight::from_parts(9_871_336, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(39))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(68))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2851).saturating_mul(n.into()))
+	}
+	/// Storage: `Publish::Publishers` (r:1 w:0)
+	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherDeposits` (r:1 w:1)
+	/// Proof: `Publish::PublisherDeposits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherDeposit` (r:1 w:0)
+	/// Proof: `Publish::PublisherDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn deposit_top_up() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `392`
+		//  Estimated: `4158`
+		// Minimum execution time: 38_214_000 picoseconds.
+		Weight::from_parts(39_480_000, 0)
+			.saturating_add(Weight::from_parts(0, 4158))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Publish::Publishers` (r:1 w:0)
+	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherDeposits` (r:1 w:1)
+	/// Proof: `Publish::PublisherDeposits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherDeposit` (r:1 w:0)
+	/// Proof: `Publish::PublisherDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn deposit_reclaim_excess() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `392`
+		//  Estimated: `4158`
+		// Minimum execution time: 35_106_000 picoseconds.
+		Weight::from_parts(36_327_000, 0)
+			.saturating_add(Weight::from_parts(0, 4158))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Publish::Publishers` (r:1 w:1)
+	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::Verifications` (r:1 w:1)
+	/// Proof: `Publish::Verifications` (`max_values`: None, `max_size`: Some(305), added: 2780, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[1, 128]`.
+	/// The range of component `b` is `[8, 128]`.
+	/// The range of component `c` is `[13, 128]`.
+	fn publisher_update(a: u32, b: u32, _c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `201 + a * (1 ±0) + b * (1 ±0)`
+		//  Estimated: `4158`
+		// Minimum execution time: 14_209_000 picoseconds.
+		Weight::from_parts(15_387_512, 0)
+			.saturating_add(Weight::from_parts(0, 4158))
+			// Standard Error: 412
+			.saturating_add(Weight::from_parts(1_104, 0).saturating_mul(a.into()))
+			// Standard Error: 412
+			.saturating_add(Weight::from_parts(812, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: `Publish::Publishers` (r:1 w:0)
+	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherDeposits` (r:1 w:1)
+	/// Proof: `Publish::PublisherDeposits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherStatuses` (r:0 w:1)
+	/// Proof: `Publish::PublisherStatuses` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	fn publisher_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `455`
+		//  Estimated: `4158`
+		// Minimum execution time: 52_718_000 picoseconds.
+		Weight::from_parts(54_106_000, 0)
+			.saturating_add(Weight::from_parts(0, 4158))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Publish::Publishers` (r:1 w:0)
+	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherStatuses` (r:1 w:1)
+	/// Proof: `Publish::PublisherStatuses` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	fn publisher_suspend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `245`
+		//  Estimated: `4158`
+		// Minimum execution time: 12_874_000 picoseconds.
+		Weight::from_parts(13_401_000, 0)
+			.saturating_add(Weight::from_parts(0, 4158))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Publish::PublisherStatuses` (r:1 w:1)
+	/// Proof: `Publish::PublisherStatuses` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	fn publisher_reinstate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `105`
+		//  Estimated: `3506`
+		// Minimum execution time: 9_617_000 picoseconds.
+		Weight::from_parts(10_032_000, 0)
+			.saturating_add(Weight::from_parts(0, 3506))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Publish::Publishers` (r:2 w:0)
+	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::MemberOf` (r:1 w:1)
+	/// Proof: `Publish::MemberOf` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::MembersCount` (r:1 w:1)
+	/// Proof: `Publish::MembersCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::Members` (r:0 w:1)
+	/// Proof: `Publish::Members` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn member_grant() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `382`
+		//  Estimated: `7326`
+		// Minimum execution time: 18_941_000 picoseconds.
+		Weight::from_parts(19_622_000, 0)
+			.saturating_add(Weight::from_parts(0, 7326))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Publish::Members` (r:1 w:1)
+	/// Proof: `Publish::Members` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::MembersCount` (r:1 w:1)
+	/// Proof: `Publish::MembersCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::MemberOf` (r:0 w:1)
+	/// Proof: `Publish::MemberOf` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn member_revoke() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `227`
+		//  Estimated: `3546`
+		// Minimum execution time: 15_306_000 picoseconds.
+		Weight::from_parts(15_890_000, 0)
+			.saturating_add(Weight::from_parts(0, 3546))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Publish::Publishers` (r:2 w:0)
+	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherStatuses` (r:1 w:0)
+	/// Proof: `Publish::PublisherStatuses` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `Games::CatalogTransfers` (r:1 w:0)
+	/// Proof: `Games::CatalogTransfers` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherLineage` (r:1 w:0)
+	/// Proof: `Publish::PublisherLineage` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::MemberOf` (r:1 w:0)
+	/// Proof: `Publish::MemberOf` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::Successors` (r:1 w:0)
+	/// Proof: `Publish::Successors` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PendingTransfers` (r:0 w:1)
+	/// Proof: `Publish::PendingTransfers` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn publisher_transfer_initiate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `7326`
+		// Minimum execution time: 24_518_000 picoseconds.
+		Weight::from_parts(25_371_000, 0)
+			.saturating_add(Weight::from_parts(0, 7326))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Publish::PendingTransfers` (r:1 w:1)
+	/// Proof: `Publish::PendingTransfers` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::Publishers` (r:2 w:2)
+	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherStatuses` (r:1 w:0)
+	/// Proof: `Publish::PublisherStatuses` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+	/// Storage: `Games::PendingOrdersCount` (r:1 w:0)
+	/// Proof: `Games::PendingOrdersCount` (`max_values`: None, `max_size`: Some(70), added: 2545, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::MemberOf` (r:33 w:32)
+	/// Proof: `Publish::MemberOf` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::Successors` (r:9 w:8)
+	/// Proof: `Publish::Successors` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherLineage` (r:1 w:2)
+	/// Proof: `Publish::PublisherLineage` (`max_values`: None, `max_size`: Some(298), added: 2773, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::PublisherDeposits` (r:1 w:2)
+	/// Proof: `Publish::PublisherDeposits` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:3 w:3)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::Members` (r:33 w:64)
+	/// Proof: `Publish::Members` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::MembersCount` (r:1 w:2)
+	/// Proof: `Publish::MembersCount` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Games::GamesCount` (r:1 w:2)
+	/// Proof: `Games::GamesCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Games::CatalogTransfers` (r:0 w:1)
+	/// Proof: `Games::CatalogTransfers` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn publisher_transfer_accept() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4187`
+		//  Estimated: `88506`
+		// Minimum execution time: 318_402_000 picoseconds.
+		Weight::from_parts(324_915_000, 0)
+			.saturating_add(Weight::from_parts(0, 88506))
+			.saturating_add(T::DbWeight::get().reads(88))
+			.saturating_add(T::DbWeight::get().writes(121))
+	}
+	/// Storage: `Publish::Publishers` (r:1 w:0)
+	/// Proof: `Publish::Publishers` (`max_values`: None, `max_size`: Some(693), added: 3168, mode: `MaxEncodedLen`)
+	/// Storage: `Publish::Verifications` (r:0 w:1)
+	/// Proof: `Publish::Verifications` (`max_values`: None, `max_size`: Some(305), added: 2780, mode: `MaxEncodedLen`)
+	fn publisher_verify() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `201`
+		//  Estimated: `4158`
+		// Minimum execution time: 16_842_000 picoseconds.
+		Weight::from_parts(17_395_000, 0)
+			.saturating_add(Weight::from_parts(0, 4158))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Publish::Verifications` (r:1 w:1)
+	/// Proof: `Publish::Verifications` (`max_values`: None, `max_size`: Some(305), added: 2780, mode: `MaxEncodedLen`)
+	fn publisher_unverify() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `389`
+		//  Estimated: `3770`
+		// Minimum execution time: 11_203_000 picoseconds.
+		Weight::from_parts(11_764_000, 0)
+			.saturating_add(Weight::from_parts(0, 3770))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }